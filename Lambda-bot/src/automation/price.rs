@@ -321,8 +321,8 @@ async fn handle_price(
     state: &Arc<Mutex<HashMap<String, Vec<PriceAlert>>>>,
 ) -> Result<bool, PriceAlertStoreError> {
     let mut to_send = Vec::new();
-    let mut persist: Option<Vec<PriceAlert>> = None;
-    let mut stop = false;
+    let persist: Option<Vec<PriceAlert>>;
+    let stop: bool;
 
     {
         let mut guard = state.lock().await;
@@ -361,10 +361,8 @@ async fn handle_price(
         notifier.send(channel_id, content).await;
     }
 
-    if let Some(cache) = cache {
-        if let Some(alerts) = persist {
-            save_symbol_alerts(cache, symbol, &alerts).await?;
-        }
+    if let (Some(cache), Some(alerts)) = (cache, persist) {
+        save_symbol_alerts(cache, symbol, &alerts).await?;
     }
 
     Ok(stop)
@@ -373,7 +371,6 @@ async fn handle_price(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::price::{Price, PriceUpdate};
     use serenity::all::{ChannelId, GuildId, Http};
     use std::env;
     use std::sync::Arc;
@@ -391,21 +388,6 @@ mod tests {
         }
     }
 
-    fn sample_price(symbol: &str, price: f64) -> PriceUpdate {
-        PriceUpdate {
-            prices: vec![Price {
-                symbol: symbol.to_string(),
-                name: symbol.to_string(),
-                price,
-                change: 0.0,
-                percent_change: 0.0,
-                pre_market_price: None,
-                after_hours_price: None,
-            }],
-            timestamp: Utc::now(),
-        }
-    }
-
     #[tokio::test]
     async fn triggers_expected_alerts() {
         let notifier = Arc::new(MockNotifier::default());
@@ -452,10 +434,10 @@ Downside PT3
         let sent = notifier.sent.lock().await.clone();
         let texts: Vec<_> = sent.iter().map(|(_, msg)| msg.as_str()).collect();
 
-        assert!(texts.iter().any(|m| *m == "FAIL SAFE 681.00 HIT"));
-        assert!(texts.iter().any(|m| *m == "PT1 Downside 680.00 HIT"));
-        assert!(texts.iter().any(|m| *m == "Lambda 684.50 HIT"));
-        assert!(texts.iter().any(|m| *m == "PT2 Upside 687.00 HIT"));
+        assert!(texts.contains(&"FAIL SAFE 681.00 HIT"));
+        assert!(texts.contains(&"PT1 Downside 680.00 HIT"));
+        assert!(texts.contains(&"Lambda 684.50 HIT"));
+        assert!(texts.contains(&"PT2 Upside 687.00 HIT"));
         // PT1 Upside (690) not reached in this sequence; ensure not sent
         assert!(!texts.iter().any(|m| m.contains("PT1 Upside 690.00")));
     }
@@ -22,10 +22,10 @@ pub async fn handle_interaction(
     source_channel: ChannelId,
     target_channel: ChannelId,
 ) -> serenity::Result<()> {
-    if let Interaction::Command(command) = interaction {
-        if command.data.name == COMMAND_NAME {
-            return relay_messages(ctx, command, source_channel, target_channel).await;
-        }
+    if let Interaction::Command(command) = interaction
+        && command.data.name == COMMAND_NAME
+    {
+        return relay_messages(ctx, command, source_channel, target_channel).await;
     }
     Ok(())
 }
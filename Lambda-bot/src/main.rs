@@ -1,8 +1,4 @@
-mod automation;
-mod finance;
-mod models;
-
-use automation::test;
+use lambda_bot::automation::test;
 use serenity::all::{
     ChannelId, Client, Context, CreateAllowedMentions, CreateMessage, EventHandler, GatewayIntents,
     GuildId, Interaction, Message, Ready,
@@ -13,14 +13,23 @@ use serenity::{async_trait, model::gateway::Ready, prelude::*, Client};
 use tracing::info;
 
 use stacks_bot::models::StatementType;
-use stacks_bot::service::automation::{earnings, options_data};
+use stacks_bot::service::automation::{earnings, insiders, options_data};
+use stacks_bot::service::caching::collections::watchlist;
 use stacks_bot::service::caching::RedisCache;
+use stacks_bot::service::command::compare as compare_cmd;
+use stacks_bot::service::command::dcf as dcf_cmd;
 use stacks_bot::service::command::earnings as earnings_cmd;
+use stacks_bot::service::command::earnings_season as earnings_season_cmd;
+use stacks_bot::service::command::fundamental_chart as fundamental_chart_cmd;
 use stacks_bot::service::command::fundamentals as fundamentals_cmd;
 use stacks_bot::service::command::holders as holders_cmd;
+use stacks_bot::service::command::iv as iv_cmd;
 use stacks_bot::service::command::mention as mention_cmd;
 use stacks_bot::service::command::news as news_cmd;
+use stacks_bot::service::command::options as options_cmd;
 use stacks_bot::service::command::quotes as quotes_cmd;
+use stacks_bot::service::command::ratios as ratios_cmd;
+use stacks_bot::service::command::strategy as strategy_cmd;
 use stacks_bot::service::finance::FinanceService;
 
 struct Handler {
@@ -69,9 +78,21 @@ impl EventHandler for Handler {
                             fundamentals_cmd::register_command(StatementType::CashFlow),
                         )
                         .await;
+                    let _ = guild_id
+                        .create_command(&ctx.http, fundamental_chart_cmd::register_command())
+                        .await;
                     let _ = guild_id
                         .create_command(&ctx.http, quotes_cmd::register_command())
                         .await;
+                    let _ = guild_id
+                        .create_command(&ctx.http, ratios_cmd::register_command())
+                        .await;
+                    let _ = guild_id
+                        .create_command(&ctx.http, compare_cmd::register_command())
+                        .await;
+                    let _ = guild_id
+                        .create_command(&ctx.http, dcf_cmd::register_command())
+                        .await;
                     let _ = guild_id
                         .create_command(&ctx.http, holders_cmd::register_command())
                         .await;
@@ -87,6 +108,27 @@ impl EventHandler for Handler {
                     let _ = guild_id
                         .create_command(&ctx.http, earnings_cmd::register_after_daily_command())
                         .await;
+                    let _ = guild_id
+                        .create_command(&ctx.http, earnings_cmd::register_history_command())
+                        .await;
+                    let _ = guild_id
+                        .create_command(&ctx.http, earnings_cmd::register_subscription_command())
+                        .await;
+                    let _ = guild_id
+                        .create_command(&ctx.http, earnings_cmd::register_theme_command())
+                        .await;
+                    let _ = guild_id
+                        .create_command(&ctx.http, earnings_season_cmd::register_command())
+                        .await;
+                    let _ = guild_id
+                        .create_command(&ctx.http, options_cmd::register_command())
+                        .await;
+                    let _ = guild_id
+                        .create_command(&ctx.http, iv_cmd::register_command())
+                        .await;
+                    let _ = guild_id
+                        .create_command(&ctx.http, strategy_cmd::register_command())
+                        .await;
                     info!("Guild commands registered for guild ID: {}", guild_id);
                 }
                 info!(
@@ -113,11 +155,29 @@ impl EventHandler for Handler {
         // Start SPY options pinger (every 15 minutes) if configured
         options_data::spawn_options_pinger(ctx.http.clone(), self.finance.clone(), self.cache.clone());
         // Start daily earnings poster
-        earnings::spawn_earnings_poster(ctx.http.clone(), self.finance.clone());
+        earnings::spawn_earnings_poster(ctx.http.clone(), self.finance.clone(), self.cache.clone());
         // Start daily earnings (IV/IM) poster at 6pm ET
-        earnings::spawn_daily_report_poster(ctx.http.clone(), self.finance.clone());
+        earnings::spawn_daily_report_poster(
+            ctx.http.clone(),
+            self.finance.clone(),
+            self.cache.clone(),
+        );
+        // Start daily IV recorder (IV rank/percentile history) at 4:15pm ET
+        options_data::spawn_iv_recorder(self.finance.clone(), self.cache.clone());
         // Start post-earnings (actuals) poster at 8:45am ET (BMO) and 5:50pm ET (AMC)
-        earnings::spawn_after_daily_poster(ctx.http.clone(), self.finance.clone());
+        earnings::spawn_after_daily_poster(
+            ctx.http.clone(),
+            self.finance.clone(),
+            self.cache.clone(),
+        );
+        // Start earnings reminders for /earnings subscribe (eve, report time, results)
+        earnings::spawn_earnings_reminders(
+            ctx.http.clone(),
+            self.finance.clone(),
+            self.cache.clone(),
+        );
+        // Start insider trading feed (new open-market buys/sales and cluster buys)
+        insiders::spawn_insider_feed(ctx.http.clone(), self.finance.clone(), self.cache.clone());
     }
 
     async fn interaction_create(&self, ctx: Context, interaction: Interaction) {
@@ -143,18 +203,22 @@ impl EventHandler for Handler {
                         .await;
 
                     // Now we have up to 15 minutes to send the actual response
-                    let content = match fundamentals_cmd::handle(&command, &self.finance).await {
-                        Ok(msg) => msg,
-                        Err(err) => format!("❌ {}", err),
+                    let edit = match fundamentals_cmd::handle(&command, &self.finance).await {
+                        Ok(resp) => {
+                            let mut edit =
+                                serenity::all::EditInteractionResponse::new().content(resp.content);
+                            if let Some(file) = resp.export {
+                                let attachment = CreateAttachment::bytes(file.bytes, file.filename);
+                                edit = edit.attachments(EditAttachments::new().add(attachment));
+                            }
+                            edit
+                        }
+                        Err(err) => serenity::all::EditInteractionResponse::new()
+                            .content(format!("❌ {}", err)),
                     };
 
                     // Send the follow-up message
-                    let _ = command
-                        .edit_response(
-                            &ctx.http,
-                            serenity::all::EditInteractionResponse::new().content(content),
-                        )
-                        .await;
+                    let _ = command.edit_response(&ctx.http, edit).await;
                 }
                 "quote" => {
                     let _ = command
@@ -164,7 +228,13 @@ impl EventHandler for Handler {
                         )
                         .await;
 
-                    let content = match quotes_cmd::handle(&command, &self.finance).await {
+                    let content = match quotes_cmd::handle(
+                        &command,
+                        &self.finance,
+                        self.cache.as_deref(),
+                    )
+                    .await
+                    {
                         Ok(msg) => msg,
                         Err(err) => format!("❌ {}", err),
                     };
@@ -184,17 +254,31 @@ impl EventHandler for Handler {
                         )
                         .await;
 
-                    let content = match holders_cmd::handle(&command, &self.finance).await {
-                        Ok(msg) => msg,
-                        Err(err) => format!("❌ {}", err),
+                    let response = match holders_cmd::handle(&command, &self.finance).await {
+                        Ok(resp) => resp,
+                        Err(err) => {
+                            let _ = command
+                                .edit_response(
+                                    &ctx.http,
+                                    serenity::all::EditInteractionResponse::new()
+                                        .content(format!("❌ {}", err)),
+                                )
+                                .await;
+                            return;
+                        }
                     };
 
-                    let _ = command
-                        .edit_response(
-                            &ctx.http,
-                            serenity::all::EditInteractionResponse::new().content(content),
-                        )
-                        .await;
+                    let mut edit = serenity::all::EditInteractionResponse::new()
+                        .content(response.content)
+                        .components(response.components);
+
+                    if let Some(bytes) = response.image {
+                        let attachment = CreateAttachment::bytes(bytes, "holders.png");
+                        let attachments = EditAttachments::new().add(attachment);
+                        edit = edit.attachments(attachments);
+                    }
+
+                    let _ = command.edit_response(&ctx.http, edit).await;
                 }
                 "news" => {
                     let _ = command
@@ -224,7 +308,12 @@ impl EventHandler for Handler {
                         )
                         .await;
 
-                    let response = match earnings_cmd::handle_weekly(&command, &self.finance).await
+                    let response = match earnings_cmd::handle_weekly(
+                        &command,
+                        &self.finance,
+                        self.cache.as_deref(),
+                    )
+                    .await
                     {
                         Ok(resp) => resp,
                         Err(err) => {
@@ -239,12 +328,15 @@ impl EventHandler for Handler {
                         }
                     };
 
-                    let mut edit =
-                        serenity::all::EditInteractionResponse::new().content(response.content);
+                    let mut edit = serenity::all::EditInteractionResponse::new()
+                        .content(response.content)
+                        .components(response.components);
 
-                    if let Some(bytes) = response.image {
-                        let attachment = CreateAttachment::bytes(bytes, "earnings-calendar.png");
-                        let attachments = EditAttachments::new().add(attachment);
+                    if !response.images.is_empty() {
+                        let attachments =
+                            earnings::calendar_attachments(response.images, "earnings-calendar")
+                                .into_iter()
+                                .fold(EditAttachments::new(), |acc, a| acc.add(a));
                         edit = edit.attachments(attachments);
                     }
 
@@ -261,6 +353,7 @@ impl EventHandler for Handler {
                     let content = match earnings_cmd::handle_daily(
                         &command,
                         &self.finance,
+                        self.cache.as_deref(),
                         &ctx.http,
                     )
                     .await
@@ -284,10 +377,40 @@ impl EventHandler for Handler {
                         )
                         .await;
 
-                    let content =
-                        match earnings_cmd::handle_after_daily(&command, &self.finance, &ctx.http)
-                            .await
-                        {
+                    let content = match earnings_cmd::handle_after_daily(
+                        &command,
+                        &self.finance,
+                        self.cache.as_deref(),
+                        &ctx.http,
+                    )
+                    .await
+                    {
+                        Ok(msg) => msg,
+                        Err(err) => format!("❌ {}", err),
+                    };
+
+                    let _ = command
+                        .edit_response(
+                            &ctx.http,
+                            serenity::all::EditInteractionResponse::new().content(content),
+                        )
+                        .await;
+                }
+                "earnings-history" => {
+                    let _ = command
+                        .create_response(
+                            &ctx.http,
+                            CreateInteractionResponse::Defer(Default::default()),
+                        )
+                        .await;
+
+                    let content = match earnings_cmd::handle_history(
+                        &command,
+                        &self.finance,
+                        self.cache.as_deref(),
+                    )
+                    .await
+                    {
                         Ok(msg) => msg,
                         Err(err) => format!("❌ {}", err),
                     };
@@ -299,6 +422,322 @@ impl EventHandler for Handler {
                         )
                         .await;
                 }
+                "earnings-season" => {
+                    let _ = command
+                        .create_response(
+                            &ctx.http,
+                            CreateInteractionResponse::Defer(Default::default()),
+                        )
+                        .await;
+
+                    let content = match earnings_season_cmd::handle(
+                        &command,
+                        &self.finance,
+                        self.cache.as_deref(),
+                    )
+                    .await
+                    {
+                        Ok(msg) => msg,
+                        Err(err) => format!("❌ {}", err),
+                    };
+
+                    let _ = command
+                        .edit_response(
+                            &ctx.http,
+                            serenity::all::EditInteractionResponse::new().content(content),
+                        )
+                        .await;
+                }
+                "earnings" => {
+                    let _ = command
+                        .create_response(
+                            &ctx.http,
+                            CreateInteractionResponse::Defer(
+                                CreateInteractionResponseMessage::new().ephemeral(true),
+                            ),
+                        )
+                        .await;
+
+                    let content = match earnings_cmd::handle_subscription(
+                        &command,
+                        &self.finance,
+                        self.cache.as_deref(),
+                    )
+                    .await
+                    {
+                        Ok(msg) => msg,
+                        Err(err) => format!("❌ {}", err),
+                    };
+
+                    let _ = command
+                        .edit_response(
+                            &ctx.http,
+                            serenity::all::EditInteractionResponse::new().content(content),
+                        )
+                        .await;
+                }
+                "earnings-theme" => {
+                    let _ = command
+                        .create_response(
+                            &ctx.http,
+                            CreateInteractionResponse::Defer(
+                                CreateInteractionResponseMessage::new().ephemeral(true),
+                            ),
+                        )
+                        .await;
+
+                    let content =
+                        match earnings_cmd::handle_theme(&command, self.cache.as_deref()).await {
+                            Ok(msg) => msg,
+                            Err(err) => format!("❌ {}", err),
+                        };
+
+                    let _ = command
+                        .edit_response(
+                            &ctx.http,
+                            serenity::all::EditInteractionResponse::new().content(content),
+                        )
+                        .await;
+                }
+                "iv" => {
+                    let _ = command
+                        .create_response(
+                            &ctx.http,
+                            CreateInteractionResponse::Defer(Default::default()),
+                        )
+                        .await;
+
+                    let response = match iv_cmd::handle(
+                        &command,
+                        &self.finance,
+                        self.cache.as_deref(),
+                    )
+                    .await
+                    {
+                        Ok(resp) => resp,
+                        Err(err) => {
+                            let _ = command
+                                .edit_response(
+                                    &ctx.http,
+                                    serenity::all::EditInteractionResponse::new()
+                                        .content(format!("❌ {}", err)),
+                                )
+                                .await;
+                            return;
+                        }
+                    };
+
+                    let mut edit =
+                        serenity::all::EditInteractionResponse::new().content(response.content);
+
+                    if let Some(bytes) = response.image {
+                        let attachment = CreateAttachment::bytes(bytes, "iv-term-structure.png");
+                        let attachments = EditAttachments::new().add(attachment);
+                        edit = edit.attachments(attachments);
+                    }
+
+                    let _ = command.edit_response(&ctx.http, edit).await;
+                }
+                "strategy" => {
+                    let _ = command
+                        .create_response(
+                            &ctx.http,
+                            CreateInteractionResponse::Defer(Default::default()),
+                        )
+                        .await;
+
+                    let response = match strategy_cmd::handle(&command, &self.finance).await {
+                        Ok(resp) => resp,
+                        Err(err) => {
+                            let _ = command
+                                .edit_response(
+                                    &ctx.http,
+                                    serenity::all::EditInteractionResponse::new()
+                                        .content(format!("❌ {}", err)),
+                                )
+                                .await;
+                            return;
+                        }
+                    };
+
+                    let mut edit =
+                        serenity::all::EditInteractionResponse::new().content(response.content);
+
+                    if let Some(bytes) = response.image {
+                        let attachment = CreateAttachment::bytes(bytes, "strategy-payoff.png");
+                        let attachments = EditAttachments::new().add(attachment);
+                        edit = edit.attachments(attachments);
+                    }
+
+                    let _ = command.edit_response(&ctx.http, edit).await;
+                }
+                "ratios" => {
+                    let _ = command
+                        .create_response(
+                            &ctx.http,
+                            CreateInteractionResponse::Defer(Default::default()),
+                        )
+                        .await;
+
+                    let response = match ratios_cmd::handle(&command, &self.finance).await {
+                        Ok(resp) => resp,
+                        Err(err) => {
+                            let _ = command
+                                .edit_response(
+                                    &ctx.http,
+                                    serenity::all::EditInteractionResponse::new()
+                                        .content(format!("❌ {}", err)),
+                                )
+                                .await;
+                            return;
+                        }
+                    };
+
+                    let mut edit =
+                        serenity::all::EditInteractionResponse::new().content(response.content);
+
+                    if let Some(bytes) = response.image {
+                        let attachment = CreateAttachment::bytes(bytes, "ratios.png");
+                        let attachments = EditAttachments::new().add(attachment);
+                        edit = edit.attachments(attachments);
+                    }
+
+                    let _ = command.edit_response(&ctx.http, edit).await;
+                }
+                "dcf" => {
+                    let _ = command
+                        .create_response(
+                            &ctx.http,
+                            CreateInteractionResponse::Defer(Default::default()),
+                        )
+                        .await;
+
+                    let response = match dcf_cmd::handle(&command, &self.finance).await {
+                        Ok(resp) => resp,
+                        Err(err) => {
+                            let _ = command
+                                .edit_response(
+                                    &ctx.http,
+                                    serenity::all::EditInteractionResponse::new()
+                                        .content(format!("❌ {}", err)),
+                                )
+                                .await;
+                            return;
+                        }
+                    };
+
+                    let mut edit =
+                        serenity::all::EditInteractionResponse::new().content(response.content);
+
+                    if let Some(bytes) = response.image {
+                        let attachment = CreateAttachment::bytes(bytes, "dcf.png");
+                        let attachments = EditAttachments::new().add(attachment);
+                        edit = edit.attachments(attachments);
+                    }
+
+                    let _ = command.edit_response(&ctx.http, edit).await;
+                }
+                "compare" => {
+                    let _ = command
+                        .create_response(
+                            &ctx.http,
+                            CreateInteractionResponse::Defer(Default::default()),
+                        )
+                        .await;
+
+                    let response = match compare_cmd::handle(&command, &self.finance).await {
+                        Ok(resp) => resp,
+                        Err(err) => {
+                            let _ = command
+                                .edit_response(
+                                    &ctx.http,
+                                    serenity::all::EditInteractionResponse::new()
+                                        .content(format!("❌ {}", err)),
+                                )
+                                .await;
+                            return;
+                        }
+                    };
+
+                    let mut edit =
+                        serenity::all::EditInteractionResponse::new().content(response.content);
+
+                    if let Some(bytes) = response.image {
+                        let attachment = CreateAttachment::bytes(bytes, "compare.png");
+                        let attachments = EditAttachments::new().add(attachment);
+                        edit = edit.attachments(attachments);
+                    }
+
+                    let _ = command.edit_response(&ctx.http, edit).await;
+                }
+                "fundamental-chart" => {
+                    let _ = command
+                        .create_response(
+                            &ctx.http,
+                            CreateInteractionResponse::Defer(Default::default()),
+                        )
+                        .await;
+
+                    let response =
+                        match fundamental_chart_cmd::handle(&command, &self.finance).await {
+                            Ok(resp) => resp,
+                            Err(err) => {
+                                let _ = command
+                                    .edit_response(
+                                        &ctx.http,
+                                        serenity::all::EditInteractionResponse::new()
+                                            .content(format!("❌ {}", err)),
+                                    )
+                                    .await;
+                                return;
+                            }
+                        };
+
+                    let mut edit =
+                        serenity::all::EditInteractionResponse::new().content(response.content);
+
+                    if let Some(bytes) = response.image {
+                        let attachment = CreateAttachment::bytes(bytes, "fundamental-chart.png");
+                        let attachments = EditAttachments::new().add(attachment);
+                        edit = edit.attachments(attachments);
+                    }
+
+                    let _ = command.edit_response(&ctx.http, edit).await;
+                }
+                "options" => {
+                    let _ = command
+                        .create_response(
+                            &ctx.http,
+                            CreateInteractionResponse::Defer(Default::default()),
+                        )
+                        .await;
+
+                    let response =
+                        match options_cmd::handle(&command, self.cache.as_deref()).await {
+                            Ok(resp) => resp,
+                            Err(err) => {
+                                let _ = command
+                                    .edit_response(
+                                        &ctx.http,
+                                        serenity::all::EditInteractionResponse::new()
+                                            .content(format!("❌ {}", err)),
+                                    )
+                                    .await;
+                                return;
+                            }
+                        };
+
+                    let mut edit =
+                        serenity::all::EditInteractionResponse::new().content(response.content);
+
+                    if let Some(bytes) = response.image {
+                        let attachment = CreateAttachment::bytes(bytes, "spy_options_history.png");
+                        let attachments = EditAttachments::new().add(attachment);
+                        edit = edit.attachments(attachments);
+                    }
+
+                    let _ = command.edit_response(&ctx.http, edit).await;
+                }
                 _ => {
                     let _ = command
                         .create_response(
@@ -311,6 +750,71 @@ impl EventHandler for Handler {
                         .await;
                 }
             }
+        } else if let Interaction::Component(component) = interaction {
+            // Page buttons on the /weekly-earnings list view
+            if component
+                .data
+                .custom_id
+                .starts_with(earnings_cmd::WEEKLY_PAGE_PREFIX)
+            {
+                let _ = component
+                    .create_response(&ctx.http, CreateInteractionResponse::Acknowledge)
+                    .await;
+
+                let edit = match earnings_cmd::handle_weekly_page(
+                    &component.data.custom_id,
+                    &self.finance,
+                    self.cache.as_deref(),
+                )
+                .await
+                {
+                    Ok(resp) => serenity::all::EditInteractionResponse::new()
+                        .content(resp.content)
+                        .components(resp.components),
+                    Err(err) => serenity::all::EditInteractionResponse::new()
+                        .content(format!("❌ {}", err))
+                        .components(Vec::new()),
+                };
+
+                let _ = component.edit_response(&ctx.http, edit).await;
+            } else if component
+                .data
+                .custom_id
+                .starts_with(holders_cmd::HOLDERS_PAGE_PREFIX)
+            {
+                // Page buttons on the /holders images
+                let _ = component
+                    .create_response(&ctx.http, CreateInteractionResponse::Acknowledge)
+                    .await;
+
+                let edit = match holders_cmd::handle_page(&component.data.custom_id, &self.finance)
+                    .await
+                {
+                    Ok(resp) => {
+                        let mut attachments = EditAttachments::new();
+                        if let Some(bytes) = resp.image {
+                            attachments =
+                                attachments.add(CreateAttachment::bytes(bytes, "holders.png"));
+                        }
+                        serenity::all::EditInteractionResponse::new()
+                            .content(resp.content)
+                            .components(resp.components)
+                            .attachments(attachments)
+                    }
+                    Err(err) => serenity::all::EditInteractionResponse::new()
+                        .content(format!("❌ {}", err))
+                        .components(Vec::new()),
+                };
+
+                let _ = component.edit_response(&ctx.http, edit).await;
+            }
+        } else if let Interaction::Autocomplete(command) = interaction {
+            // Metric suggestions for /income, /balance and /cashflow
+            if let Some(response) = fundamentals_cmd::autocomplete(&command) {
+                let _ = command
+                    .create_response(&ctx.http, CreateInteractionResponse::Autocomplete(response))
+                    .await;
+            }
         }
     }
 
@@ -333,7 +837,16 @@ impl EventHandler for Handler {
             return;
         }
 
-        match mention_cmd::handle(rest, &ctx.http, msg.channel_id, &self.finance).await {
+        match mention_cmd::handle(
+            rest,
+            &ctx.http,
+            msg.channel_id,
+            msg.author.id,
+            &self.finance,
+            self.cache.as_deref(),
+        )
+        .await
+        {
             Ok(resp) => {
                 // Send a placeholder message immediately, then edit with the real response.
                 let mut placeholder = match msg
@@ -349,8 +862,11 @@ impl EventHandler for Handler {
                 };
 
                 let mut edit = EditMessage::new().content(resp.content);
-                if let Some(attachment) = resp.attachment {
-                    let attachments = EditAttachments::new().add(attachment);
+                if !resp.attachments.is_empty() {
+                    let attachments = resp
+                        .attachments
+                        .into_iter()
+                        .fold(EditAttachments::new(), |acc, a| acc.add(a));
                     edit = edit.attachments(attachments);
                 }
 
@@ -385,7 +901,12 @@ async fn register_global_commands(ctx: &Context) {
         fundamentals_cmd::register_command(StatementType::CashFlow),
     )
     .await;
+    let _ =
+        Command::create_global_command(&ctx.http, fundamental_chart_cmd::register_command()).await;
     let _ = Command::create_global_command(&ctx.http, quotes_cmd::register_command()).await;
+    let _ = Command::create_global_command(&ctx.http, ratios_cmd::register_command()).await;
+    let _ = Command::create_global_command(&ctx.http, compare_cmd::register_command()).await;
+    let _ = Command::create_global_command(&ctx.http, dcf_cmd::register_command()).await;
     let _ = Command::create_global_command(&ctx.http, holders_cmd::register_command()).await;
     let _ = Command::create_global_command(&ctx.http, news_cmd::register_command()).await;
     let _ = Command::create_global_command(&ctx.http, earnings_cmd::register_weekly_command())
@@ -397,6 +918,19 @@ async fn register_global_commands(ctx: &Context) {
         earnings_cmd::register_after_daily_command(),
     )
     .await;
+    let _ = Command::create_global_command(&ctx.http, earnings_cmd::register_history_command())
+        .await;
+    let _ = Command::create_global_command(
+        &ctx.http,
+        earnings_cmd::register_subscription_command(),
+    )
+    .await;
+    let _ = Command::create_global_command(&ctx.http, earnings_cmd::register_theme_command()).await;
+    let _ =
+        Command::create_global_command(&ctx.http, earnings_season_cmd::register_command()).await;
+    let _ = Command::create_global_command(&ctx.http, options_cmd::register_command()).await;
+    let _ = Command::create_global_command(&ctx.http, iv_cmd::register_command()).await;
+    let _ = Command::create_global_command(&ctx.http, strategy_cmd::register_command()).await;
 }
 
 #[tokio::main]
@@ -428,6 +962,9 @@ async fn main() -> Result<()> {
         }
     };
 
+    // Yahoo earnings fallback rescans the watchlist (env + Redis set) and subscribed symbols
+    finance.set_earnings_watchlist(Arc::new(watchlist::EarningsWatchlist::new(cache.clone())));
+
     info!("Starting Discord client...");
     let mut client = Client::builder(token, intents)
         .application_id(app_id)
@@ -1,6 +1,165 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, Utc};
 use serde::{Deserialize, Serialize};
 
+/// When a company reports relative to the regular session (US Eastern).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum EarningsSession {
+    /// Before 09:30 ET.
+    #[serde(rename = "BMO")]
+    BeforeOpen,
+    /// 09:30–16:00 ET.
+    #[serde(rename = "DMH")]
+    DuringMarket,
+    /// 16:00 ET or later.
+    #[serde(rename = "AMC")]
+    AfterClose,
+    /// No usable time from the provider.
+    #[default]
+    #[serde(rename = "TBA")]
+    Unknown,
+}
+
+/// Whole-word phrases (after `-`/`_` become spaces) that name a session outright.
+const BEFORE_OPEN_HINTS: &[&str] = &[
+    "bmo",
+    "pre market",
+    "premarket",
+    "pre",
+    "before market",
+    "before open",
+    "before the open",
+];
+const AFTER_CLOSE_HINTS: &[&str] = &[
+    "amc",
+    "after close",
+    "after the close",
+    "after market",
+    "after hours",
+    "afterhours",
+    "post market",
+    "postmarket",
+    "post",
+];
+const DURING_MARKET_HINTS: &[&str] = &["dmh", "during market", "intraday"];
+
+impl EarningsSession {
+    /// Classify a provider time hint: keywords (`BMO`, `after-hours`, `pre market`, …) as
+    /// whole words, then clock times (`16:05:00`, `4:30 PM`). Midnight is treated as a
+    /// placeholder for "no time".
+    pub fn classify(time: Option<&str>) -> Self {
+        let Some(raw) = time else {
+            return EarningsSession::Unknown;
+        };
+        let normalized: String = raw
+            .trim()
+            .to_ascii_lowercase()
+            .chars()
+            .map(|c| if c == '-' || c == '_' { ' ' } else { c })
+            .collect();
+        let words: Vec<&str> = normalized.split_whitespace().collect();
+        let padded = format!(" {} ", words.join(" "));
+        let has = |phrase: &str| padded.contains(&format!(" {} ", phrase));
+
+        if BEFORE_OPEN_HINTS.iter().any(|p| has(p)) {
+            return EarningsSession::BeforeOpen;
+        }
+        if AFTER_CLOSE_HINTS.iter().any(|p| has(p)) {
+            return EarningsSession::AfterClose;
+        }
+        if DURING_MARKET_HINTS.iter().any(|p| has(p)) {
+            return EarningsSession::DuringMarket;
+        }
+
+        match report_clock(Some(raw)) {
+            Some(t) if t < market_open() => EarningsSession::BeforeOpen,
+            Some(t) if t >= market_close() => EarningsSession::AfterClose,
+            Some(_) => EarningsSession::DuringMarket,
+            None => EarningsSession::Unknown,
+        }
+    }
+
+    /// Short label used in reports: `BMO`, `DMH`, `AMC`, `TBA`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EarningsSession::BeforeOpen => "BMO",
+            EarningsSession::DuringMarket => "DMH",
+            EarningsSession::AfterClose => "AMC",
+            EarningsSession::Unknown => "TBA",
+        }
+    }
+
+    /// Parse a label produced by `as_str` (case-insensitive).
+    pub fn from_label(label: &str) -> Option<Self> {
+        match label.trim().to_ascii_uppercase().as_str() {
+            "BMO" => Some(EarningsSession::BeforeOpen),
+            "DMH" => Some(EarningsSession::DuringMarket),
+            "AMC" => Some(EarningsSession::AfterClose),
+            "TBA" => Some(EarningsSession::Unknown),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for EarningsSession {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+fn market_open() -> NaiveTime {
+    NaiveTime::from_hms_opt(9, 30, 0).unwrap_or_default()
+}
+
+fn market_close() -> NaiveTime {
+    NaiveTime::from_hms_opt(16, 0, 0).unwrap_or_default()
+}
+
+/// Clock time in a provider hint: `16:05:00`, `07:30`, `16`, `4:30 PM`, `8am`. Midnight and
+/// anything unparseable yield `None`.
+pub fn report_clock(time: Option<&str>) -> Option<NaiveTime> {
+    let t = time?.trim().to_ascii_lowercase();
+    let (body, meridiem) = match (t.strip_suffix("am"), t.strip_suffix("pm")) {
+        (Some(b), _) => (b.trim_end_matches('.').trim(), Some(false)),
+        (_, Some(b)) => (b.trim_end_matches('.').trim(), Some(true)),
+        _ => (t.as_str(), None),
+    };
+
+    let mut parts = body.split(':');
+    let hour_str = parts.next()?;
+    if hour_str.is_empty() || hour_str.len() > 2 || !hour_str.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let mut hour: u32 = hour_str.parse().ok()?;
+    let minute: u32 = match parts.next() {
+        Some(m) if m.len() == 2 => m.parse().ok()?,
+        Some(_) => return None,
+        None => 0,
+    };
+    let second: u32 = match parts.next() {
+        Some(s) if s.len() == 2 => s.parse().ok()?,
+        Some(_) => return None,
+        None => 0,
+    };
+    if parts.next().is_some() {
+        return None;
+    }
+
+    if let Some(pm) = meridiem {
+        if !(1..=12).contains(&hour) {
+            return None;
+        }
+        hour = match (pm, hour) {
+            (false, 12) => 0,
+            (false, h) => h,
+            (true, 12) => 12,
+            (true, h) => h + 12,
+        };
+    }
+
+    let clock = NaiveTime::from_hms_opt(hour, minute, second)?;
+    (clock != NaiveTime::MIN).then_some(clock)
+}
+
 /// Earnings event used by the bot for calendar displays.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EarningsEvent {
@@ -10,6 +169,9 @@ pub struct EarningsEvent {
     pub date_end: Option<DateTime<Utc>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub time_of_day: Option<String>, // e.g., BMO/AMC if known
+    /// Session derived from `time_of_day` when the event is ingested.
+    #[serde(default)]
+    pub session: EarningsSession,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub eps_estimate: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -27,3 +189,145 @@ pub struct EarningsEvent {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub logo: Option<String>, // Base64 encoded logo data from API
 }
+
+/// How a reported number compared with consensus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SurpriseVerdict {
+    Beat,
+    Inline,
+    Miss,
+}
+
+impl SurpriseVerdict {
+    /// Classify a surprise percentage; anything within ±`inline_band` is in line.
+    pub fn from_surprise(surprise_pct: f64, inline_band: f64) -> Self {
+        if surprise_pct > inline_band {
+            SurpriseVerdict::Beat
+        } else if surprise_pct < -inline_band {
+            SurpriseVerdict::Miss
+        } else {
+            SurpriseVerdict::Inline
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SurpriseVerdict::Beat => "Beat",
+            SurpriseVerdict::Inline => "Inline",
+            SurpriseVerdict::Miss => "Miss",
+        }
+    }
+
+    pub fn badge(&self) -> &'static str {
+        match self {
+            SurpriseVerdict::Beat => "✅ Beat",
+            SurpriseVerdict::Inline => "➖ Inline",
+            SurpriseVerdict::Miss => "❌ Miss",
+        }
+    }
+}
+
+/// Consensus for the quarter after the one just reported; revisions right after the
+/// report are the closest proxy for guidance Yahoo exposes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EstimateOutlook {
+    pub period_end: NaiveDate,
+    pub eps_estimate: Option<f64>,
+    pub eps_estimate_7d_ago: Option<f64>,
+    pub revenue_estimate: Option<f64>,
+}
+
+/// Actuals vs estimates for the fiscal quarter covered by a report.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EarningsResult {
+    pub symbol: String,
+    pub fiscal_quarter_end: NaiveDate,
+    pub eps_actual: Option<f64>,
+    pub eps_estimate: Option<f64>,
+    pub revenue_actual: Option<f64>,
+    pub revenue_estimate: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outlook: Option<EstimateOutlook>,
+}
+
+impl EarningsResult {
+    pub fn eps_surprise_pct(&self) -> Option<f64> {
+        surprise_pct(self.eps_actual?, self.eps_estimate?)
+    }
+
+    pub fn revenue_surprise_pct(&self) -> Option<f64> {
+        surprise_pct(self.revenue_actual?, self.revenue_estimate?)
+    }
+}
+
+/// Percent difference of `actual` over `estimate`, relative to |estimate|.
+pub fn surprise_pct(actual: f64, estimate: f64) -> Option<f64> {
+    (estimate != 0.0).then(|| (actual - estimate) / estimate.abs() * 100.0)
+}
+
+/// A reporting season, named after the calendar quarter being reported: results published
+/// in Oct–Dec 2026 make up the Q3 2026 season.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct EarningsSeason {
+    pub year: i32,
+    /// Reported quarter, 1–4.
+    pub quarter: u32,
+}
+
+impl EarningsSeason {
+    /// Season whose reports are published on `date`.
+    pub fn for_report_date(date: NaiveDate) -> Self {
+        let published = (date.month0() / 3) + 1;
+        if published == 1 {
+            Self {
+                year: date.year() - 1,
+                quarter: 4,
+            }
+        } else {
+            Self {
+                year: date.year(),
+                quarter: published - 1,
+            }
+        }
+    }
+
+    /// First day reports for this season are published.
+    pub fn start(&self) -> NaiveDate {
+        let (year, month) = if self.quarter == 4 {
+            (self.year + 1, 1)
+        } else {
+            (self.year, self.quarter * 3 + 1)
+        };
+        NaiveDate::from_ymd_opt(year, month, 1).expect("valid season start")
+    }
+
+    /// Last day reports for this season are published.
+    pub fn end(&self) -> NaiveDate {
+        self.next().start().pred_opt().expect("valid season end")
+    }
+
+    pub fn next(&self) -> Self {
+        if self.quarter == 4 {
+            Self {
+                year: self.year + 1,
+                quarter: 1,
+            }
+        } else {
+            Self {
+                year: self.year,
+                quarter: self.quarter + 1,
+            }
+        }
+    }
+
+    /// Compact form used in Redis keys, e.g. `2026Q3`.
+    pub fn key(&self) -> String {
+        format!("{}Q{}", self.year, self.quarter)
+    }
+}
+
+impl std::fmt::Display for EarningsSeason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Q{} {}", self.quarter, self.year)
+    }
+}
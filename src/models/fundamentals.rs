@@ -1,3 +1,4 @@
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -54,12 +55,63 @@ pub struct FinancialStatement {
 }
 
 /// Bot-facing financial snapshot for a ticker (used in Discord responses).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct FinancialSummary {
     pub symbol: String,
+    /// Latest fiscal-year revenue.
     pub revenue: Option<f64>,
     pub eps: Option<f64>,
     pub pe_ratio: Option<f64>,
     pub market_cap: Option<f64>,
     pub currency: Option<String>,
+    pub price: Option<f64>,
+    /// Market cap plus total debt less cash, from the latest balance sheet.
+    pub enterprise_value: Option<f64>,
+    /// Latest ordinary shares outstanding, else diluted average shares.
+    pub shares_outstanding: Option<f64>,
+    /// Total debt less cash and short-term investments, from the latest balance sheet.
+    pub net_debt: Option<f64>,
+    pub ttm: TtmFigures,
+    pub ratios: FinancialRatios,
+}
+
+/// Trailing-twelve-month sums of the last four reported quarters.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TtmFigures {
+    /// End of the latest quarter in the sums.
+    pub as_of: Option<NaiveDate>,
+    pub revenue: Option<f64>,
+    pub gross_profit: Option<f64>,
+    pub operating_income: Option<f64>,
+    pub pretax_income: Option<f64>,
+    pub tax_provision: Option<f64>,
+    pub net_income: Option<f64>,
+    pub ebitda: Option<f64>,
+    pub diluted_eps: Option<f64>,
+    pub operating_cash_flow: Option<f64>,
+    pub capital_expenditure: Option<f64>,
+    pub free_cash_flow: Option<f64>,
+}
+
+/// Derived ratios. Margins, returns and yields are percentages; the rest are multiples.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FinancialRatios {
+    pub gross_margin: Option<f64>,
+    pub operating_margin: Option<f64>,
+    pub net_margin: Option<f64>,
+    /// TTM net income over latest stockholders' equity.
+    pub roe: Option<f64>,
+    /// TTM after-tax operating income over latest invested capital.
+    pub roic: Option<f64>,
+    pub debt_to_equity: Option<f64>,
+    pub current_ratio: Option<f64>,
+    /// TTM free cash flow over market cap.
+    pub fcf_yield: Option<f64>,
+    pub ev_to_ebitda: Option<f64>,
+    /// P/E over annual diluted EPS growth (in percent).
+    pub peg: Option<f64>,
+    /// Latest fiscal year over the one before.
+    pub revenue_growth: Option<f64>,
+    /// Latest fiscal year's diluted EPS over the one before.
+    pub eps_growth: Option<f64>,
 }
@@ -18,6 +18,10 @@ pub struct HoldersOverview {
     pub insider_transactions: Option<Vec<InsiderTransaction>>,
     pub insider_purchases: Option<InsiderPurchase>,
     pub insider_roster: Option<Vec<InsiderRosterMember>>,
+    /// Change in each institution's or fund's position since its previous filing, as a
+    /// fraction, keyed by holder name.
+    #[serde(default)]
+    pub position_changes: HashMap<String, f64>,
 }
 
 /// Convenience helpers for formatting dates.
@@ -4,11 +4,16 @@ pub mod holders;
 pub mod news;
 pub mod quotes;
 
-pub use earnings::EarningsEvent;
-pub use fundamentals::{FinancialStatement, FinancialSummary, Frequency, StatementType};
+pub use earnings::{
+    EarningsEvent, EarningsResult, EarningsSeason, EarningsSession, EstimateOutlook,
+    SurpriseVerdict,
+};
+pub use fundamentals::{
+    FinancialRatios, FinancialStatement, FinancialSummary, Frequency, StatementType, TtmFigures,
+};
 pub use holders::{
     HolderType, HoldersOverview, InsiderPurchase, InsiderRosterMember, InsiderTransaction,
     InstitutionalHolder, MajorHoldersBreakdown, MutualFundHolder,
 };
 pub use news::NewsItem;
-pub use quotes::PriceQuote;
+pub use quotes::{PricePerformance, PriceQuote};
@@ -12,3 +12,13 @@ pub struct PriceQuote {
     pub pre_market_price: Option<f64>,
     pub after_hours_price: Option<f64>,
 }
+
+/// Trailing price returns in percent, measured from the last close on or before each
+/// lookback date to the latest close.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PricePerformance {
+    pub one_month: Option<f64>,
+    pub three_months: Option<f64>,
+    pub year_to_date: Option<f64>,
+    pub one_year: Option<f64>,
+}
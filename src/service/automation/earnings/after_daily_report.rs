@@ -1,30 +1,39 @@
 use std::env;
 use std::sync::Arc;
 
-use chrono::{Datelike, Duration, Timelike, Utc, Weekday};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Timelike, Utc, Weekday};
 use chrono_tz::America;
 use chrono_tz::America::New_York;
 use once_cell::sync::Lazy;
-use serenity::all::{CreateMessage, Http};
-use serenity::model::prelude::ChannelId;
+use serenity::all::{AutoArchiveDuration, CreateAttachment, CreateMessage, CreateThread, Http};
+use serenity::model::prelude::{ChannelId, MessageId};
 use tokio::sync::Mutex;
 use tokio::task::JoinHandle;
 use tracing::{info, warn};
 
+use super::reaction_cards::{rank_by_reaction, render_reaction_cards, ReactionCard};
+use crate::models::earnings::surprise_pct;
+use crate::models::{
+    EarningsEvent, EarningsResult, EarningsSession, EstimateOutlook, PriceQuote, SurpriseVerdict,
+};
+use crate::service::caching::collections::earnings_moves::{self, ActualMoveSource};
+use crate::service::caching::collections::earnings_season::{self, SeasonReport};
+use crate::service::caching::RedisCache;
+use crate::service::finance::earnings::INLINE_SURPRISE_PCT;
+use crate::service::finance::news::EarningsNewsDigest;
 use crate::service::finance::FinanceService;
 
-#[derive(Debug, Clone)]
-struct EarningsActuals {
-    eps_actual: Option<f64>,
-    revenue_actual: Option<f64>,
-}
-
 enum SessionTarget {
     Bmo,
     Amc,
     Waiting,
 }
 
+/// Discord's per-message character limit.
+const MESSAGE_LIMIT: usize = 2000;
+/// Regular-session close, ET hour.
+const SESSION_CLOSE_HOUR: u32 = 16;
+
 static LAST_AFTER_BMO_POST_DATE: Lazy<Mutex<Option<chrono::NaiveDate>>> =
     Lazy::new(|| Mutex::new(None));
 static LAST_AFTER_AMC_POST_DATE: Lazy<Mutex<Option<chrono::NaiveDate>>> =
@@ -53,6 +62,7 @@ fn resolve_channel_id(var_names: &[&str], feature_label: &str) -> Option<Channel
 pub fn spawn_after_daily_poster(
     http: Arc<Http>,
     finance: Arc<FinanceService>,
+    cache: Option<Arc<RedisCache>>,
 ) -> Option<JoinHandle<()>> {
     if env::var("ENABLE_EARNINGS_PINGER")
         .map(|v| v == "0")
@@ -85,13 +95,17 @@ pub fn spawn_after_daily_poster(
             }
 
             if should_post_bmo(&now_et).await {
-                if let Err(e) = send_after_daily_report(&http, &finance, channel_id).await {
+                if let Err(e) =
+                    send_after_daily_report(&http, &finance, cache.as_deref(), channel_id).await
+                {
                     warn!("after-daily BMO iteration failed: {e}");
                 }
             }
 
             if should_post_amc(&now_et).await {
-                if let Err(e) = send_after_daily_report(&http, &finance, channel_id).await {
+                if let Err(e) =
+                    send_after_daily_report(&http, &finance, cache.as_deref(), channel_id).await
+                {
                     warn!("after-daily AMC iteration failed: {e}");
                 }
             }
@@ -138,6 +152,7 @@ async fn should_post_amc(now_et: &chrono::DateTime<chrono_tz::Tz>) -> bool {
 pub async fn send_after_daily_report(
     http: &Http,
     finance: &FinanceService,
+    cache: Option<&RedisCache>,
     channel_id: ChannelId,
 ) -> Result<(), String> {
     let now_et = Utc::now().with_timezone(&New_York);
@@ -205,190 +220,465 @@ pub async fn send_after_daily_report(
         SessionTarget::Waiting => unreachable!(),
     };
 
-    let mut lines = Vec::new();
-    lines.push(format!(
+    let heading = format!(
         "📈 Post-earnings results ({}) — {} as of {} ET",
         date_labels,
         session_label,
         now_et.format("%-I:%M %p")
-    ));
-    lines.push(String::new());
-
-    let mut shown = 0usize;
+    );
 
-    for ev in events {
-        let ev_date = ev.date.date_naive();
-        if !target_dates.contains(&ev_date) {
-            continue;
+    let mut reporting: Vec<_> = events
+        .into_iter()
+        .filter(|ev| {
+            if !target_dates.contains(&ev.date.date_naive()) {
+                return false;
+            }
+            match session_target {
+                SessionTarget::Bmo => ev.session == EarningsSession::BeforeOpen,
+                // Intraday reporters have had the whole session to react by the AMC run
+                SessionTarget::Amc => matches!(
+                    ev.session,
+                    EarningsSession::AfterClose | EarningsSession::DuringMarket
+                ),
+                SessionTarget::Waiting => false,
+            }
+        })
+        .collect();
+    finance.fill_estimates(&mut reporting).await;
+    if let Some(cache) = cache {
+        let symbols: Vec<String> = reporting.iter().map(|ev| ev.symbol.clone()).collect();
+        let sectors = finance.get_sectors(&symbols).await;
+        if let Err(e) = earnings_season::record_scheduled(cache, &reporting, &sectors).await {
+            warn!("failed to record season calendar: {}", e);
         }
+    }
 
-        let session = classify_session(ev.time_of_day.as_deref());
+    let mut cards = Vec::with_capacity(reporting.len());
+    for ev in &reporting {
+        let ev_date = ev.date.date_naive();
+        let session = ev.session;
 
-        let matches_target = match session_target {
-            SessionTarget::Bmo => session == "BMO",
-            SessionTarget::Amc => session == "AMC",
-            SessionTarget::Waiting => false,
+        let result = match finance.get_earnings_result(&ev.symbol, ev_date).await {
+            Ok(r) => Some(r),
+            Err(e) => {
+                warn!("no results yet for {}: {}", ev.symbol, e);
+                None
+            }
         };
 
-        if !matches_target {
-            continue;
+        let quote = match finance.get_price(&ev.symbol).await {
+            Ok(quote) => Some(quote),
+            Err(e) => {
+                warn!("reaction quote failed for {}: {}", ev.symbol, e);
+                None
+            }
+        };
+        let reaction = quote
+            .as_ref()
+            .and_then(|q| extended_hours_move_pct(q, session));
+        let implied_move_pct = match cache {
+            Some(cache) => implied_vs_actual(cache, &ev.symbol, ev_date, reaction).await,
+            None => None,
+        };
+        if let (Some(cache), Some(result)) = (cache, result.as_ref()) {
+            let report = SeasonReport::new(result, ev_date, session, reaction);
+            if let Err(e) = earnings_season::record_report(cache, &report).await {
+                warn!("failed to record season result for {}: {}", ev.symbol, e);
+            }
         }
 
-        let actuals = fetch_latest_actuals(finance, &ev.symbol).await;
-        let eps_text = format_eps(actuals.as_ref().and_then(|a| a.eps_actual));
-        let rev_text = format_revenue(actuals.as_ref().and_then(|a| a.revenue_actual));
-
-        let date_str = ev_date.format("%Y-%m-%d").to_string();
-
-        lines.push(format!(
-            "{} [{} {}] — EPS {} | Revenue {}",
-            ev.symbol, session, date_str, eps_text, rev_text
-        ));
-        shown += 1;
+        cards.push(ReactionCard {
+            symbol: ev.symbol.clone(),
+            name: quote.map(|q| q.name).filter(|n| !n.is_empty()),
+            session,
+            date: ev_date,
+            result,
+            eps_estimate: ev.eps_estimate,
+            revenue_estimate: ev.revenue_estimate,
+            reaction_pct: reaction,
+            implied_move_pct,
+        });
     }
 
-    if shown == 0 {
-        lines.push(format!(
-            "No {} results detected yet for target dates ({}). If they just reported, retry in a few minutes.",
-            session_label, date_labels
-        ));
+    if cards.is_empty() {
+        let msg = format!(
+            "{}\n\nNo {} results detected yet for target dates ({}). If they just reported, retry in a few minutes.",
+            heading, session_label, date_labels
+        );
+        channel_id
+            .send_message(http, CreateMessage::new().content(msg))
+            .await
+            .map_err(|e| format!("failed to post after-daily earnings report: {e}"))?;
+        return Ok(());
     }
 
-    let content = lines.join("\n");
+    rank_by_reaction(&mut cards);
     info!(
-        "Posting after-daily earnings report ({} lines, {})",
-        lines.len(),
+        "Posting after-daily earnings report ({} companies, {})",
+        cards.len(),
         session_label
     );
 
-    channel_id
-        .send_message(http, CreateMessage::new().content(content))
-        .await
-        .map_err(|e| format!("failed to post after-daily earnings report: {e}"))?;
+    let title = format!(
+        "Post-earnings reactions — {} {}",
+        session_label, date_labels
+    );
+    let mut report_message = None;
+    match render_reaction_cards(&title, &cards, &reporting, cache).await {
+        Ok(images) => {
+            let pages = images.len();
+            let mut files = images.into_iter().enumerate().map(|(idx, bytes)| {
+                CreateAttachment::bytes(bytes, format!("earnings-reactions-{}.png", idx + 1))
+            });
+            // Discord caps attachments per message at 10
+            for batch in 0..pages.div_ceil(10) {
+                let attachments: Vec<_> = files.by_ref().take(10).collect();
+                let message = if batch == 0 {
+                    CreateMessage::new().content(heading.clone())
+                } else {
+                    CreateMessage::new()
+                };
+                let sent = channel_id
+                    .send_files(http, attachments, message)
+                    .await
+                    .map_err(|e| format!("failed to post after-daily earnings cards: {e}"))?;
+                report_message.get_or_insert(sent.id);
+            }
+        }
+        Err(render_err) => {
+            warn!("Falling back to text after-daily report: {}", render_err);
+            let mut lines = vec![heading, String::new()];
+            lines.extend(cards.iter().map(format_card_line));
+            for content in split_message(&lines, MESSAGE_LIMIT) {
+                let sent = channel_id
+                    .send_message(http, CreateMessage::new().content(content))
+                    .await
+                    .map_err(|e| format!("failed to post after-daily earnings report: {e}"))?;
+                report_message.get_or_insert(sent.id);
+            }
+        }
+    }
+
+    if let Some(message_id) = report_message {
+        if news_digest_enabled() {
+            let thread_name = format!("Earnings news — {} {}", session_label, date_labels);
+            post_news_digest(
+                http,
+                finance,
+                channel_id,
+                message_id,
+                &thread_name,
+                &reporting,
+            )
+            .await;
+        }
+    }
 
     Ok(())
 }
 
-async fn fetch_latest_actuals(finance: &FinanceService, symbol: &str) -> Option<EarningsActuals> {
-    let resp = match finance
-        .client()
-        .get_quote_summary(symbol, &["earnings"])
+fn news_digest_enabled() -> bool {
+    !env::var("ENABLE_EARNINGS_NEWS_DIGEST")
+        .map(|v| v == "0")
+        .unwrap_or(false)
+}
+
+/// Collect post-report headlines for the session's companies and post them, grouped by
+/// company, in a thread started from the report message. Failures are logged only; the
+/// report itself is already out.
+async fn post_news_digest(
+    http: &Http,
+    finance: &FinanceService,
+    channel_id: ChannelId,
+    message_id: MessageId,
+    thread_name: &str,
+    reporting: &[EarningsEvent],
+) {
+    let digests = finance.earnings_news_digest(reporting).await;
+    if digests.is_empty() {
+        info!("No post-earnings news to digest for {}", thread_name);
+        return;
+    }
+
+    let thread = match channel_id
+        .create_thread_from_message(
+            http,
+            message_id,
+            CreateThread::new(thread_name).auto_archive_duration(AutoArchiveDuration::OneDay),
+        )
         .await
     {
-        Ok(v) => v,
+        Ok(thread) => thread,
         Err(e) => {
-            warn!("earnings fetch failed for {}: {}", symbol, e);
-            return None;
+            warn!("failed to start earnings news thread: {e}");
+            return;
         }
     };
 
-    let root = resp
-        .get("quoteSummary")
-        .and_then(|q| q.get("result"))
-        .and_then(|r| r.as_array())
-        .and_then(|arr| arr.first())?;
-
-    let earnings = root.get("earnings")?;
-
-    let eps_actual = earnings
-        .get("earningsChart")
-        .and_then(|c| c.get("quarterly"))
-        .and_then(|arr| arr.as_array())
-        .and_then(|arr| arr.last())
-        .and_then(|entry| entry.get("actual"))
-        .and_then(|v| v.get("raw").and_then(|r| r.as_f64()).or_else(|| v.as_f64()));
-
-    let revenue_actual = earnings
-        .get("financialsChart")
-        .and_then(|c| c.get("quarterly"))
-        .and_then(|arr| arr.as_array())
-        .and_then(|arr| arr.last())
-        .and_then(|entry| entry.get("revenue"))
-        .and_then(|v| v.get("raw").and_then(|r| r.as_f64()).or_else(|| v.as_f64()));
-
-    if eps_actual.is_none() && revenue_actual.is_none() {
-        warn!("no actuals available yet for {}", symbol);
-        return None;
+    for content in split_message(&format_news_digest(&digests), MESSAGE_LIMIT) {
+        if let Err(e) = thread
+            .id
+            .send_message(http, CreateMessage::new().content(content))
+            .await
+        {
+            warn!("failed to post earnings news digest: {e}");
+            return;
+        }
     }
+}
 
-    Some(EarningsActuals {
-        eps_actual,
-        revenue_actual,
-    })
+/// `**AAPL**`, the summary, then `• [headline](<link>) — Reuters · 5:12 PM ET` per story.
+/// Links are wrapped in `<>` so Discord does not expand a preview for each one.
+pub(crate) fn format_news_digest(digests: &[EarningsNewsDigest]) -> Vec<String> {
+    let mut lines = Vec::new();
+    for digest in digests {
+        if !lines.is_empty() {
+            lines.push(String::new());
+        }
+        lines.push(format!("**{}**", digest.symbol));
+        if let Some(summary) = &digest.summary {
+            lines.extend(summary.lines().map(str::to_string));
+        }
+        for item in &digest.items {
+            let time = item
+                .published_at
+                .map(|t| format!(" · {} ET", t.with_timezone(&New_York).format("%-I:%M %p")))
+                .unwrap_or_default();
+            lines.push(format!(
+                "• [{}](<{}>) — {}{}",
+                item.title.replace(['[', ']'], ""),
+                item.link,
+                item.source.as_deref().unwrap_or("Unknown"),
+                time
+            ));
+        }
+    }
+    lines
 }
 
-fn format_eps(eps: Option<f64>) -> String {
-    eps.map(|v| format!("{:.2}", v))
-        .unwrap_or_else(|| "N/A".to_string())
+/// `SYMBOL [AMC 2025-10-30] — <results> | Implied ±x% vs actual +y%`
+pub fn format_card_line(card: &ReactionCard) -> String {
+    let move_text = match (card.implied_move_pct, card.reaction_pct) {
+        (Some(implied), Some(actual)) => {
+            format!(" | Implied ±{:.1}% vs actual {:+.1}%", implied, actual)
+        }
+        (Some(implied), None) => format!(" | Implied ±{:.1}%", implied),
+        (None, Some(actual)) => format!(" | Move {:+.1}%", actual),
+        (None, None) => String::new(),
+    };
+    format!(
+        "{} [{} {}] — {}{}",
+        card.symbol,
+        card.session,
+        card.date.format("%Y-%m-%d"),
+        format_result(
+            card.result.as_ref(),
+            card.eps_estimate,
+            card.revenue_estimate
+        ),
+        move_text
+    )
 }
 
-fn format_revenue(revenue: Option<f64>) -> String {
-    match revenue {
-        Some(v) => {
-            let abs = v.abs();
-            if abs >= 1_000_000_000.0 {
-                format!("{:.1}B", v / 1_000_000_000.0)
-            } else if abs >= 1_000_000.0 {
-                format!("{:.1}M", v / 1_000_000.0)
-            } else {
-                format!("{:.0}", v)
-            }
+/// Group lines into messages that stay under `limit` characters; a single longer line is
+/// truncated.
+pub(crate) fn split_message(lines: &[String], limit: usize) -> Vec<String> {
+    let mut messages = Vec::new();
+    let mut current = String::new();
+    for line in lines {
+        let line: String = if line.chars().count() > limit {
+            line.chars().take(limit - 1).chain(['…']).collect()
+        } else {
+            line.clone()
+        };
+        if !current.is_empty() && current.chars().count() + 1 + line.chars().count() > limit {
+            messages.push(std::mem::take(&mut current));
         }
-        None => "N/A".to_string(),
+        if !current.is_empty() {
+            current.push('\n');
+        }
+        current.push_str(&line);
     }
+    if !current.trim().is_empty() {
+        messages.push(current);
+    }
+    messages
 }
 
-fn classify_session(time: Option<&str>) -> &'static str {
-    let Some(raw) = time else {
-        return "TBA";
+/// `FQ ending Sep 27 — EPS 1.46 vs 1.39 (+5.0%) ✅ Beat | Revenue 90.7B vs 89.5B (+1.3%) ✅ Beat | Next Q EPS est 1.60 (7d +2.0%)`
+pub(crate) fn format_result(
+    result: Option<&EarningsResult>,
+    eps_estimate: Option<f64>,
+    revenue_estimate: Option<f64>,
+) -> String {
+    let Some(r) = result else {
+        let estimates = match (eps_estimate, revenue_estimate) {
+            (None, None) => String::new(),
+            (eps, rev) => format!(
+                " (est. EPS {} | Revenue {})",
+                format_eps(eps),
+                format_revenue(rev)
+            ),
+        };
+        return format!("EPS N/A | Revenue N/A{}", estimates);
     };
-    let t = raw.trim().to_ascii_lowercase();
 
-    if t.contains("bmo") || t.contains("before market") || t.contains("pre") {
-        return "BMO";
+    let eps = compare_text(
+        format_eps(r.eps_actual),
+        r.eps_estimate.map(|e| format_eps(Some(e))),
+        r.eps_surprise_pct(),
+    );
+    let revenue = compare_text(
+        format_revenue(r.revenue_actual),
+        r.revenue_estimate.map(|e| format_revenue(Some(e))),
+        r.revenue_surprise_pct(),
+    );
+
+    let mut text = format!(
+        "FQ ending {} — EPS {} | Revenue {}",
+        r.fiscal_quarter_end.format("%b %-d, %Y"),
+        eps,
+        revenue
+    );
+
+    if let Some(outlook) = r.outlook.as_ref().and_then(format_outlook) {
+        text.push_str(&format!(" | Next Q {}", outlook));
     }
-    if t.contains("amc")
-        || t.contains("after close")
-        || t.contains("after market")
-        || t.contains("post")
-    {
-        return "AMC";
+
+    text
+}
+
+/// `EPS est 1.60 (7d +2.0%), Rev est 95.0B`; `None` when consensus is empty.
+pub(crate) fn format_outlook(outlook: &EstimateOutlook) -> Option<String> {
+    let mut parts = Vec::new();
+    if let Some(eps) = outlook.eps_estimate {
+        let revision = outlook
+            .eps_estimate_7d_ago
+            .and_then(|prev| surprise_pct(eps, prev))
+            .filter(|pct| pct.abs() >= 0.05)
+            .map(|pct| format!(" (7d {:+.1}%)", pct))
+            .unwrap_or_default();
+        parts.push(format!("EPS est {:.2}{}", eps, revision));
+    }
+    if let Some(rev) = outlook.revenue_estimate {
+        parts.push(format!("Rev est {}", format_revenue(Some(rev))));
     }
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
 
-    if let Some(hour) = parse_hour_prefix(&t) {
-        if hour >= 15 {
-            return "AMC";
+fn compare_text(actual: String, estimate: Option<String>, surprise: Option<f64>) -> String {
+    match (estimate, surprise) {
+        (Some(est), Some(pct)) => format!(
+            "{} vs {} ({:+.1}%) {}",
+            actual,
+            est,
+            pct,
+            SurpriseVerdict::from_surprise(pct, INLINE_SURPRISE_PCT).badge()
+        ),
+        (Some(est), None) => format!("{} vs {}", actual, est),
+        (None, _) => actual,
+    }
+}
+
+/// Attach the extended-hours reaction to the stored implied move and return that move.
+async fn implied_vs_actual(
+    cache: &RedisCache,
+    symbol: &str,
+    date: NaiveDate,
+    reaction: Option<f64>,
+) -> Option<f64> {
+    let mut record = match earnings_moves::load(cache, symbol, date).await {
+        Ok(r) => r,
+        Err(e) => {
+            warn!("failed to load implied move for {}: {}", symbol, e);
+            None
         }
-        if hour <= 11 {
-            return "BMO";
+    }?;
+
+    if let Some(actual) = reaction {
+        if record.actual_source != Some(ActualMoveSource::SessionClose) {
+            record.actual_move_pct = Some(actual);
+            record.actual_source = Some(ActualMoveSource::ExtendedHours);
+            if let Err(e) = earnings_moves::save(cache, &record).await {
+                warn!("failed to store actual move for {}: {}", symbol, e);
+            }
         }
     }
 
-    if t.ends_with("am") {
-        return "BMO";
+    Some(record.implied_move_pct)
+}
+
+/// Early reaction from quotes: pre-market (BMO) or after-hours (AMC) vs the regular close,
+/// or the regular-session change for intraday (DMH) reports.
+pub fn extended_hours_move_pct(quote: &PriceQuote, session: EarningsSession) -> Option<f64> {
+    let close = quote.price.filter(|p| *p > 0.0)?;
+    let extended = match session {
+        EarningsSession::BeforeOpen => quote.pre_market_price,
+        EarningsSession::AfterClose => quote.after_hours_price,
+        EarningsSession::DuringMarket => return quote.percent_change,
+        EarningsSession::Unknown => None,
+    }?;
+    Some((extended / close - 1.0) * 100.0)
+}
+
+/// Latest ET date whose regular session has closed at `now`: today from 16:00, otherwise
+/// yesterday. Bars after it are still moving and must not settle a reaction.
+pub fn last_closed_session(now: DateTime<Utc>) -> NaiveDate {
+    let local = now.with_timezone(&New_York);
+    let today = local.date_naive();
+    if local.hour() >= SESSION_CLOSE_HOUR {
+        today
+    } else {
+        today - Duration::days(1)
     }
-    if t.ends_with("pm") {
-        return "AMC";
+}
+
+/// Realized earnings move from daily closes: for BMO and DMH the report-day close vs the
+/// prior close, otherwise the next session's close vs the report-day close. Only bars up to
+/// `last_closed` count, so an intraday bar never settles the move.
+pub fn realized_move_pct(
+    closes: &[(NaiveDate, f64)],
+    date: NaiveDate,
+    session: EarningsSession,
+    last_closed: NaiveDate,
+) -> Option<f64> {
+    let reacts_same_day = matches!(
+        session,
+        EarningsSession::BeforeOpen | EarningsSession::DuringMarket
+    );
+    let closes: Vec<_> = closes.iter().filter(|(d, _)| *d <= last_closed).collect();
+    let (base, after) = if reacts_same_day {
+        let base = closes.iter().rev().find(|(d, _)| *d < date)?;
+        let after = closes.iter().find(|(d, _)| *d == date)?;
+        (base.1, after.1)
+    } else {
+        let base = closes.iter().find(|(d, _)| *d == date)?;
+        let after = closes.iter().find(|(d, _)| *d > date)?;
+        (base.1, after.1)
+    };
+    if base <= 0.0 {
+        return None;
     }
+    Some((after / base - 1.0) * 100.0)
+}
 
-    "TBA"
+pub(crate) fn format_eps(eps: Option<f64>) -> String {
+    eps.map(|v| format!("{:.2}", v))
+        .unwrap_or_else(|| "N/A".to_string())
 }
 
-fn parse_hour_prefix(s: &str) -> Option<u32> {
-    let mut digits = String::new();
-    for ch in s.chars() {
-        if ch.is_ascii_digit() {
-            digits.push(ch);
-            if digits.len() >= 2 {
-                break;
+pub(crate) fn format_revenue(revenue: Option<f64>) -> String {
+    match revenue {
+        Some(v) => {
+            let abs = v.abs();
+            if abs >= 1_000_000_000.0 {
+                format!("{:.1}B", v / 1_000_000_000.0)
+            } else if abs >= 1_000_000.0 {
+                format!("{:.1}M", v / 1_000_000.0)
+            } else {
+                format!("{:.0}", v)
             }
-        } else if ch == ':' || !digits.is_empty() {
-            break;
         }
+        None => "N/A".to_string(),
     }
-
-    if digits.is_empty() {
-        return None;
-    }
-    digits.parse::<u32>().ok()
 }
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::env;
 use std::sync::Arc;
 
@@ -5,26 +6,21 @@ use chrono::{Datelike, Duration, Timelike, Utc, Weekday};
 use chrono_tz::America;
 use chrono_tz::America::New_York;
 use once_cell::sync::Lazy;
-use serenity::all::{CreateAttachment, CreateMessage, Http};
+use serenity::all::{CreateMessage, Http};
 use serenity::model::prelude::ChannelId;
 use tokio::sync::Mutex;
 use tokio::task::JoinHandle;
 use tracing::{info, warn};
 
+use crate::models::{EarningsEvent, EarningsSession};
+use crate::service::caching::collections::earnings_moves::{self, EarningsMoveRecord};
+use crate::service::caching::collections::iv_history;
+use crate::service::caching::RedisCache;
+use crate::service::finance::implied_move::ImpliedMove;
 use crate::service::finance::FinanceService;
+use super::after_daily_report::format_revenue;
 use super::weekly_report;
 
-#[allow(dead_code)]
-#[derive(Debug, Clone)]
-struct IvSnapshot {
-    spot: f64,
-    atm_strike: f64,
-    call_iv: f64,
-    put_iv: f64,
-    implied_move_pct: f64,
-    days_to_expiry: i64,
-}
-
 static LAST_DAILY_POST_DATE: Lazy<Mutex<Option<chrono::NaiveDate>>> =
     Lazy::new(|| Mutex::new(None));
 
@@ -49,6 +45,7 @@ fn resolve_channel_id(var_names: &[&str], feature_label: &str) -> Option<Channel
 pub fn spawn_daily_report_poster(
     http: Arc<Http>,
     finance: Arc<FinanceService>,
+    cache: Option<Arc<RedisCache>>,
 ) -> Option<JoinHandle<()>> {
     if env::var("ENABLE_EARNINGS_PINGER")
         .map(|v| v == "0")
@@ -70,7 +67,9 @@ pub fn spawn_daily_report_poster(
         loop {
             interval.tick().await;
             if should_post_now().await {
-                if let Err(e) = send_daily_report(&http, &finance, channel_id).await {
+                if let Err(e) =
+                    send_daily_report(&http, &finance, cache.as_deref(), channel_id).await
+                {
                     warn!("daily earnings poster iteration failed: {e}");
                 }
             }
@@ -107,6 +106,7 @@ async fn should_post_now() -> bool {
 pub async fn send_daily_report(
     http: &Http,
     finance: &FinanceService,
+    cache: Option<&RedisCache>,
     channel_id: ChannelId,
 ) -> Result<(), String> {
     let now_et = Utc::now().with_timezone(&New_York);
@@ -136,7 +136,7 @@ pub async fn send_daily_report(
     let start = target_date;
     let end = start; // same day
 
-    let events = finance
+    let mut events = finance
         .get_earnings_range(start, end)
         .await
         .map_err(|e| format!("fetch error: {e}"))?;
@@ -158,38 +158,80 @@ pub async fn send_daily_report(
     lines.push(heading.clone());
     lines.push(String::new());
 
+    finance.fill_estimates(&mut events).await;
+
+    let mut implied_moves = HashMap::new();
     for ev in &events {
-        let session = classify_session(ev.time_of_day.as_deref());
-        let iv_snapshot =
-            fetch_iv_snapshot(finance, &ev.symbol, ev.date.date_naive(), session).await;
+        let session = ev.session;
+        let implied = fetch_implied_move(finance, &ev.symbol, ev.date.date_naive(), session).await;
+
+        // Don't persist moves priced off untradeable quotes; they would skew the history
+        let trusted = implied.as_ref().filter(|im| !im.is_unreliable());
+        if let (Some(cache), Some(im)) = (cache, trusted) {
+            let record = EarningsMoveRecord {
+                symbol: ev.symbol.clone(),
+                date: ev.date.date_naive(),
+                session,
+                implied_move_pct: im.implied_move_pct,
+                spot: im.straddle.spot,
+                recorded_at: Utc::now(),
+                actual_move_pct: None,
+                actual_source: None,
+            };
+            if let Err(e) = earnings_moves::record_implied(cache, &record).await {
+                warn!("failed to persist implied move for {}: {}", ev.symbol, e);
+            }
+        }
+
+        let iv_rank = match cache {
+            Some(cache) => iv_history::stats(cache, &ev.symbol, now_et.date_naive(), None)
+                .await
+                .ok()
+                .flatten()
+                .map(|s| format!(" | IVR {:.0} / IVP {:.0}", s.rank, s.percentile))
+                .unwrap_or_default(),
+            None => String::new(),
+        };
+
+        let estimates = format_estimates(ev);
 
-        match iv_snapshot {
-            Some(iv) => lines.push(format!(
-                "{} [{}] — IV C {:.1}% | IM ±{:.1}%",
+        if let Some(im) = &implied {
+            implied_moves.insert(ev.symbol.clone(), im.implied_move_pct);
+        }
+        match implied {
+            Some(im) => lines.push(format!(
+                "{} [{}] — IV {:.1}% | IM ±{:.1}%{}{}{}{}",
                 ev.symbol,
                 session,
-                iv.call_iv * 100.0,
-                iv.implied_move_pct,
+                im.straddle.iv * 100.0,
+                im.implied_move_pct,
+                im.earnings_move_pct
+                    .map(|m| format!(" (event ±{:.1}%)", m))
+                    .unwrap_or_default(),
+                iv_rank,
+                estimates,
+                format_flags(&im),
             )),
             None => lines.push(format!(
-                "{} [{}] — IV/IM unavailable (no options expiring after earnings)",
-                ev.symbol, session
+                "{} [{}] — IV/IM unavailable (no options expiring after earnings){}",
+                ev.symbol, session, estimates
             )),
         }
     }
 
     // Try to render image (reuse weekly renderer); fall back to text
-    match weekly_report::render_calendar_image(&events).await {
-        Ok(bytes) => {
-            let attachment = CreateAttachment::bytes(bytes, "earnings-daily.png");
+    let theme = weekly_report::theme_for_channel(http, cache, channel_id).await;
+    match weekly_report::render_calendar_pages(&events, &implied_moves, theme, cache).await {
+        Ok(pages) => {
             info!(
-                "Posting daily earnings report (image) with {} lines of backup text",
+                "Posting daily earnings report ({} image pages) with {} lines of backup text",
+                pages.len(),
                 lines.len()
             );
             channel_id
                 .send_files(
                     http,
-                    vec![attachment],
+                    weekly_report::calendar_attachments(pages, "earnings-daily"),
                     CreateMessage::new().content(heading),
                 )
                 .await
@@ -209,150 +251,53 @@ pub async fn send_daily_report(
     Ok(())
 }
 
-fn classify_session(time: Option<&str>) -> &'static str {
-    let Some(raw) = time else {
-        return "TBA";
-    };
-    let t = raw.trim().to_ascii_lowercase();
-
-    // Explicit keywords first
-    if t.contains("bmo") || t.contains("before market") || t.contains("pre") {
-        return "BMO";
-    }
-    if t.contains("amc")
-        || t.contains("after close")
-        || t.contains("after market")
-        || t.contains("post")
-    {
-        return "AMC";
-    }
-
-    // Handle clock-style times like "09:00", "9:00 am", "16:00", etc.
-    if let Some(hour) = parse_hour_prefix(&t) {
-        if hour >= 15 {
-            return "AMC";
-        }
-        if hour <= 11 {
-            return "BMO";
-        }
-    }
-
-    // Fallback to AM/PM suffix hints
-    if t.ends_with("am") {
-        return "BMO";
-    }
-    if t.ends_with("pm") {
-        return "AMC";
-    }
-
-    "TBA"
-}
-
-fn parse_hour_prefix(s: &str) -> Option<u32> {
-    // Accept formats like "9", "09", "9:00", "09:30", "9 am", "16:00"
-    let mut digits = String::new();
-    for ch in s.chars() {
-        if ch.is_ascii_digit() {
-            digits.push(ch);
-            if digits.len() >= 2 {
-                // Enough to form an hour like "09" or "16"
-                break;
-            }
-        } else if ch == ':' || !digits.is_empty() {
-            break;
-        }
-    }
-
-    if digits.is_empty() {
-        return None;
-    }
-    digits.parse::<u32>().ok()
-}
-
-async fn fetch_iv_snapshot(
+/// Implied move from the first expiry after the report, priced off bid/ask mids.
+pub(crate) async fn fetch_implied_move(
     finance: &FinanceService,
     symbol: &str,
     earnings_date: chrono::NaiveDate,
-    earnings_time: &str,
-) -> Option<IvSnapshot> {
+    session: EarningsSession,
+) -> Option<ImpliedMove> {
     // Determine when earnings actually happen
-    let earnings_datetime = match earnings_time {
-        "BMO" => earnings_date.and_hms_opt(9, 30, 0)?, // Before market open (9:30 AM ET)
-        "AMC" => earnings_date.and_hms_opt(16, 0, 0)?, // After market close (4:00 PM ET)
-        _ => earnings_date.and_hms_opt(16, 0, 0)?,     // Default to AMC if TBA
-    };
-
-    // Get all available option expirations for this symbol
-    let expirations = match finance.get_option_expirations(symbol).await {
-        Ok(exps) => exps,
-        Err(e) => {
-            warn!("Failed to get option expirations for {}: {}", symbol, e);
-            return None;
-        }
+    let earnings_datetime = match session {
+        EarningsSession::BeforeOpen => earnings_date.and_hms_opt(9, 30, 0)?, // 9:30 AM ET open
+        // Intraday and unconfirmed reports are priced like AMC (4:00 PM ET close)
+        _ => earnings_date.and_hms_opt(16, 0, 0)?,
     };
 
-    // Find the first expiration AFTER earnings (assume options expire 4:00 PM ET)
-    let target_expiry = expirations
-        .into_iter()
-        .filter_map(|exp| exp.and_hms_opt(16, 0, 0).map(|dt| (exp, dt)))
-        .filter(|(_, dt)| *dt > earnings_datetime)
-        .min_by(|(_, a), (_, b)| a.cmp(b))
-        .map(|(exp, _)| exp)?;
-
-    // Fetch options for that specific expiration
-    let slice = match finance.get_option_slice(symbol, target_expiry, 5).await {
-        Ok(s) => s,
+    match finance
+        .get_earnings_implied_move(symbol, earnings_datetime)
+        .await
+    {
+        Ok(im) => Some(im),
         Err(e) => {
-            warn!(
-                "IV fetch failed for {} at expiry {:?}: {}",
-                symbol, target_expiry, e
-            );
-            return None;
+            warn!("Implied move unavailable for {}: {}", symbol, e);
+            None
         }
-    };
-
-    let spot = slice.spot;
-
-    // Find ATM call (strike closest to spot price)
-    let atm_call = slice
-        .calls
-        .iter()
-        .min_by(|a, b| float_abs_cmp(a.strike, b.strike, spot))?;
-
-    // Find ATM put (strike closest to spot price)
-    let atm_put = slice
-        .puts
-        .iter()
-        .min_by(|a, b| float_abs_cmp(a.strike, b.strike, spot))?;
-
-    let call_price = atm_call.last_price;
-    let put_price = atm_put.last_price;
+    }
+}
 
-    // Calculate implied move: (ATM call + ATM put) / spot price
-    let implied_move_pct = if spot > 0.0 {
-        (call_price + put_price) / spot * 100.0
+/// Consensus suffix, e.g. ` | Est EPS 1.39 / Rev 89.5B`.
+pub(crate) fn format_estimates(ev: &EarningsEvent) -> String {
+    let mut parts = Vec::new();
+    if let Some(eps) = ev.eps_estimate {
+        parts.push(format!("EPS {:.2}", eps));
+    }
+    if ev.revenue_estimate.is_some() {
+        parts.push(format!("Rev {}", format_revenue(ev.revenue_estimate)));
+    }
+    if parts.is_empty() {
+        String::new()
     } else {
-        0.0
-    };
-
-    // Calculate days until expiration
-    let expiry_dt = target_expiry.and_hms_opt(16, 0, 0)?;
-    let days_to_expiry =
-        (expiry_dt.and_utc().timestamp() - earnings_datetime.and_utc().timestamp()) / 86400; // seconds in a day
-
-    Some(IvSnapshot {
-        spot,
-        atm_strike: (atm_call.strike + atm_put.strike) / 2.0,
-        call_iv: atm_call.implied_volatility,
-        put_iv: atm_put.implied_volatility,
-        implied_move_pct,
-        days_to_expiry,
-    })
+        format!(" | Est {}", parts.join(" / "))
+    }
 }
 
-/// Helper function to compare two strikes by their distance from spot price
-fn float_abs_cmp(a: f64, b: f64, spot: f64) -> std::cmp::Ordering {
-    let da = (a - spot).abs();
-    let db = (b - spot).abs();
-    da.partial_cmp(&db).unwrap_or(std::cmp::Ordering::Equal)
+/// Warning suffix for quotes that need a second look, e.g. ` ⚠️ wide spread 35%`.
+pub(crate) fn format_flags(im: &ImpliedMove) -> String {
+    if im.flags.is_empty() {
+        return String::new();
+    }
+    let labels: Vec<String> = im.flags.iter().map(|f| f.label()).collect();
+    format!(" ⚠️ {}", labels.join(", "))
 }
@@ -1,8 +1,11 @@
 pub mod after_daily_report;
 pub mod daily_report;
+pub mod reaction_cards;
+pub mod reminders;
 pub mod weekly_report;
 
 // Re-export for convenient access
 pub use after_daily_report::*;
 pub use daily_report::*;
+pub use reminders::*;
 pub use weekly_report::*;
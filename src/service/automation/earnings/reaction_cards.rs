@@ -0,0 +1,351 @@
+use std::collections::HashMap;
+use std::io::Cursor;
+
+use ab_glyph::{FontArc, PxScale};
+use chrono::NaiveDate;
+use image::{imageops, DynamicImage, ImageFormat, Rgba, RgbaImage};
+use imageproc::drawing::{draw_filled_rect_mut, draw_text_mut, text_size};
+use imageproc::rect::Rect;
+
+use super::after_daily_report::{format_eps, format_outlook, format_revenue};
+use super::weekly_report::{fetch_logos_from_urls, load_font, placeholder_logo};
+use crate::models::{EarningsEvent, EarningsResult, EarningsSession, SurpriseVerdict};
+use crate::service::caching::RedisCache;
+use crate::service::finance::earnings::INLINE_SURPRISE_PCT;
+
+/// Cards per PNG; Discord allows 10 attachments per message.
+pub const CARDS_PER_IMAGE: usize = 8;
+
+const WIDTH: u32 = 820;
+const MARGIN: u32 = 20;
+const HEADER_HEIGHT: u32 = 50;
+const CARD_HEIGHT: u32 = 140;
+const CARD_GAP: u32 = 10;
+const LOGO_W: u32 = 120;
+const INFO_X: u32 = 160;
+const MOVE_W: u32 = 180;
+
+const CANVAS_BG: Rgba<u8> = Rgba([255, 255, 255, 255]);
+const CARD_BG: Rgba<u8> = Rgba([248, 248, 248, 255]);
+const TITLE_COLOR: Rgba<u8> = Rgba([40, 35, 30, 255]);
+const TEXT_COLOR: Rgba<u8> = Rgba([70, 70, 70, 255]);
+const MUTED_COLOR: Rgba<u8> = Rgba([130, 130, 130, 255]);
+const UP_COLOR: Rgba<u8> = Rgba([22, 150, 70, 255]);
+const DOWN_COLOR: Rgba<u8> = Rgba([205, 45, 45, 255]);
+
+/// One company's post-earnings snapshot.
+#[derive(Debug, Clone)]
+pub struct ReactionCard {
+    pub symbol: String,
+    pub name: Option<String>,
+    pub session: EarningsSession,
+    pub date: NaiveDate,
+    pub result: Option<EarningsResult>,
+    pub eps_estimate: Option<f64>,
+    pub revenue_estimate: Option<f64>,
+    /// Pre-market (BMO) or after-hours (AMC) move vs the regular close, or the regular
+    /// session change for DMH, in percent.
+    pub reaction_pct: Option<f64>,
+    /// Implied move stored by the daily report, in percent.
+    pub implied_move_pct: Option<f64>,
+}
+
+/// Largest absolute reaction first; names without a quote go last.
+pub fn rank_by_reaction(cards: &mut [ReactionCard]) {
+    cards.sort_by(|a, b| {
+        let key = |c: &ReactionCard| c.reaction_pct.map(f64::abs);
+        key(b)
+            .partial_cmp(&key(a))
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.symbol.cmp(&b.symbol))
+    });
+}
+
+/// Render cards into PNG pages of at most `CARDS_PER_IMAGE`, in the given order.
+pub async fn render_reaction_cards(
+    title: &str,
+    cards: &[ReactionCard],
+    events: &[EarningsEvent],
+    cache: Option<&RedisCache>,
+) -> Result<Vec<Vec<u8>>, String> {
+    if cards.is_empty() {
+        return Err("no reactions to render".into());
+    }
+
+    let font = load_font()?;
+    let logos = fetch_logos_from_urls(events, cache).await;
+
+    let pages: Vec<&[ReactionCard]> = cards.chunks(CARDS_PER_IMAGE).collect();
+    let total = pages.len();
+    let mut images = Vec::with_capacity(total);
+    for (idx, page) in pages.into_iter().enumerate() {
+        let heading = if total > 1 {
+            format!("{} ({}/{})", title, idx + 1, total)
+        } else {
+            title.to_string()
+        };
+        let img = draw_page(&heading, page, &font, &logos);
+
+        let mut buffer = Vec::new();
+        DynamicImage::ImageRgba8(img)
+            .write_to(&mut Cursor::new(&mut buffer), ImageFormat::Png)
+            .map_err(|e| format!("failed to encode png: {e}"))?;
+        images.push(buffer);
+    }
+
+    Ok(images)
+}
+
+fn draw_page(
+    heading: &str,
+    cards: &[ReactionCard],
+    font: &FontArc,
+    logos: &HashMap<String, RgbaImage>,
+) -> RgbaImage {
+    let height = MARGIN + HEADER_HEIGHT + cards.len() as u32 * (CARD_HEIGHT + CARD_GAP) + MARGIN;
+    let mut img = RgbaImage::from_pixel(WIDTH, height, CANVAS_BG);
+
+    draw_text_mut(
+        &mut img,
+        TITLE_COLOR,
+        MARGIN as i32,
+        (MARGIN + 8) as i32,
+        PxScale::from(24.0),
+        font,
+        heading,
+    );
+
+    for (idx, card) in cards.iter().enumerate() {
+        let y = MARGIN + HEADER_HEIGHT + idx as u32 * (CARD_HEIGHT + CARD_GAP);
+        draw_card(&mut img, MARGIN, y, card, font, logos);
+    }
+
+    img
+}
+
+fn draw_card(
+    img: &mut RgbaImage,
+    x: u32,
+    y: u32,
+    card: &ReactionCard,
+    font: &FontArc,
+    logos: &HashMap<String, RgbaImage>,
+) {
+    let card_w = WIDTH - 2 * MARGIN;
+    draw_filled_rect_mut(
+        img,
+        Rect::at(x as i32, y as i32).of_size(card_w, CARD_HEIGHT),
+        CARD_BG,
+    );
+
+    // Left: logo, ticker, session
+    let logo_x = (x + 12) as i64;
+    let logo_y = (y + 14) as i64;
+    match logos.get(&card.symbol) {
+        Some(logo) => imageops::overlay(img, logo, logo_x, logo_y),
+        None => {
+            let placeholder = placeholder_logo(&card.symbol, font, TEXT_COLOR);
+            imageops::overlay(img, &placeholder, logo_x, logo_y)
+        }
+    }
+    draw_centered(
+        img,
+        font,
+        &card.symbol,
+        20.0,
+        x + 12,
+        LOGO_W,
+        y + 74,
+        TITLE_COLOR,
+    );
+    let session = format!("{} {}", card.session, card.date.format("%b %-d"));
+    draw_centered(
+        img,
+        font,
+        &session,
+        14.0,
+        x + 12,
+        LOGO_W,
+        y + 102,
+        MUTED_COLOR,
+    );
+
+    // Middle: company, quarter, EPS / revenue vs estimates, next-quarter consensus
+    let info_x = x + INFO_X;
+    let info_w = card_w - INFO_X - MOVE_W;
+    let name = card.name.as_deref().unwrap_or(&card.symbol);
+    draw_clipped(img, font, name, 18.0, info_x, y + 12, info_w, TITLE_COLOR);
+
+    let result = card.result.as_ref();
+    let quarter = match result {
+        Some(r) => format!("FQ ending {}", r.fiscal_quarter_end.format("%b %-d, %Y")),
+        None => "Results not published yet".to_string(),
+    };
+    draw_clipped(
+        img,
+        font,
+        &quarter,
+        14.0,
+        info_x,
+        y + 38,
+        info_w,
+        MUTED_COLOR,
+    );
+
+    let eps = compare_line(
+        "EPS",
+        format_eps(result.and_then(|r| r.eps_actual)),
+        result.and_then(|r| r.eps_estimate).or(card.eps_estimate),
+        format_eps,
+        result.and_then(|r| r.eps_surprise_pct()),
+    );
+    draw_compare(img, font, &eps, info_x, y + 62, info_w);
+
+    let revenue = compare_line(
+        "Revenue",
+        format_revenue(result.and_then(|r| r.revenue_actual)),
+        result
+            .and_then(|r| r.revenue_estimate)
+            .or(card.revenue_estimate),
+        format_revenue,
+        result.and_then(|r| r.revenue_surprise_pct()),
+    );
+    draw_compare(img, font, &revenue, info_x, y + 86, info_w);
+
+    if let Some(outlook) = result
+        .and_then(|r| r.outlook.as_ref())
+        .and_then(format_outlook)
+    {
+        let text = format!("Next Q: {}", outlook);
+        draw_clipped(img, font, &text, 14.0, info_x, y + 112, info_w, MUTED_COLOR);
+    }
+
+    // Right: the reaction, with the implied move for scale
+    let move_x = x + card_w - MOVE_W;
+    let (move_text, color) = match card.reaction_pct {
+        Some(pct) if pct >= 0.0 => (format!("{:+.1}%", pct), UP_COLOR),
+        Some(pct) => (format!("{:+.1}%", pct), DOWN_COLOR),
+        None => ("N/A".to_string(), MUTED_COLOR),
+    };
+    draw_centered(img, font, &move_text, 34.0, move_x, MOVE_W, y + 22, color);
+    let label = match card.session {
+        EarningsSession::BeforeOpen => "pre-market",
+        EarningsSession::DuringMarket => "regular session",
+        _ => "after hours",
+    };
+    draw_centered(img, font, label, 14.0, move_x, MOVE_W, y + 72, MUTED_COLOR);
+    if let Some(implied) = card.implied_move_pct {
+        let text = format!("implied ±{:.1}%", implied);
+        draw_centered(img, font, &text, 14.0, move_x, MOVE_W, y + 96, TEXT_COLOR);
+    }
+}
+
+/// EPS or revenue line of a card, e.g. `EPS 1.46 vs 1.39 (+5.0%)` with a Beat verdict.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompareLine {
+    pub text: String,
+    pub verdict: Option<SurpriseVerdict>,
+}
+
+pub fn compare_line(
+    label: &str,
+    actual: String,
+    estimate: Option<f64>,
+    format: fn(Option<f64>) -> String,
+    surprise: Option<f64>,
+) -> CompareLine {
+    let text = match (estimate, surprise) {
+        (Some(est), Some(pct)) => {
+            format!(
+                "{} {} vs {} ({:+.1}%)",
+                label,
+                actual,
+                format(Some(est)),
+                pct
+            )
+        }
+        (Some(est), None) => format!("{} {} vs est {}", label, actual, format(Some(est))),
+        (None, _) => format!("{} {}", label, actual),
+    };
+    CompareLine {
+        text,
+        verdict: surprise.map(|pct| SurpriseVerdict::from_surprise(pct, INLINE_SURPRISE_PCT)),
+    }
+}
+
+fn draw_compare(img: &mut RgbaImage, font: &FontArc, line: &CompareLine, x: u32, y: u32, w: u32) {
+    let scale = PxScale::from(15.0);
+    let Some(verdict) = line.verdict else {
+        draw_clipped(img, font, &line.text, 15.0, x, y, w, TEXT_COLOR);
+        return;
+    };
+
+    let badge = verdict.label();
+    let (badge_w, _) = text_size(scale, font, badge);
+    let text_w = w.saturating_sub(badge_w + 10);
+    draw_clipped(img, font, &line.text, 15.0, x, y, text_w, TEXT_COLOR);
+    let (drawn_w, _) = text_size(scale, font, &clip_text(font, scale, &line.text, text_w));
+    let color = match verdict {
+        SurpriseVerdict::Beat => UP_COLOR,
+        SurpriseVerdict::Inline => MUTED_COLOR,
+        SurpriseVerdict::Miss => DOWN_COLOR,
+    };
+    draw_text_mut(
+        img,
+        color,
+        (x + drawn_w + 10) as i32,
+        y as i32,
+        scale,
+        font,
+        badge,
+    );
+}
+
+#[allow(clippy::too_many_arguments)]
+fn draw_clipped(
+    img: &mut RgbaImage,
+    font: &FontArc,
+    text: &str,
+    size: f32,
+    x: u32,
+    y: u32,
+    max_w: u32,
+    color: Rgba<u8>,
+) {
+    let scale = PxScale::from(size);
+    let text = clip_text(font, scale, text, max_w);
+    draw_text_mut(img, color, x as i32, y as i32, scale, font, &text);
+}
+
+#[allow(clippy::too_many_arguments)]
+fn draw_centered(
+    img: &mut RgbaImage,
+    font: &FontArc,
+    text: &str,
+    size: f32,
+    x: u32,
+    width: u32,
+    y: u32,
+    color: Rgba<u8>,
+) {
+    let scale = PxScale::from(size);
+    let (tw, _) = text_size(scale, font, text);
+    let offset_x = x as i32 + (width as i32 - tw as i32) / 2;
+    draw_text_mut(img, color, offset_x, y as i32, scale, font, text);
+}
+
+/// Trim `text` with a trailing "..." until it fits in `max_w` pixels.
+fn clip_text(font: &FontArc, scale: PxScale, text: &str, max_w: u32) -> String {
+    if text_size(scale, font, text).0 <= max_w {
+        return text.to_string();
+    }
+    let mut chars: Vec<char> = text.chars().collect();
+    while !chars.is_empty() {
+        chars.pop();
+        let candidate = format!("{}...", chars.iter().collect::<String>().trim_end());
+        if text_size(scale, font, &candidate).0 <= max_w {
+            return candidate;
+        }
+    }
+    String::new()
+}
@@ -0,0 +1,354 @@
+use std::collections::HashMap;
+use std::env;
+use std::sync::Arc;
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveTime, Utc, Weekday};
+use chrono_tz::America::New_York;
+use serenity::all::{ChannelId, CreateMessage, Http, UserId};
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+use super::after_daily_report::{extended_hours_move_pct, format_result};
+use super::daily_report::{fetch_implied_move, format_estimates, format_flags};
+use crate::models::earnings::report_clock;
+use crate::models::{EarningsEvent, EarningsSession};
+use crate::service::caching::collections::earnings_moves;
+use crate::service::caching::collections::earnings_subscriptions::{
+    self, Delivery, EarningsSubscription, ReminderStage,
+};
+use crate::service::caching::RedisCache;
+use crate::service::finance::FinanceService;
+
+/// How often subscriptions are checked against the calendar.
+const POLL_SECS: u64 = 300;
+
+/// Eve reminders go out from this ET hour on the trading day before the report.
+const EVE_HOUR: u32 = 18;
+
+/// A report-time reminder older than this is skipped (e.g. after a restart).
+const REPORT_WINDOW_MINUTES: i64 = 120;
+
+/// Results are for the report when their fiscal quarter ended at most this long before it.
+const RESULTS_MAX_LAG_DAYS: i64 = 100;
+
+/// Spawn the subscription reminder loop: eve-of-report, report-time and results messages
+/// for every `/earnings subscribe` entry, driven by `get_earnings_range`.
+pub fn spawn_earnings_reminders(
+    http: Arc<Http>,
+    finance: Arc<FinanceService>,
+    cache: Option<Arc<RedisCache>>,
+) -> Option<JoinHandle<()>> {
+    if env::var("ENABLE_EARNINGS_PINGER")
+        .map(|v| v == "0")
+        .unwrap_or(false)
+    {
+        info!("Earnings reminders disabled via ENABLE_EARNINGS_PINGER=0");
+        return None;
+    }
+
+    let Some(cache) = cache else {
+        info!("Earnings reminders not started; subscriptions require REDIS_URL");
+        return None;
+    };
+
+    info!("Starting earnings subscription reminders");
+
+    Some(tokio::spawn(async move {
+        let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(POLL_SECS));
+        loop {
+            interval.tick().await;
+            if let Err(e) = run_once(&http, &finance, &cache).await {
+                warn!("earnings reminder iteration failed: {e}");
+            }
+        }
+    }))
+}
+
+async fn run_once(http: &Http, finance: &FinanceService, cache: &RedisCache) -> Result<(), String> {
+    let subs = earnings_subscriptions::load_all(cache)
+        .await
+        .map_err(|e| format!("failed to load subscriptions: {e}"))?;
+    if subs.is_empty() {
+        return Ok(());
+    }
+
+    let now_et = Utc::now().with_timezone(&New_York).naive_local();
+    let today = now_et.date();
+    let events = finance
+        .get_earnings_range(today - Duration::days(1), next_trading_day(today))
+        .await
+        .map_err(|e| format!("fetch error: {e}"))?;
+
+    let mut events: Vec<EarningsEvent> = events
+        .into_iter()
+        .filter(|ev| subs.contains_key(&ev.symbol.to_ascii_uppercase()))
+        .collect();
+    if events.is_empty() {
+        return Ok(());
+    }
+    finance.fill_estimates(&mut events).await;
+
+    for ev in &events {
+        let symbol = ev.symbol.to_ascii_uppercase();
+        let date = ev.date.date_naive();
+        let session = ev.session;
+
+        for stage in due_stages(now_et, date, report_time(ev)) {
+            match earnings_subscriptions::was_notified(cache, &symbol, date, stage).await {
+                Ok(true) => continue,
+                Ok(false) => {}
+                Err(e) => {
+                    warn!("failed to check reminder state for {}: {}", symbol, e);
+                    continue;
+                }
+            }
+
+            let (content, results_quarter) = match stage {
+                ReminderStage::Eve | ReminderStage::Report => (
+                    Some(upcoming_message(finance, ev, session, stage).await),
+                    None,
+                ),
+                ReminderStage::Results => {
+                    match results_message(finance, cache, ev, session).await {
+                        Some((content, quarter_end)) => (Some(content), Some(quarter_end)),
+                        None => (None, None),
+                    }
+                }
+            };
+            // Results are retried on the next poll until Yahoo publishes them
+            let Some(content) = content else {
+                continue;
+            };
+
+            match earnings_subscriptions::mark_notified(cache, &symbol, date, stage).await {
+                Ok(true) => {
+                    if let Some(quarter_end) = results_quarter {
+                        if let Err(e) = earnings_subscriptions::record_results_quarter(
+                            cache,
+                            &symbol,
+                            quarter_end,
+                        )
+                        .await
+                        {
+                            warn!("failed to record results quarter for {}: {}", symbol, e);
+                        }
+                    }
+                    deliver(http, &subs[&symbol], &content).await;
+                }
+                Ok(false) => {}
+                Err(e) => warn!("failed to mark reminder for {}: {}", symbol, e),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Stages that should have gone out by `now` (ET) for a report on `date`.
+pub fn due_stages(
+    now: chrono::NaiveDateTime,
+    date: NaiveDate,
+    time: NaiveTime,
+) -> Vec<ReminderStage> {
+    let mut stages = Vec::new();
+    let report_at = date.and_time(time);
+
+    if now.date() == previous_trading_day(date) && now.time() >= eve_time() {
+        stages.push(ReminderStage::Eve);
+    }
+    if now >= report_at && now < report_at + Duration::minutes(REPORT_WINDOW_MINUTES) {
+        stages.push(ReminderStage::Report);
+    }
+    if now >= report_at {
+        stages.push(ReminderStage::Results);
+    }
+    stages
+}
+
+/// Whether results for the fiscal quarter ending `quarter_end` belong to the report on
+/// `report_date`: the quarter ended shortly before it and is newer than the last quarter
+/// a results reminder went out for.
+pub fn is_reported_quarter(
+    quarter_end: NaiveDate,
+    report_date: NaiveDate,
+    last_reported: Option<NaiveDate>,
+) -> bool {
+    let lag = (report_date - quarter_end).num_days();
+    (0..=RESULTS_MAX_LAG_DAYS).contains(&lag) && last_reported.is_none_or(|last| quarter_end > last)
+}
+
+/// Scheduled release time (ET) when the calendar has one, else the typical time for the
+/// session; unconfirmed times ping at the open.
+pub fn report_time(ev: &EarningsEvent) -> NaiveTime {
+    if let Some(time) = report_clock(ev.time_of_day.as_deref()) {
+        return time;
+    }
+    match ev.session {
+        EarningsSession::BeforeOpen => NaiveTime::from_hms_opt(7, 0, 0),
+        EarningsSession::AfterClose => NaiveTime::from_hms_opt(16, 5, 0),
+        EarningsSession::DuringMarket | EarningsSession::Unknown => {
+            NaiveTime::from_hms_opt(9, 30, 0)
+        }
+    }
+    .unwrap_or_default()
+}
+
+fn eve_time() -> NaiveTime {
+    NaiveTime::from_hms_opt(EVE_HOUR, 0, 0).unwrap_or_default()
+}
+
+fn next_trading_day(date: NaiveDate) -> NaiveDate {
+    let mut next = date + Duration::days(1);
+    while matches!(next.weekday(), Weekday::Sat | Weekday::Sun) {
+        next += Duration::days(1);
+    }
+    next
+}
+
+fn previous_trading_day(date: NaiveDate) -> NaiveDate {
+    let mut prev = date - Duration::days(1);
+    while matches!(prev.weekday(), Weekday::Sat | Weekday::Sun) {
+        prev -= Duration::days(1);
+    }
+    prev
+}
+
+fn session_phrase(session: EarningsSession) -> &'static str {
+    match session {
+        EarningsSession::BeforeOpen => "before the open",
+        EarningsSession::DuringMarket => "during market hours",
+        EarningsSession::AfterClose => "after the close",
+        EarningsSession::Unknown => "(time unconfirmed)",
+    }
+}
+
+/// `⏰ AAPL reports next session after the close (Thu Oct 30) — IM ±4.2% (event ±3.1%), exp Oct 31 | Est EPS 1.77 / Rev 101.2B`
+async fn upcoming_message(
+    finance: &FinanceService,
+    ev: &EarningsEvent,
+    session: EarningsSession,
+    stage: ReminderStage,
+) -> String {
+    let date = ev.date.date_naive();
+    let implied = match fetch_implied_move(finance, &ev.symbol, date, session).await {
+        Some(im) => format!(
+            " — IM ±{:.1}%{}, exp {}{}",
+            im.implied_move_pct,
+            im.earnings_move_pct
+                .map(|m| format!(" (event ±{:.1}%)", m))
+                .unwrap_or_default(),
+            im.expiration.format("%b %-d"),
+            format_flags(&im)
+        ),
+        None => " — implied move unavailable".to_string(),
+    };
+
+    let lead = match stage {
+        ReminderStage::Eve => format!(
+            "⏰ {} reports next session {} ({})",
+            ev.symbol,
+            session_phrase(session),
+            date.format("%a %b %-d")
+        ),
+        _ => format!("🔔 {} reports today {}", ev.symbol, session_phrase(session)),
+    };
+
+    format!("{}{}{}", lead, implied, format_estimates(ev))
+}
+
+/// `📊 AAPL results — FQ ending … — EPS … | Revenue … | Move +3.1% (implied ±4.2%)` with
+/// the fiscal quarter end, or `None` while actuals for the reported quarter are not out yet.
+async fn results_message(
+    finance: &FinanceService,
+    cache: &RedisCache,
+    ev: &EarningsEvent,
+    session: EarningsSession,
+) -> Option<(String, NaiveDate)> {
+    let date = ev.date.date_naive();
+    let result = match finance.get_earnings_result(&ev.symbol, date).await {
+        Ok(r) if r.eps_actual.is_some() || r.revenue_actual.is_some() => r,
+        Ok(_) => return None,
+        Err(e) => {
+            info!("results not available yet for {}: {}", ev.symbol, e);
+            return None;
+        }
+    };
+    let last_reported = match earnings_subscriptions::last_results_quarter(cache, &ev.symbol).await
+    {
+        Ok(last) => last,
+        Err(e) => {
+            warn!(
+                "failed to load last results quarter for {}: {}",
+                ev.symbol, e
+            );
+            return None;
+        }
+    };
+    if !is_reported_quarter(result.fiscal_quarter_end, date, last_reported) {
+        info!(
+            "results for {} still show FQ ending {}; waiting for the reported quarter",
+            ev.symbol, result.fiscal_quarter_end
+        );
+        return None;
+    }
+
+    let reaction = match finance.get_price(&ev.symbol).await {
+        Ok(quote) => extended_hours_move_pct(&quote, session),
+        Err(e) => {
+            warn!("reaction quote failed for {}: {}", ev.symbol, e);
+            None
+        }
+    };
+    let implied = earnings_moves::load(cache, &ev.symbol, date)
+        .await
+        .ok()
+        .flatten()
+        .map(|r| r.implied_move_pct);
+    let move_text = match (reaction, implied) {
+        (Some(r), Some(im)) => format!(" | Move {:+.1}% (implied ±{:.1}%)", r, im),
+        (Some(r), None) => format!(" | Move {:+.1}%", r),
+        (None, Some(im)) => format!(" | Implied ±{:.1}%", im),
+        (None, None) => String::new(),
+    };
+
+    let content = format!(
+        "📊 {} results — {}{}",
+        ev.symbol,
+        format_result(Some(&result), ev.eps_estimate, ev.revenue_estimate),
+        move_text
+    );
+    Some((content, result.fiscal_quarter_end))
+}
+
+/// DM each `Dm` subscriber; ping `Channel` subscribers with one message per channel.
+async fn deliver(http: &Http, subs: &[EarningsSubscription], content: &str) {
+    let mut channel_pings: HashMap<u64, Vec<u64>> = HashMap::new();
+
+    for sub in subs {
+        match sub.delivery {
+            Delivery::Dm => {
+                let builder = CreateMessage::new().content(content);
+                if let Err(e) = UserId::new(sub.user_id).direct_message(http, builder).await {
+                    warn!("failed to DM earnings reminder to {}: {}", sub.user_id, e);
+                }
+            }
+            Delivery::Channel { channel_id } => {
+                channel_pings
+                    .entry(channel_id)
+                    .or_default()
+                    .push(sub.user_id);
+            }
+        }
+    }
+
+    for (channel_id, users) in channel_pings {
+        let mentions: Vec<String> = users.iter().map(|u| format!("<@{}>", u)).collect();
+        let message = format!("{}\n{}", mentions.join(" "), content);
+        if let Err(e) = ChannelId::new(channel_id)
+            .send_message(http, CreateMessage::new().content(message))
+            .await
+        {
+            warn!("failed to post earnings reminder to {}: {}", channel_id, e);
+        }
+    }
+}
@@ -1,4 +1,5 @@
-use std::collections::BTreeMap;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
 use std::env;
 use std::io::Cursor;
 use std::sync::Arc;
@@ -9,18 +10,25 @@ use chrono_tz::America::New_York;
 use font_kit::family_name::FamilyName;
 use font_kit::properties::{Properties, Weight};
 use font_kit::source::SystemSource;
+use futures_util::future::join_all;
 use image::imageops::{self, FilterType};
 use image::{DynamicImage, GenericImageView, ImageFormat, Rgba, RgbaImage};
 use imageproc::drawing::{draw_filled_rect_mut, draw_text_mut, text_size};
 use imageproc::rect::Rect;
 use once_cell::sync::Lazy;
-use serenity::all::{CreateAttachment, CreateMessage, Http};
+use serenity::all::{Channel, CreateAttachment, CreateMessage, Http};
 use serenity::model::prelude::ChannelId;
 use tokio::sync::Mutex;
 use tokio::task::JoinHandle;
 use tracing::{error, info, warn};
 
-use crate::models::EarningsEvent;
+use super::after_daily_report::format_eps;
+use super::daily_report::fetch_implied_move;
+use crate::models::{EarningsEvent, EarningsSession};
+use crate::service::caching::collections::earnings_season;
+use crate::service::caching::collections::guild_settings::{self, CalendarTheme};
+use crate::service::caching::collections::logo_cache;
+use crate::service::caching::RedisCache;
 use crate::service::command::earnings::format_output;
 use crate::service::finance::FinanceService;
 
@@ -47,6 +55,7 @@ fn resolve_channel_id(var_names: &[&str], feature_label: &str) -> Option<Channel
 pub fn spawn_earnings_poster(
     http: Arc<Http>,
     finance: Arc<FinanceService>,
+    cache: Option<Arc<RedisCache>>,
 ) -> Option<JoinHandle<()>> {
     if env::var("ENABLE_EARNINGS_PINGER")
         .map(|v| v == "0")
@@ -68,7 +77,7 @@ pub fn spawn_earnings_poster(
         loop {
             interval.tick().await;
             if should_post_now().await {
-                if let Err(e) = post_once(&http, &finance, channel_id).await {
+                if let Err(e) = post_once(&http, &finance, cache.as_deref(), channel_id).await {
                     error!("earnings poster iteration failed: {e}");
                 }
             }
@@ -79,6 +88,7 @@ pub fn spawn_earnings_poster(
 async fn post_once(
     http: &Http,
     finance: &FinanceService,
+    cache: Option<&RedisCache>,
     channel_id: ChannelId,
 ) -> Result<(), String> {
     let now_et = Utc::now().with_timezone(&New_York);
@@ -87,7 +97,7 @@ async fn post_once(
     let week_monday = start + chrono::Duration::days(1);
     let heading = format!("@everyone 📊 Earnings Calendar — Week of {}", week_monday);
 
-    let events = finance
+    let mut events = finance
         .get_earnings_range(start, end)
         .await
         .map_err(|e| format!("fetch error: {e}"))?;
@@ -97,13 +107,26 @@ async fn post_once(
         return Ok(());
     }
 
-    match render_calendar_image(&events).await {
-        Ok(bytes) => {
-            let attachment = CreateAttachment::bytes(bytes, "earnings-calendar.png");
+    // Pre-warm logos for the whole week so the daily reports render from cache
+    if let Some(cache) = cache {
+        warm_logo_cache(&events, cache).await;
+        // The week's calendar feeds the `/earnings-season` reported-so-far count; sectors
+        // are looked up here, off the command path, for its sector scope
+        let symbols: Vec<String> = events.iter().map(|ev| ev.symbol.clone()).collect();
+        let sectors = finance.get_sectors(&symbols).await;
+        if let Err(e) = earnings_season::record_scheduled(cache, &events, &sectors).await {
+            warn!("failed to record season calendar: {}", e);
+        }
+    }
+
+    let implied_moves = enrich_calendar(finance, &mut events).await;
+    let theme = theme_for_channel(http, cache, channel_id).await;
+    match render_calendar_pages(&events, &implied_moves, theme, cache).await {
+        Ok(pages) => {
             channel_id
                 .send_files(
                     http,
-                    vec![attachment],
+                    calendar_attachments(pages, "earnings-calendar"),
                     CreateMessage::new().content(heading.clone()),
                 )
                 .await
@@ -152,61 +175,245 @@ struct DayColumn {
     after: Vec<EarningsEvent>,
 }
 
-enum Session {
-    Before,
-    After,
-    Tba,
+/// One day as drawn on a single page.
+struct PageColumn<'a> {
+    date: NaiveDate,
+    /// Later page of a day that overflowed.
+    continued: bool,
+    before: &'a [EarningsEvent],
+    after: &'a [EarningsEvent],
+    /// Entries left over once `MAX_CALENDAR_PAGES` is reached.
+    before_hidden: usize,
+    after_hidden: usize,
 }
 
 const HALF_WIDTH: u32 = 180;
 const DAY_WIDTH: u32 = HALF_WIDTH * 2;
-const ENTRY_HEIGHT: u32 = 85;
+const ENTRY_HEIGHT: u32 = 100;
 const HEADER_HEIGHT: u32 = 60;
 const MARGIN: u32 = 20;
 const DIVIDER_WIDTH: u32 = 2;
 
-const TITLE_COLOR: Rgba<u8> = Rgba([40, 35, 30, 255]);
-const HEADER_COLOR: Rgba<u8> = Rgba([100, 100, 100, 255]);
-const CANVAS_BG: Rgba<u8> = Rgba([255, 255, 255, 255]);
-const COLUMN_BG: Rgba<u8> = Rgba([255, 255, 255, 255]);
-const ENTRY_BG: Rgba<u8> = Rgba([248, 248, 248, 255]);
-const DIVIDER_COLOR: Rgba<u8> = Rgba([220, 220, 220, 255]);
-
 const LOGO_W: u32 = 120;
 const LOGO_H: u32 = 50;
+
+/// Entries per half-column on one page; the rest continue on the next page.
 const MAX_PER_COLUMN: usize = 12;
 
-pub async fn render_calendar_image(events: &[EarningsEvent]) -> Result<Vec<u8>, String> {
+/// Discord allows 10 attachments per message.
+pub const MAX_CALENDAR_PAGES: usize = 10;
+
+/// Most important events that get EPS estimates and implied moves looked up for their cards.
+const ENRICH_LIMIT: usize = 40;
+
+/// Concurrent implied-move lookups (each loads option chains).
+const IMPLIED_CONCURRENCY: usize = 4;
+
+struct Palette {
+    canvas: Rgba<u8>,
+    column: Rgba<u8>,
+    entry: Rgba<u8>,
+    title: Rgba<u8>,
+    header: Rgba<u8>,
+    text: Rgba<u8>,
+    divider: Rgba<u8>,
+    /// Plate behind logos; most are drawn for light backgrounds.
+    logo_plate: Option<Rgba<u8>>,
+}
+
+const LIGHT: Palette = Palette {
+    canvas: Rgba([255, 255, 255, 255]),
+    column: Rgba([255, 255, 255, 255]),
+    entry: Rgba([248, 248, 248, 255]),
+    title: Rgba([40, 35, 30, 255]),
+    header: Rgba([100, 100, 100, 255]),
+    text: Rgba([70, 70, 70, 255]),
+    divider: Rgba([220, 220, 220, 255]),
+    logo_plate: None,
+};
+
+const DARK: Palette = Palette {
+    canvas: Rgba([30, 31, 34, 255]),
+    column: Rgba([30, 31, 34, 255]),
+    entry: Rgba([43, 45, 49, 255]),
+    title: Rgba([235, 235, 235, 255]),
+    header: Rgba([170, 172, 178, 255]),
+    text: Rgba([210, 212, 216, 255]),
+    divider: Rgba([60, 62, 68, 255]),
+    logo_plate: Some(Rgba([236, 236, 236, 255])),
+};
+
+impl Palette {
+    fn for_theme(theme: CalendarTheme) -> &'static Palette {
+        match theme {
+            CalendarTheme::Light => &LIGHT,
+            CalendarTheme::Dark => &DARK,
+        }
+    }
+}
+
+/// Render the calendar as PNG pages (at most `MAX_CALENDAR_PAGES`). Each session lists
+/// the most important reports first; a day with more than `MAX_PER_COLUMN` reports in a
+/// session continues on the next page. Cards show the EPS estimate and, when present in
+/// `implied_moves` (percent by symbol), the implied move.
+pub async fn render_calendar_pages(
+    events: &[EarningsEvent],
+    implied_moves: &HashMap<String, f64>,
+    theme: CalendarTheme,
+    cache: Option<&RedisCache>,
+) -> Result<Vec<Vec<u8>>, String> {
     let columns = build_columns(events);
     if columns.is_empty() {
         return Err("no events to render".into());
     }
+    let pages = paginate(&columns);
 
     let font = load_font()?;
 
-    // Fetch logos from URLs provided by API
-    let logos = fetch_logos_from_urls(events).await;
+    // Only fetch logos for entries that make it onto a page
+    let drawn: Vec<EarningsEvent> = pages
+        .iter()
+        .flatten()
+        .flat_map(|c| c.before.iter().chain(c.after))
+        .cloned()
+        .collect();
+    let logos = fetch_logos_from_urls(&drawn, cache).await;
+
+    let palette = Palette::for_theme(theme);
+    let mut images = Vec::with_capacity(pages.len());
+    for page in &pages {
+        let image =
+            DynamicImage::ImageRgba8(draw_canvas(page, &font, &logos, implied_moves, palette));
+        let mut buffer = Vec::new();
+        image
+            .write_to(&mut Cursor::new(&mut buffer), ImageFormat::Png)
+            .map_err(|e| format!("failed to encode png: {e}"))?;
+        images.push(buffer);
+    }
+
+    Ok(images)
+}
 
-    let image = DynamicImage::ImageRgba8(draw_canvas(&columns, &font, &logos));
-    let mut buffer = Vec::new();
-    image
-        .write_to(&mut Cursor::new(&mut buffer), ImageFormat::Png)
-        .map_err(|e| format!("failed to encode png: {e}"))?;
+/// Fill EPS estimates and price implied moves for the `ENRICH_LIMIT` most important events
+/// (the ones leading each day). Returns implied moves in percent by symbol.
+pub async fn enrich_calendar(
+    finance: &FinanceService,
+    events: &mut [EarningsEvent],
+) -> HashMap<String, f64> {
+    let mut order: Vec<usize> = (0..events.len()).collect();
+    order.sort_by(|&a, &b| by_importance(&events[a], &events[b]));
+    order.truncate(ENRICH_LIMIT);
+
+    let mut top: Vec<EarningsEvent> = order.iter().map(|&i| events[i].clone()).collect();
+    finance.fill_estimates(&mut top).await;
+    for (&i, ev) in order.iter().zip(&top) {
+        events[i].eps_estimate = ev.eps_estimate;
+        events[i].revenue_estimate = ev.revenue_estimate;
+    }
 
-    Ok(buffer)
+    let mut moves = HashMap::new();
+    for chunk in top.chunks(IMPLIED_CONCURRENCY) {
+        let results = join_all(chunk.iter().map(|ev| async move {
+            let date = ev.date.date_naive();
+            let implied = fetch_implied_move(finance, &ev.symbol, date, ev.session).await;
+            (ev.symbol.clone(), implied)
+        }))
+        .await;
+        for (symbol, implied) in results {
+            if let Some(im) = implied {
+                moves.insert(symbol, im.implied_move_pct);
+            }
+        }
+    }
+    moves
 }
 
-/// Fetch logos from URLs (replaces base64 decoding)
-async fn fetch_logos_from_urls(
-    events: &[EarningsEvent],
-) -> std::collections::HashMap<String, RgbaImage> {
-    use std::collections::HashMap;
+/// Theme of the guild that owns `channel_id`; light when it cannot be resolved.
+pub async fn theme_for_channel(
+    http: &Http,
+    cache: Option<&RedisCache>,
+    channel_id: ChannelId,
+) -> CalendarTheme {
+    if cache.is_none() {
+        return CalendarTheme::default();
+    }
+    let guild_id = match channel_id.to_channel(http).await {
+        Ok(Channel::Guild(channel)) => Some(channel.guild_id.get()),
+        Ok(_) => None,
+        Err(e) => {
+            warn!("could not resolve guild for channel {}: {}", channel_id, e);
+            None
+        }
+    };
+    guild_settings::load_calendar_theme(cache, guild_id).await
+}
 
+/// `{name}.png` for a single page, otherwise `{name}-1.png`, `{name}-2.png`, …
+pub fn calendar_attachments(pages: Vec<Vec<u8>>, name: &str) -> Vec<CreateAttachment> {
+    let total = pages.len();
+    pages
+        .into_iter()
+        .enumerate()
+        .map(|(idx, bytes)| {
+            let filename = if total == 1 {
+                format!("{}.png", name)
+            } else {
+                format!("{}-{}.png", name, idx + 1)
+            };
+            CreateAttachment::bytes(bytes, filename)
+        })
+        .collect()
+}
+
+/// Split each day into pages of `MAX_PER_COLUMN` entries per session. The first page shows
+/// every day; later pages only the days that still have entries.
+fn paginate(columns: &[DayColumn]) -> Vec<Vec<PageColumn<'_>>> {
+    let page_count = columns
+        .iter()
+        .map(|c| c.before.len().max(c.after.len()).div_ceil(MAX_PER_COLUMN))
+        .max()
+        .unwrap_or(0)
+        .clamp(1, MAX_CALENDAR_PAGES);
+
+    let slice = |events: &[EarningsEvent], page: usize| -> (usize, usize) {
+        let start = (page * MAX_PER_COLUMN).min(events.len());
+        let end = (start + MAX_PER_COLUMN).min(events.len());
+        (start, end)
+    };
+
+    (0..page_count)
+        .map(|page| {
+            let last = page + 1 == page_count;
+            columns
+                .iter()
+                .filter_map(|column| {
+                    let (bs, be) = slice(&column.before, page);
+                    let (as_, ae) = slice(&column.after, page);
+                    if page > 0 && bs == be && as_ == ae {
+                        return None;
+                    }
+                    Some(PageColumn {
+                        date: column.date,
+                        continued: page > 0,
+                        before: &column.before[bs..be],
+                        after: &column.after[as_..ae],
+                        before_hidden: if last { column.before.len() - be } else { 0 },
+                        after_hidden: if last { column.after.len() - ae } else { 0 },
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Logos for the events' URLs, keyed by symbol. With Redis, cached logos and known-missing
+/// URLs (drawn as `placeholder_logo`) are served from `logo_cache`; only the rest are
+/// downloaded, and the results are cached for next time.
+pub(crate) async fn fetch_logos_from_urls(
+    events: &[EarningsEvent],
+    cache: Option<&RedisCache>,
+) -> HashMap<String, RgbaImage> {
     let mut logos = HashMap::new();
-    let client = reqwest::Client::builder()
-        .timeout(std::time::Duration::from_secs(3))
-        .build()
-        .unwrap_or_default();
 
     // Collect unique logo URLs
     let mut logo_urls: HashMap<String, String> = HashMap::new();
@@ -219,63 +426,185 @@ async fn fetch_logos_from_urls(
         }
     }
 
+    if let Some(cache) = cache {
+        let cached = load_cached_logos(cache, &logo_urls).await;
+        info!(
+            "Logo cache: {} hits, {} known missing",
+            cached.logos.len(),
+            cached.missing.len()
+        );
+        for symbol in cached.logos.keys().chain(&cached.missing) {
+            logo_urls.remove(symbol);
+        }
+        logos.extend(cached.logos);
+    }
+
+    if logo_urls.is_empty() {
+        return logos;
+    }
+
     info!("Fetching {} logos from URLs...", logo_urls.len());
+    let client = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(3))
+        .build()
+        .unwrap_or_default();
 
-    // Fetch logos in parallel with limited concurrency
+    // Fetch logos in parallel
     let mut tasks = Vec::new();
     for (symbol, url) in logo_urls {
         let client = client.clone();
-        let symbol = symbol.clone();
-        let url = url.clone();
-        
         tasks.push(tokio::spawn(async move {
-            match fetch_single_logo(&client, &url).await {
-                Ok(logo) => Some((symbol, logo)),
-                Err(e) => {
-                    warn!("Failed to fetch logo for {} from {}: {}", symbol, url, e);
-                    None
-                }
-            }
+            let fetched = fetch_single_logo(&client, &url).await;
+            (symbol, url, fetched)
         }));
     }
 
-    // Wait for all tasks and collect results
+    let mut fetched_count = 0usize;
     for task in tasks {
-        if let Ok(Some((symbol, logo))) = task.await {
-            logos.insert(symbol, logo);
+        let Ok((symbol, url, fetched)) = task.await else {
+            continue;
+        };
+        match fetched {
+            LogoFetch::Found { logo, hash } => {
+                if let Some(cache) = cache {
+                    cache_logo(cache, &symbol, &url, &hash, &logo).await;
+                }
+                logos.insert(symbol, logo);
+                fetched_count += 1;
+            }
+            LogoFetch::Missing(reason) => {
+                warn!("No usable logo for {} at {}: {}", symbol, url, reason);
+                if let Some(cache) = cache {
+                    if let Err(e) = logo_cache::store_missing(cache, &symbol, &url).await {
+                        warn!("failed to record missing logo for {}: {}", symbol, e);
+                    }
+                }
+            }
+            LogoFetch::Failed(reason) => {
+                warn!(
+                    "Failed to fetch logo for {} from {}: {}",
+                    symbol, url, reason
+                );
+            }
         }
     }
 
-    info!("Successfully loaded {} logos from URLs", logos.len());
+    info!("Successfully loaded {} logos from URLs", fetched_count);
     logos
 }
 
-async fn fetch_single_logo(
-    client: &reqwest::Client,
-    url: &str,
-) -> Result<RgbaImage, String> {
-    let response = client
-        .get(url)
-        .send()
-        .await
-        .map_err(|e| format!("request failed: {}", e))?;
+/// Download and cache logos for every event without drawing anything, so the week's
+/// daily and weekly renders hit the cache.
+pub async fn warm_logo_cache(events: &[EarningsEvent], cache: &RedisCache) {
+    let logos = fetch_logos_from_urls(events, Some(cache)).await;
+    info!("Logo cache warmed: {} logos available", logos.len());
+}
+
+struct CachedLogos {
+    logos: HashMap<String, RgbaImage>,
+    /// Symbols whose current URL is known to have no usable logo.
+    missing: Vec<String>,
+}
+
+async fn load_cached_logos(cache: &RedisCache, logo_urls: &HashMap<String, String>) -> CachedLogos {
+    let mut cached = CachedLogos {
+        logos: HashMap::new(),
+        missing: Vec::new(),
+    };
 
-    if !response.status().is_success() {
-        return Err(format!("HTTP {}", response.status()));
+    let symbols: Vec<String> = logo_urls.keys().cloned().collect();
+    let entries = match logo_cache::load_entries(cache, &symbols).await {
+        Ok(entries) => entries,
+        Err(e) => {
+            warn!("failed to read logo cache: {}", e);
+            return cached;
+        }
+    };
+
+    // Entries for an old URL are ignored and re-fetched
+    let mut wanted: HashMap<String, Vec<String>> = HashMap::new();
+    for (symbol, entry) in entries {
+        if logo_urls.get(&symbol) != Some(&entry.url) {
+            continue;
+        }
+        match entry.hash {
+            Some(hash) => wanted.entry(hash).or_default().push(symbol),
+            None => cached.missing.push(symbol),
+        }
     }
 
-    let bytes = response
-        .bytes()
-        .await
-        .map_err(|e| format!("failed to read bytes: {}", e))?;
+    let hashes: Vec<String> = wanted.keys().cloned().collect();
+    let blobs = match logo_cache::load_blobs(cache, &hashes).await {
+        Ok(blobs) => blobs,
+        Err(e) => {
+            warn!("failed to read cached logos: {}", e);
+            return cached;
+        }
+    };
+    for (hash, png) in blobs {
+        let Ok(img) = image::load_from_memory(&png) else {
+            continue;
+        };
+        let logo = img.to_rgba8();
+        for symbol in wanted.remove(&hash).unwrap_or_default() {
+            cached.logos.insert(symbol, logo.clone());
+        }
+    }
+
+    cached
+}
+
+async fn cache_logo(cache: &RedisCache, symbol: &str, url: &str, hash: &str, logo: &RgbaImage) {
+    let mut png = Vec::new();
+    if let Err(e) = logo.write_to(&mut Cursor::new(&mut png), ImageFormat::Png) {
+        warn!("failed to encode logo for {}: {}", symbol, e);
+        return;
+    }
+    if let Err(e) = logo_cache::store_logo(cache, symbol, url, hash, &png).await {
+        warn!("failed to cache logo for {}: {}", symbol, e);
+    }
+}
+
+enum LogoFetch {
+    Found {
+        logo: RgbaImage,
+        hash: String,
+    },
+    /// The URL answered without a usable image (4xx or undecodable); cached as missing.
+    Missing(String),
+    /// Network error or 5xx; retried on the next render.
+    Failed(String),
+}
+
+async fn fetch_single_logo(client: &reqwest::Client, url: &str) -> LogoFetch {
+    let response = match client.get(url).send().await {
+        Ok(response) => response,
+        Err(e) => return LogoFetch::Failed(format!("request failed: {}", e)),
+    };
+
+    let status = response.status();
+    if status.is_client_error() {
+        return LogoFetch::Missing(format!("HTTP {}", status));
+    }
+    if !status.is_success() {
+        return LogoFetch::Failed(format!("HTTP {}", status));
+    }
 
-    let img = image::load_from_memory(&bytes)
-        .map_err(|e| format!("failed to decode image: {}", e))?;
+    let bytes = match response.bytes().await {
+        Ok(bytes) => bytes,
+        Err(e) => return LogoFetch::Failed(format!("failed to read bytes: {}", e)),
+    };
 
-    Ok(fit_logo(&img))
+    match image::load_from_memory(&bytes) {
+        Ok(img) => LogoFetch::Found {
+            logo: fit_logo(&img),
+            hash: logo_cache::content_hash(&bytes),
+        },
+        Err(e) => LogoFetch::Missing(format!("failed to decode image: {}", e)),
+    }
 }
 
-fn load_font() -> Result<FontArc, String> {
+pub(crate) fn load_font() -> Result<FontArc, String> {
     let source = SystemSource::new();
 
     let handle = source
@@ -327,19 +656,33 @@ fn build_columns(events: &[EarningsEvent]) -> Vec<DayColumn> {
             after: Vec::new(),
         });
 
-        match classify_session(ev.time_of_day.as_deref()) {
-            Session::Before | Session::Tba => entry.before.push(ev.clone()),
-            Session::After => entry.after.push(ev.clone()),
+        match ev.session {
+            EarningsSession::AfterClose => entry.after.push(ev.clone()),
+            _ => entry.before.push(ev.clone()),
         }
     }
 
-    grouped.into_values().take(5).collect()
+    let mut columns: Vec<DayColumn> = grouped.into_values().take(5).collect();
+    for column in &mut columns {
+        column.before.sort_by(by_importance);
+        column.after.sort_by(by_importance);
+    }
+    columns
+}
+
+/// Highest importance first (unranked last), then symbol.
+fn by_importance(a: &EarningsEvent, b: &EarningsEvent) -> Ordering {
+    b.importance
+        .cmp(&a.importance)
+        .then_with(|| a.symbol.cmp(&b.symbol))
 }
 
 fn draw_canvas(
-    columns: &[DayColumn],
+    columns: &[PageColumn],
     font: &FontArc,
-    logos: &std::collections::HashMap<String, RgbaImage>,
+    logos: &HashMap<String, RgbaImage>,
+    implied_moves: &HashMap<String, f64>,
+    palette: &Palette,
 ) -> RgbaImage {
     let count = columns.len() as u32;
     let width = count * (DAY_WIDTH + DIVIDER_WIDTH) + 2 * MARGIN - DIVIDER_WIDTH;
@@ -348,46 +691,48 @@ fn draw_canvas(
         .iter()
         .map(|c| c.before.len().max(c.after.len()))
         .max()
-        .unwrap_or(0)
-        .min(MAX_PER_COLUMN);
+        .unwrap_or(0);
 
     let height = MARGIN + HEADER_HEIGHT + (max_entries as u32 * ENTRY_HEIGHT) + MARGIN;
 
-    let mut img = RgbaImage::from_pixel(width, height, CANVAS_BG);
+    let mut img = RgbaImage::from_pixel(width, height, palette.canvas);
 
     for (idx, column) in columns.iter().enumerate() {
         let x = MARGIN + idx as u32 * (DAY_WIDTH + DIVIDER_WIDTH);
-        draw_day_column(&mut img, x, MARGIN, column, font, logos);
+        draw_day_column(&mut img, x, MARGIN, column, font, logos, implied_moves, palette);
 
         if idx < columns.len() - 1 {
             let divider_x = x + DAY_WIDTH;
             let divider_rect = Rect::at(divider_x as i32, MARGIN as i32)
                 .of_size(DIVIDER_WIDTH, height - 2 * MARGIN);
-            draw_filled_rect_mut(&mut img, divider_rect, DIVIDER_COLOR);
+            draw_filled_rect_mut(&mut img, divider_rect, palette.divider);
         }
     }
 
     img
 }
 
+#[allow(clippy::too_many_arguments)]
 fn draw_day_column(
     img: &mut RgbaImage,
     x: u32,
     y: u32,
-    column: &DayColumn,
+    column: &PageColumn,
     font: &FontArc,
-    logos: &std::collections::HashMap<String, RgbaImage>,
+    logos: &HashMap<String, RgbaImage>,
+    implied_moves: &HashMap<String, f64>,
+    palette: &Palette,
 ) {
-    let max_entries = column
-        .before
-        .len()
-        .max(column.after.len())
-        .min(MAX_PER_COLUMN);
+    let max_entries = column.before.len().max(column.after.len());
     let col_height = HEADER_HEIGHT + (max_entries as u32 * ENTRY_HEIGHT);
     let bg_rect = Rect::at(x as i32, y as i32).of_size(DAY_WIDTH, col_height);
-    draw_filled_rect_mut(img, bg_rect, COLUMN_BG);
+    draw_filled_rect_mut(img, bg_rect, palette.column);
 
-    let day_label = column.date.weekday().to_string();
+    let day_label = if column.continued {
+        format!("{} (cont.)", column.date.weekday())
+    } else {
+        column.date.weekday().to_string()
+    };
     let date_label = column.date.format("%b %e").to_string();
 
     draw_centered_text(
@@ -398,7 +743,7 @@ fn draw_day_column(
         x,
         DAY_WIDTH,
         y + 8,
-        TITLE_COLOR,
+        palette.title,
     );
     draw_centered_text(
         img,
@@ -408,7 +753,7 @@ fn draw_day_column(
         x,
         DAY_WIDTH,
         y + 35,
-        HEADER_COLOR,
+        palette.header,
     );
 
     let header_y = y + HEADER_HEIGHT - 20;
@@ -420,7 +765,7 @@ fn draw_day_column(
         x,
         HALF_WIDTH,
         header_y,
-        HEADER_COLOR,
+        palette.header,
     );
     draw_centered_text(
         img,
@@ -430,42 +775,54 @@ fn draw_day_column(
         x + HALF_WIDTH,
         HALF_WIDTH,
         header_y,
-        HEADER_COLOR,
+        palette.header,
     );
 
     let entry_start_y = y + HEADER_HEIGHT;
-    draw_half_column(img, font, x, entry_start_y, &column.before, logos);
-    draw_half_column(
-        img,
-        font,
-        x + HALF_WIDTH,
-        entry_start_y,
-        &column.after,
-        logos,
-    );
+    let halves = [
+        (x, column.before, column.before_hidden),
+        (x + HALF_WIDTH, column.after, column.after_hidden),
+    ];
+    for (half_x, events, hidden) in halves {
+        draw_half_column(
+            img,
+            font,
+            half_x,
+            entry_start_y,
+            events,
+            hidden,
+            logos,
+            implied_moves,
+            palette,
+        );
+    }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn draw_half_column(
     img: &mut RgbaImage,
     font: &FontArc,
     x: u32,
     y: u32,
     events: &[EarningsEvent],
-    logos: &std::collections::HashMap<String, RgbaImage>,
+    hidden: usize,
+    logos: &HashMap<String, RgbaImage>,
+    implied_moves: &HashMap<String, f64>,
+    palette: &Palette,
 ) {
-    for (idx, ev) in events.iter().take(MAX_PER_COLUMN).enumerate() {
+    for (idx, ev) in events.iter().enumerate() {
         let entry_y = y + (idx as u32 * ENTRY_HEIGHT);
 
         let entry_rect = Rect::at((x + 4) as i32, (entry_y + 2) as i32)
             .of_size(HALF_WIDTH - 8, ENTRY_HEIGHT - 4);
-        draw_filled_rect_mut(img, entry_rect, ENTRY_BG);
+        draw_filled_rect_mut(img, entry_rect, palette.entry);
 
         // Place ticker on the left and logo on the right so they do not overlap
         let text_y = entry_y + 10;
         let text_x = x + 12;
         draw_text_mut(
             img,
-            HEADER_COLOR,
+            palette.header,
             text_x as i32,
             text_y as i32,
             PxScale::from(14.0),
@@ -474,19 +831,42 @@ fn draw_half_column(
         );
 
         let logo_x = x + HALF_WIDTH - LOGO_W - 8;
-        let logo_y = entry_y + (ENTRY_HEIGHT.saturating_sub(LOGO_H)) / 2;
+        let logo_y = entry_y + 10;
         if let Some(logo) = logos.get(&ev.symbol) {
+            if let Some(plate) = palette.logo_plate {
+                let plate_rect = Rect::at(logo_x as i32, logo_y as i32).of_size(LOGO_W, LOGO_H);
+                draw_filled_rect_mut(img, plate_rect, plate);
+            }
             imageops::overlay(img, logo, logo_x as i64, logo_y as i64);
         } else {
-            let placeholder = placeholder_logo(&ev.symbol, font);
+            let placeholder = placeholder_logo(&ev.symbol, font, palette.header);
             imageops::overlay(img, &placeholder, logo_x as i64, logo_y as i64);
         }
+
+        // Bottom line: consensus EPS on the left, implied move on the right
+        let metrics_y = (entry_y + ENTRY_HEIGHT - 30) as i32;
+        let scale = PxScale::from(13.0);
+        let eps = format!("EPS {}", format_eps(ev.eps_estimate));
+        draw_text_mut(img, palette.text, text_x as i32, metrics_y, scale, font, &eps);
+        if let Some(implied) = implied_moves.get(&ev.symbol) {
+            let text = format!("IM ±{:.1}%", implied);
+            let (tw, _) = text_size(scale, font, &text);
+            let right = (x + HALF_WIDTH - 12) as i32;
+            draw_text_mut(
+                img,
+                palette.text,
+                right - tw as i32,
+                metrics_y,
+                scale,
+                font,
+                &text,
+            );
+        }
     }
 
-    let overflow = events.len().saturating_sub(MAX_PER_COLUMN);
-    if overflow > 0 {
-        let overflow_y = y + (MAX_PER_COLUMN as u32 * ENTRY_HEIGHT) - 15;
-        let notice = format!("+{}", overflow);
+    if hidden > 0 {
+        let overflow_y = y + (events.len() as u32 * ENTRY_HEIGHT) - 15;
+        let notice = format!("+{}", hidden);
         draw_centered_text(
             img,
             font,
@@ -495,12 +875,12 @@ fn draw_half_column(
             x,
             HALF_WIDTH,
             overflow_y,
-            HEADER_COLOR,
+            palette.header,
         );
     }
 }
 
-fn placeholder_logo(symbol: &str, font: &FontArc) -> RgbaImage {
+pub(crate) fn placeholder_logo(symbol: &str, font: &FontArc, color: Rgba<u8>) -> RgbaImage {
     // Transparent background so logos are not placed on a card
     let mut img = RgbaImage::from_pixel(LOGO_W, LOGO_H, Rgba([0, 0, 0, 0]));
     draw_centered_text(
@@ -511,7 +891,7 @@ fn placeholder_logo(symbol: &str, font: &FontArc) -> RgbaImage {
         0,
         LOGO_W,
         (LOGO_H / 2) - 12,
-        HEADER_COLOR,
+        color,
     );
     img
 }
@@ -540,17 +920,3 @@ fn draw_centered_text(
         text,
     );
 }
-
-fn classify_session(time: Option<&str>) -> Session {
-    let Some(raw) = time else {
-        return Session::Tba;
-    };
-    let t = raw.to_ascii_lowercase();
-    if t.contains("amc") || t.contains("after") || t.starts_with("16") || t.ends_with("pm") {
-        Session::After
-    } else if t.contains("bmo") || t.contains("pre") || t.starts_with("09") || t.ends_with("am") {
-        Session::Before
-    } else {
-        Session::Tba
-    }
-}
@@ -0,0 +1,373 @@
+use std::collections::{BTreeSet, HashSet};
+use std::env;
+use std::sync::Arc;
+
+use chrono::{Duration, NaiveDate};
+use serenity::all::{ChannelId, CreateMessage, Http};
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+use crate::models::{HolderType, InsiderTransaction};
+use crate::service::automation::earnings::after_daily_report::split_message;
+use crate::service::caching::collections::insider_feed::{self, transaction_key};
+use crate::service::caching::collections::watchlist;
+use crate::service::caching::RedisCache;
+use crate::service::finance::holders::TradeSide;
+use crate::service::finance::statements::abbreviate;
+use crate::service::finance::FinanceService;
+
+/// Form 4 filings trickle in during the day; Yahoo refreshes them slowly.
+const POLL_SECS: u64 = 1800;
+
+/// Discord caps messages at 2000 characters.
+const MESSAGE_CHARS: usize = 1900;
+
+/// Thresholds for the insider feed, read from the environment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InsiderFeedConfig {
+    /// Smallest purchase or sale value (USD) that gets posted.
+    pub min_value: i64,
+    /// Purchases within this many days of each other count towards one cluster.
+    pub cluster_window_days: i64,
+    /// Distinct insiders needed for a cluster buy.
+    pub cluster_min_insiders: usize,
+}
+
+impl Default for InsiderFeedConfig {
+    fn default() -> Self {
+        Self {
+            min_value: 100_000,
+            cluster_window_days: 14,
+            cluster_min_insiders: 3,
+        }
+    }
+}
+
+impl InsiderFeedConfig {
+    /// `INSIDER_FEED_MIN_VALUE`, `INSIDER_CLUSTER_WINDOW_DAYS` and
+    /// `INSIDER_CLUSTER_MIN_INSIDERS`, each falling back to the default.
+    pub fn from_env() -> Self {
+        fn var<T: std::str::FromStr>(name: &str) -> Option<T> {
+            let raw = env::var(name).ok()?;
+            match raw.trim().parse() {
+                Ok(v) => Some(v),
+                Err(_) => {
+                    warn!("insider feed: {name} is set but not a valid number");
+                    None
+                }
+            }
+        }
+        let defaults = Self::default();
+        Self {
+            min_value: var("INSIDER_FEED_MIN_VALUE").unwrap_or(defaults.min_value),
+            cluster_window_days: var("INSIDER_CLUSTER_WINDOW_DAYS")
+                .unwrap_or(defaults.cluster_window_days),
+            cluster_min_insiders: var("INSIDER_CLUSTER_MIN_INSIDERS")
+                .unwrap_or(defaults.cluster_min_insiders),
+        }
+    }
+}
+
+/// Several insiders buying on the open market within the cluster window.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClusterBuy {
+    /// Distinct insiders, in the order of their first purchase.
+    pub insiders: Vec<String>,
+    pub first: NaiveDate,
+    pub last: NaiveDate,
+    /// Summed value of the purchases that reported one.
+    pub total_value: i64,
+}
+
+fn resolve_channel_id(var_names: &[&str], feature_label: &str) -> Option<ChannelId> {
+    for name in var_names {
+        if let Ok(value) = env::var(name) {
+            match value.parse::<u64>() {
+                Ok(id) => return Some(ChannelId::new(id)),
+                Err(_) => warn!("{feature_label}: {name} is set but not a valid u64 channel id"),
+            }
+        }
+    }
+
+    info!(
+        "{feature_label} not started; set one of these env vars: {:?}",
+        var_names
+    );
+    None
+}
+
+/// Spawn the insider trading feed: polls insider transactions for every watchlist symbol
+/// and posts new open-market purchases and sales above the threshold, plus cluster buys.
+pub fn spawn_insider_feed(
+    http: Arc<Http>,
+    finance: Arc<FinanceService>,
+    cache: Option<Arc<RedisCache>>,
+) -> Option<JoinHandle<()>> {
+    if env::var("ENABLE_INSIDER_FEED")
+        .map(|v| v == "0")
+        .unwrap_or(false)
+    {
+        info!("Insider feed disabled via ENABLE_INSIDER_FEED=0");
+        return None;
+    }
+
+    let channel_id = resolve_channel_id(&["INSIDER_FEED_CHANNEL_ID"], "insider feed")?;
+    let Some(cache) = cache else {
+        info!("Insider feed not started; seen transactions require REDIS_URL");
+        return None;
+    };
+    let config = InsiderFeedConfig::from_env();
+
+    info!(
+        "Starting insider feed to channel {} (min ${}, clusters of {} within {}d)",
+        channel_id, config.min_value, config.cluster_min_insiders, config.cluster_window_days
+    );
+
+    Some(tokio::spawn(async move {
+        let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(POLL_SECS));
+        loop {
+            interval.tick().await;
+            run_once(&http, &finance, &cache, channel_id, &config).await;
+        }
+    }))
+}
+
+/// One pass over the watchlist; failures for one symbol do not stop the rest.
+pub async fn run_once(
+    http: &Http,
+    finance: &FinanceService,
+    cache: &RedisCache,
+    channel_id: ChannelId,
+    config: &InsiderFeedConfig,
+) {
+    let symbols = watchlist::load_symbols(Some(cache)).await;
+    if symbols.is_empty() {
+        info!(
+            "Insider feed: watchlist is empty; set WATCHLIST_SYMBOLS or the watchlist:symbols set"
+        );
+        return;
+    }
+
+    for symbol in &symbols {
+        match check_symbol(finance, cache, symbol, config).await {
+            Ok(update) => post_update(http, cache, channel_id, symbol, update).await,
+            Err(e) => warn!("insider feed failed for {}: {}", symbol, e),
+        }
+    }
+}
+
+/// Transactions new since the last pass and the feed lines they produce.
+#[derive(Default)]
+struct FeedUpdate {
+    lines: Vec<String>,
+    new_keys: Vec<String>,
+}
+
+/// Post the lines, then mark the transactions seen. A failed send leaves them unseen so
+/// the next pass retries them.
+async fn post_update(
+    http: &Http,
+    cache: &RedisCache,
+    channel_id: ChannelId,
+    symbol: &str,
+    update: FeedUpdate,
+) {
+    for chunk in split_message(&update.lines, MESSAGE_CHARS) {
+        if let Err(e) = channel_id
+            .send_message(http, CreateMessage::new().content(chunk))
+            .await
+        {
+            warn!("failed to post insider feed for {}: {}", symbol, e);
+            return;
+        }
+    }
+    if let Err(e) = insider_feed::mark_seen(cache, symbol, &update.new_keys).await {
+        warn!("failed to record seen transactions for {}: {}", symbol, e);
+    }
+}
+
+/// Feed lines for the symbol's transactions not yet seen. The first pass for a symbol
+/// only seeds the seen set; later passes leave marking to the caller once posted.
+async fn check_symbol(
+    finance: &FinanceService,
+    cache: &RedisCache,
+    symbol: &str,
+    config: &InsiderFeedConfig,
+) -> Result<FeedUpdate, String> {
+    let txs = finance
+        .get_holders(symbol, HolderType::InsiderTransactions)
+        .await
+        .map_err(|e| format!("fetch error: {e}"))?
+        .insider_transactions
+        .unwrap_or_default();
+    if txs.is_empty() {
+        return Ok(FeedUpdate::default());
+    }
+    let keys: Vec<String> = txs.iter().map(transaction_key).collect();
+
+    let seeded = insider_feed::has_history(cache, symbol)
+        .await
+        .map_err(|e| format!("failed to read seen transactions: {e}"))?;
+    if !seeded {
+        let new_keys = insider_feed::mark_seen(cache, symbol, &keys)
+            .await
+            .map_err(|e| format!("failed to record seen transactions: {e}"))?;
+        info!(
+            "Insider feed: seeded {} transactions for {}",
+            new_keys.len(),
+            symbol
+        );
+        return Ok(FeedUpdate::default());
+    }
+
+    let new_keys = insider_feed::unseen(cache, symbol, &keys)
+        .await
+        .map_err(|e| format!("failed to read seen transactions: {e}"))?;
+    let mut fresh: Vec<&InsiderTransaction> = txs
+        .iter()
+        .zip(&keys)
+        .filter(|(_, key)| new_keys.contains(key))
+        .map(|(tx, _)| tx)
+        .collect();
+    fresh.sort_by_key(|tx| tx.start_date);
+
+    let mut lines: Vec<String> = fresh
+        .iter()
+        .filter(|tx| is_reportable(tx, config.min_value))
+        .map(|tx| format_transaction(symbol, tx))
+        .collect();
+    if let Some(cluster) = cluster_alert(&txs, &fresh, config) {
+        lines.push(format_cluster(symbol, &cluster, config));
+    }
+
+    Ok(FeedUpdate { lines, new_keys })
+}
+
+/// Open-market purchases and sales whose value is at least `min_value`. Awards, gifts,
+/// exercises and rows without a value are skipped.
+pub fn is_reportable(tx: &InsiderTransaction, min_value: i64) -> bool {
+    TradeSide::of(tx) != TradeSide::Other && tx.value.is_some_and(|v| v >= min_value)
+}
+
+/// Distinct insiders with open-market purchases in the window ending on `anchor`, when
+/// there are at least `cluster_min_insiders` of them.
+pub fn cluster_buy(
+    txs: &[InsiderTransaction],
+    anchor: NaiveDate,
+    config: &InsiderFeedConfig,
+) -> Option<ClusterBuy> {
+    let start = anchor - Duration::days(config.cluster_window_days);
+    let mut buys: Vec<&InsiderTransaction> = txs
+        .iter()
+        .filter(|tx| TradeSide::of(tx) == TradeSide::Buy)
+        .filter(|tx| (start..=anchor).contains(&tx.start_date.date_naive()))
+        .collect();
+    buys.sort_by_key(|tx| tx.start_date);
+
+    let mut seen = BTreeSet::new();
+    let insiders: Vec<String> = buys
+        .iter()
+        .map(|tx| tx.insider.trim().to_string())
+        .filter(|name| seen.insert(name.to_ascii_uppercase()))
+        .collect();
+    if insiders.len() < config.cluster_min_insiders.max(2) {
+        return None;
+    }
+
+    Some(ClusterBuy {
+        insiders,
+        first: buys.first()?.start_date.date_naive(),
+        last: buys.last()?.start_date.date_naive(),
+        total_value: buys.iter().filter_map(|tx| tx.value).sum(),
+    })
+}
+
+/// Cluster to announce for the new transactions `fresh` (drawn from `txs`): the cluster
+/// ending on the newest new purchase, but only when a new purchase comes from an insider
+/// with no earlier purchase in that window. A new purchase of any size counts, while
+/// repeat buys by insiders already in the cluster do not re-announce it.
+pub fn cluster_alert(
+    txs: &[InsiderTransaction],
+    fresh: &[&InsiderTransaction],
+    config: &InsiderFeedConfig,
+) -> Option<ClusterBuy> {
+    let is_buy = |tx: &InsiderTransaction| TradeSide::of(tx) == TradeSide::Buy;
+    let anchor = fresh
+        .iter()
+        .filter(|tx| is_buy(tx))
+        .map(|tx| tx.start_date.date_naive())
+        .max()?;
+    let cluster = cluster_buy(txs, anchor, config)?;
+
+    let start = anchor - Duration::days(config.cluster_window_days);
+    let in_window = |tx: &InsiderTransaction| {
+        is_buy(tx) && (start..=anchor).contains(&tx.start_date.date_naive())
+    };
+    let insider = |tx: &InsiderTransaction| tx.insider.trim().to_ascii_uppercase();
+    let fresh_keys: HashSet<String> = fresh.iter().map(|tx| transaction_key(tx)).collect();
+    let earlier: HashSet<String> = txs
+        .iter()
+        .filter(|tx| in_window(tx) && !fresh_keys.contains(&transaction_key(tx)))
+        .map(insider)
+        .collect();
+
+    fresh
+        .iter()
+        .any(|tx| in_window(tx) && !earlier.contains(&insider(tx)))
+        .then_some(cluster)
+}
+
+/// `🟢 AAPL insider buy — DOE JANE (Director) bought 10.00K sh for $1.25M on Oct 14 · Purchase at price 125.00 per share`
+pub fn format_transaction(symbol: &str, tx: &InsiderTransaction) -> String {
+    let (emoji, kind, verb) = match TradeSide::of(tx) {
+        TradeSide::Buy => ("🟢", "buy", "bought"),
+        TradeSide::Sell => ("🔴", "sale", "sold"),
+        TradeSide::Other => ("⚪", "transaction", "traded"),
+    };
+    let position = if tx.position.trim().is_empty() {
+        String::new()
+    } else {
+        format!(" ({})", tx.position.trim())
+    };
+    let shares = tx
+        .shares
+        .map(|s| format!(" {} sh", abbreviate(s as f64)))
+        .unwrap_or_default();
+    let value = tx
+        .value
+        .map(|v| format!(" for ${}", abbreviate(v as f64)))
+        .unwrap_or_default();
+
+    format!(
+        "{} **{}** insider {} — {}{} {}{}{} on {} · {}",
+        emoji,
+        symbol,
+        kind,
+        tx.insider.trim(),
+        position,
+        verb,
+        shares,
+        value,
+        tx.start_date.date_naive().format("%b %-d"),
+        tx.transaction.trim().trim_end_matches('.')
+    )
+}
+
+/// `🚨 AAPL cluster buy — 3 insiders bought within 14 days (Oct 1 – Oct 10), $2.10M total: A, B, C`
+pub fn format_cluster(symbol: &str, cluster: &ClusterBuy, config: &InsiderFeedConfig) -> String {
+    let total = if cluster.total_value > 0 {
+        format!(", ${} total", abbreviate(cluster.total_value as f64))
+    } else {
+        String::new()
+    };
+    format!(
+        "🚨 **{}** cluster buy — {} insiders bought within {} days ({} – {}){}: {}",
+        symbol,
+        cluster.insiders.len(),
+        config.cluster_window_days,
+        cluster.first.format("%b %-d"),
+        cluster.last.format("%b %-d"),
+        total,
+        cluster.insiders.join(", ")
+    )
+}
@@ -0,0 +1,3 @@
+pub mod insider_feed;
+
+pub use insider_feed::*;
@@ -1,2 +1,3 @@
 pub mod earnings;
+pub mod insiders;
 pub mod options_data;
@@ -0,0 +1,93 @@
+use std::{env, sync::Arc, time::Duration};
+
+use chrono::{Datelike, Timelike, Utc, Weekday};
+use chrono_tz::America::New_York;
+use once_cell::sync::Lazy;
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+use crate::service::caching::collections::{iv_history, watchlist};
+use crate::service::caching::RedisCache;
+use crate::service::finance::FinanceService;
+
+static LAST_RECORD_DATE: Lazy<Mutex<Option<chrono::NaiveDate>>> = Lazy::new(|| Mutex::new(None));
+
+/// Spawn the daily IV recorder (Mon–Fri at 4:15 PM ET) for every watchlist symbol.
+pub fn spawn_iv_recorder(
+    finance: Arc<FinanceService>,
+    cache: Option<Arc<RedisCache>>,
+) -> Option<JoinHandle<()>> {
+    if env::var("ENABLE_IV_RECORDER")
+        .map(|v| v == "0")
+        .unwrap_or(false)
+    {
+        info!("IV recorder disabled via ENABLE_IV_RECORDER=0");
+        return None;
+    }
+
+    let Some(cache) = cache else {
+        info!("REDIS_URL not set; IV recorder not started");
+        return None;
+    };
+
+    info!("Starting daily IV recorder");
+
+    Some(tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(60));
+        loop {
+            interval.tick().await;
+            if should_record_now().await {
+                record_once(&finance, &cache).await;
+            }
+        }
+    }))
+}
+
+/// Record today's 30-day ATM IV for each watchlist symbol.
+pub async fn record_once(finance: &FinanceService, cache: &RedisCache) {
+    let today = Utc::now().with_timezone(&New_York).date_naive();
+    let symbols = watchlist::load_symbols(Some(cache)).await;
+    if symbols.is_empty() {
+        info!(
+            "IV recorder: watchlist is empty; set WATCHLIST_SYMBOLS or the watchlist:symbols set"
+        );
+        return;
+    }
+
+    let mut recorded = 0usize;
+    for symbol in &symbols {
+        match finance.get_iv30(symbol).await {
+            Ok(iv) => match iv_history::record(cache, symbol, today, iv).await {
+                Ok(()) => recorded += 1,
+                Err(e) => warn!("failed to store IV for {}: {}", symbol, e),
+            },
+            Err(e) => warn!("IV30 unavailable for {}: {}", symbol, e),
+        }
+    }
+
+    info!(
+        "IV recorder stored {} of {} watchlist symbols for {}",
+        recorded,
+        symbols.len(),
+        today
+    );
+}
+
+async fn should_record_now() -> bool {
+    let now_et = Utc::now().with_timezone(&New_York);
+    if matches!(now_et.weekday(), Weekday::Sat | Weekday::Sun) {
+        return false;
+    }
+    if !(now_et.hour() == 16 && (15..20).contains(&now_et.minute())) {
+        return false;
+    }
+
+    let today = now_et.date_naive();
+    let mut last = LAST_RECORD_DATE.lock().await;
+    if *last == Some(today) {
+        return false;
+    }
+    *last = Some(today);
+    true
+}
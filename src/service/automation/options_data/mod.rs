@@ -1,3 +1,5 @@
+pub mod iv_history;
 pub mod spy_data;
 
+pub use iv_history::*;
 pub use spy_data::*;
@@ -9,7 +9,7 @@ use tokio::sync::Mutex;
 use tokio::task::JoinHandle;
 use tracing::{error, info, warn};
 
-use crate::service::caching::collections::spy_data as cache_spy;
+use crate::service::caching::collections::spy_data::{self as cache_spy, HistoryPoint, HistorySeries};
 use crate::service::caching::RedisCache;
 use crate::service::finance::options::OptionSlice;
 use crate::service::finance::FinanceService;
@@ -42,7 +42,16 @@ pub fn spawn_options_pinger(
         }
     };
 
-    info!("Starting options pinger for SPY to channel {}", channel_id);
+    let series = env::var("OPTIONS_CHART_SERIES")
+        .ok()
+        .and_then(|v| HistorySeries::parse(&v))
+        .unwrap_or(HistorySeries::Last);
+
+    info!(
+        "Starting options pinger for SPY to channel {} (charting {})",
+        channel_id,
+        series.as_str()
+    );
     let cache = cache.clone();
 
     Some(tokio::spawn(async move {
@@ -50,7 +59,9 @@ pub fn spawn_options_pinger(
         loop {
             interval.tick().await;
             if should_run_now().await {
-                if let Err(e) = post_once(&http, &finance, cache.as_deref(), channel_id).await {
+                if let Err(e) =
+                    post_once(&http, &finance, cache.as_deref(), channel_id, series).await
+                {
                     error!("options pinger iteration failed: {e}");
                 }
             }
@@ -63,6 +74,7 @@ async fn post_once(
     finance: &FinanceService,
     cache: Option<&RedisCache>,
     channel_id: ChannelId,
+    series: HistorySeries,
 ) -> Result<(), String> {
     let slice = finance
         .get_option_slice_today("SPY", 5)
@@ -95,7 +107,7 @@ async fn post_once(
     let history = history.unwrap_or_else(|| cache_spy::history_from_slice(&slice));
 
     let summary = format_slice(&slice);
-    match build_chart_bytes(&slice, &history).await {
+    match build_history_chart(&slice.expiration, &history, series).await {
         Ok(bytes) => {
             let attachment = CreateAttachment::bytes(bytes, "spy_options.png");
             let builder = serenity::builder::CreateMessage::new()
@@ -159,9 +171,11 @@ fn fmt_side(contracts: &[OptionContract]) -> String {
     lines.join("\n")
 }
 
-async fn build_chart_bytes(
-    slice: &OptionSlice,
-    history: &HashMap<String, Vec<(chrono::DateTime<Utc>, f64)>>,
+/// Render a per-strike line chart of the chosen series via QuickChart.
+pub async fn build_history_chart(
+    expiration: &str,
+    history: &HashMap<String, Vec<HistoryPoint>>,
+    series: HistorySeries,
 ) -> Result<Vec<u8>, String> {
     let mut strikes: Vec<_> = history.keys().cloned().collect();
     strikes.sort_by(|a, b| {
@@ -179,12 +193,13 @@ async fn build_chart_bytes(
 
     for strike in strikes {
         if let Some(points) = history.get(&strike) {
-            if points.is_empty() {
-                continue;
-            }
             let data: Vec<_> = points
                 .iter()
-                .map(|(t, p)| serde_json::json!({"x": t.to_rfc3339(), "y": p}))
+                .filter_map(|point| {
+                    series.value(point).map(|y| {
+                        serde_json::json!({"x": point.time.to_rfc3339(), "y": y})
+                    })
+                })
                 .collect();
             if data.is_empty() {
                 continue;
@@ -203,7 +218,10 @@ async fn build_chart_bytes(
     }
 
     if datasets.is_empty() {
-        return Err("no historical data to chart yet".into());
+        return Err(format!(
+            "no {} history to chart yet",
+            series.label().to_ascii_lowercase()
+        ));
     }
 
     let chart = serde_json::json!({
@@ -212,7 +230,7 @@ async fn build_chart_bytes(
         "options": {
             "plugins": {
                 "legend": { "position": "bottom" },
-                "title": { "display": true, "text": format!("SPY {} history", slice.expiration) }
+                "title": { "display": true, "text": format!("SPY {} {} history", expiration, series.label()) }
             },
             "scales": {
                 "x": {
@@ -220,15 +238,24 @@ async fn build_chart_bytes(
                     "time": { "unit": "minute" },
                     "title": { "display": true, "text": "Time" }
                 },
-                "y": { "title": { "display": true, "text": "Price" } }
+                "y": { "title": { "display": true, "text": series.label() } }
             }
         }
     });
 
+    render_quickchart(chart, 800, 400).await
+}
+
+/// POST a Chart.js config to QuickChart and return the PNG bytes.
+pub async fn render_quickchart(
+    chart: serde_json::Value,
+    width: u32,
+    height: u32,
+) -> Result<Vec<u8>, String> {
     let body = serde_json::json!({
         "chart": chart,
-        "width": 800,
-        "height": 400,
+        "width": width,
+        "height": height,
         "backgroundColor": "white",
         "plugins": ["chartjs-adapter-date-fns"],
         "version": "4.4.0"
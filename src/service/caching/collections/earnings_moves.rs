@@ -0,0 +1,110 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use redis::{AsyncCommands, RedisError};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::models::EarningsSession;
+use crate::service::caching::{CacheError, RedisCache};
+
+fn moves_key(symbol: &str) -> String {
+    format!("earnings:moves:{}", symbol.to_ascii_uppercase())
+}
+
+/// Where the realized move in a record came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ActualMoveSource {
+    /// Pre-market / after-hours price vs the regular close, captured by the post-earnings report.
+    ExtendedHours,
+    /// Close of the reaction session vs the close before the report.
+    SessionClose,
+}
+
+/// Pre-earnings implied move and, once known, the realized move for one report.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EarningsMoveRecord {
+    pub symbol: String,
+    pub date: NaiveDate,
+    pub session: EarningsSession,
+    pub implied_move_pct: f64,
+    pub spot: f64,
+    pub recorded_at: DateTime<Utc>,
+    #[serde(default)]
+    pub actual_move_pct: Option<f64>,
+    #[serde(default)]
+    pub actual_source: Option<ActualMoveSource>,
+}
+
+#[derive(Debug, Error)]
+pub enum EarningsMoveStoreError {
+    #[error(transparent)]
+    Cache(#[from] CacheError),
+    #[error(transparent)]
+    Serde(#[from] serde_json::Error),
+    #[error(transparent)]
+    Redis(#[from] RedisError),
+}
+
+/// Store the implied move unless one was already recorded for that report, so the
+/// earliest pre-earnings snapshot wins. Returns `true` when a new record was written.
+pub async fn record_implied(
+    cache: &RedisCache,
+    record: &EarningsMoveRecord,
+) -> Result<bool, EarningsMoveStoreError> {
+    let mut conn = cache.connection();
+    let payload = serde_json::to_string(record)?;
+    let written: bool = conn
+        .hset_nx(
+            moves_key(&record.symbol),
+            record.date.format("%Y-%m-%d").to_string(),
+            payload,
+        )
+        .await?;
+    Ok(written)
+}
+
+/// Overwrite a record (used to attach realized moves).
+pub async fn save(
+    cache: &RedisCache,
+    record: &EarningsMoveRecord,
+) -> Result<(), EarningsMoveStoreError> {
+    let mut conn = cache.connection();
+    let payload = serde_json::to_string(record)?;
+    let _: () = conn
+        .hset(
+            moves_key(&record.symbol),
+            record.date.format("%Y-%m-%d").to_string(),
+            payload,
+        )
+        .await?;
+    Ok(())
+}
+
+pub async fn load(
+    cache: &RedisCache,
+    symbol: &str,
+    date: NaiveDate,
+) -> Result<Option<EarningsMoveRecord>, EarningsMoveStoreError> {
+    let mut conn = cache.connection();
+    let stored: Option<String> = conn
+        .hget(moves_key(symbol), date.format("%Y-%m-%d").to_string())
+        .await?;
+    match stored {
+        Some(json) => Ok(Some(serde_json::from_str(&json)?)),
+        None => Ok(None),
+    }
+}
+
+/// All records for a symbol, newest first.
+pub async fn load_all(
+    cache: &RedisCache,
+    symbol: &str,
+) -> Result<Vec<EarningsMoveRecord>, EarningsMoveStoreError> {
+    let mut conn = cache.connection();
+    let stored: Vec<String> = conn.hvals(moves_key(symbol)).await?;
+    let mut out = Vec::new();
+    for json in stored {
+        out.push(serde_json::from_str::<EarningsMoveRecord>(&json)?);
+    }
+    out.sort_by_key(|r| std::cmp::Reverse(r.date));
+    Ok(out)
+}
@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use redis::{AsyncCommands, RedisError};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::models::earnings::surprise_pct;
+use crate::models::{EarningsEvent, EarningsResult, EarningsSeason, EarningsSession};
+use crate::service::caching::{CacheError, RedisCache};
+
+fn scheduled_key(season: EarningsSeason) -> String {
+    format!("earnings:season:{}:scheduled", season.key())
+}
+
+fn reported_key(season: EarningsSeason) -> String {
+    format!("earnings:season:{}:reported", season.key())
+}
+
+fn sectors_key(season: EarningsSeason) -> String {
+    format!("earnings:season:{}:sectors", season.key())
+}
+
+/// Actuals and the early price reaction for one company's report, captured by the
+/// post-earnings job.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeasonReport {
+    pub symbol: String,
+    pub date: NaiveDate,
+    pub session: EarningsSession,
+    pub eps_actual: Option<f64>,
+    pub eps_estimate: Option<f64>,
+    pub revenue_actual: Option<f64>,
+    pub revenue_estimate: Option<f64>,
+    /// Extended-hours (or regular-session for DMH) move when the report was captured.
+    pub reaction_pct: Option<f64>,
+    pub recorded_at: DateTime<Utc>,
+}
+
+impl SeasonReport {
+    pub fn new(
+        result: &EarningsResult,
+        date: NaiveDate,
+        session: EarningsSession,
+        reaction_pct: Option<f64>,
+    ) -> Self {
+        Self {
+            symbol: result.symbol.to_ascii_uppercase(),
+            date,
+            session,
+            eps_actual: result.eps_actual,
+            eps_estimate: result.eps_estimate,
+            revenue_actual: result.revenue_actual,
+            revenue_estimate: result.revenue_estimate,
+            reaction_pct,
+            recorded_at: Utc::now(),
+        }
+    }
+
+    pub fn eps_surprise_pct(&self) -> Option<f64> {
+        surprise_pct(self.eps_actual?, self.eps_estimate?)
+    }
+
+    pub fn revenue_surprise_pct(&self) -> Option<f64> {
+        surprise_pct(self.revenue_actual?, self.revenue_estimate?)
+    }
+}
+
+/// Everything accumulated for one season.
+#[derive(Debug, Clone, Default)]
+pub struct SeasonData {
+    /// Calendar events seen by the scheduled jobs, one per symbol (latest date wins).
+    pub scheduled: Vec<EarningsEvent>,
+    pub reported: Vec<SeasonReport>,
+    /// Upper-cased symbol → Yahoo sector, saved alongside the calendar so scoping the
+    /// dashboard needs no profile lookups.
+    pub sectors: HashMap<String, String>,
+}
+
+#[derive(Debug, Error)]
+pub enum EarningsSeasonError {
+    #[error(transparent)]
+    Cache(#[from] CacheError),
+    #[error(transparent)]
+    Serde(#[from] serde_json::Error),
+    #[error(transparent)]
+    Redis(#[from] RedisError),
+}
+
+/// Add calendar events to the season each one is published in, with each symbol's sector
+/// where `sectors` (keyed by upper-cased symbol) has one.
+pub async fn record_scheduled(
+    cache: &RedisCache,
+    events: &[EarningsEvent],
+    sectors: &HashMap<String, String>,
+) -> Result<(), EarningsSeasonError> {
+    let mut by_season: HashMap<EarningsSeason, Vec<(String, String)>> = HashMap::new();
+    let mut sectors_by_season: HashMap<EarningsSeason, HashMap<String, String>> = HashMap::new();
+    for event in events {
+        let season = EarningsSeason::for_report_date(event.date.date_naive());
+        let symbol = event.symbol.to_ascii_uppercase();
+        if let Some(sector) = sectors.get(&symbol) {
+            sectors_by_season
+                .entry(season)
+                .or_default()
+                .insert(symbol.clone(), sector.clone());
+        }
+        // Logos are large and not needed for season statistics
+        let stored = EarningsEvent {
+            logo: None,
+            ..event.clone()
+        };
+        by_season
+            .entry(season)
+            .or_default()
+            .push((symbol, serde_json::to_string(&stored)?));
+    }
+
+    let mut conn = cache.connection();
+    for (season, fields) in by_season {
+        let _: () = conn.hset_multiple(scheduled_key(season), &fields).await?;
+    }
+    for (season, sectors) in sectors_by_season {
+        record_sectors(cache, season, &sectors).await?;
+    }
+    Ok(())
+}
+
+/// Save sectors (upper-cased symbol → sector) for a season's companies.
+pub async fn record_sectors(
+    cache: &RedisCache,
+    season: EarningsSeason,
+    sectors: &HashMap<String, String>,
+) -> Result<(), EarningsSeasonError> {
+    if sectors.is_empty() {
+        return Ok(());
+    }
+    let fields: Vec<(&String, &String)> = sectors.iter().collect();
+    let mut conn = cache.connection();
+    let _: () = conn.hset_multiple(sectors_key(season), &fields).await?;
+    Ok(())
+}
+
+pub async fn record_report(
+    cache: &RedisCache,
+    report: &SeasonReport,
+) -> Result<(), EarningsSeasonError> {
+    let season = EarningsSeason::for_report_date(report.date);
+    let mut conn = cache.connection();
+    let _: () = conn
+        .hset(
+            reported_key(season),
+            report.symbol.clone(),
+            serde_json::to_string(report)?,
+        )
+        .await?;
+    Ok(())
+}
+
+pub async fn load(
+    cache: &RedisCache,
+    season: EarningsSeason,
+) -> Result<SeasonData, EarningsSeasonError> {
+    let mut conn = cache.connection();
+    let scheduled: Vec<String> = conn.hvals(scheduled_key(season)).await?;
+    let reported: Vec<String> = conn.hvals(reported_key(season)).await?;
+    let sectors: HashMap<String, String> = conn.hgetall(sectors_key(season)).await?;
+
+    Ok(SeasonData {
+        scheduled: scheduled
+            .iter()
+            .map(|json| serde_json::from_str(json))
+            .collect::<Result<_, _>>()?,
+        reported: reported
+            .iter()
+            .map(|json| serde_json::from_str(json))
+            .collect::<Result<_, _>>()?,
+        sectors,
+    })
+}
@@ -0,0 +1,218 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use redis::{AsyncCommands, RedisError};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::service::caching::{CacheError, RedisCache};
+
+const SYMBOL_SET_KEY: &str = "earnings:subs:symbols";
+
+/// How long per-report notification markers are kept.
+const NOTIFIED_TTL_SECS: i64 = 14 * 24 * 60 * 60;
+
+fn subs_key(symbol: &str) -> String {
+    format!("earnings:subs:{}", symbol.to_ascii_uppercase())
+}
+
+fn notified_key(symbol: &str, date: NaiveDate) -> String {
+    format!(
+        "earnings:subs:notified:{}:{}",
+        symbol.to_ascii_uppercase(),
+        date.format("%Y-%m-%d")
+    )
+}
+
+fn results_quarter_key(symbol: &str) -> String {
+    format!(
+        "earnings:subs:results_quarter:{}",
+        symbol.to_ascii_uppercase()
+    )
+}
+
+/// Where a subscriber wants to be reminded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Delivery {
+    Dm,
+    /// Ping the user in the channel the subscription was made from.
+    Channel {
+        channel_id: u64,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EarningsSubscription {
+    pub user_id: u64,
+    pub symbol: String,
+    pub delivery: Delivery,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Reminder stages sent for each report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReminderStage {
+    /// Evening of the trading day before the report.
+    Eve,
+    /// Around the scheduled report time.
+    Report,
+    /// Actuals are published.
+    Results,
+}
+
+impl ReminderStage {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReminderStage::Eve => "eve",
+            ReminderStage::Report => "report",
+            ReminderStage::Results => "results",
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum SubscriptionStoreError {
+    #[error(transparent)]
+    Cache(#[from] CacheError),
+    #[error(transparent)]
+    Serde(#[from] serde_json::Error),
+    #[error(transparent)]
+    Redis(#[from] RedisError),
+}
+
+/// Add or replace a user's subscription to a symbol. Returns `true` when it is new.
+pub async fn subscribe(
+    cache: &RedisCache,
+    sub: &EarningsSubscription,
+) -> Result<bool, SubscriptionStoreError> {
+    let mut conn = cache.connection();
+    let symbol = sub.symbol.to_ascii_uppercase();
+    let payload = serde_json::to_string(sub)?;
+    let (added, _): (i64, i64) = redis::pipe()
+        .hset(subs_key(&symbol), sub.user_id.to_string(), payload)
+        .sadd(SYMBOL_SET_KEY, &symbol)
+        .query_async(&mut conn)
+        .await?;
+    Ok(added == 1)
+}
+
+/// Remove a user's subscription. Returns `true` when one existed.
+pub async fn unsubscribe(
+    cache: &RedisCache,
+    user_id: u64,
+    symbol: &str,
+) -> Result<bool, SubscriptionStoreError> {
+    let mut conn = cache.connection();
+    let symbol = symbol.to_ascii_uppercase();
+    let removed: i64 = conn.hdel(subs_key(&symbol), user_id.to_string()).await?;
+
+    let remaining: i64 = conn.hlen(subs_key(&symbol)).await?;
+    if remaining == 0 {
+        let _: () = conn.srem(SYMBOL_SET_KEY, &symbol).await?;
+    }
+    Ok(removed > 0)
+}
+
+/// Symbols with at least one subscriber.
+pub async fn symbols(cache: &RedisCache) -> Result<Vec<String>, SubscriptionStoreError> {
+    let mut conn = cache.connection();
+    Ok(conn.smembers(SYMBOL_SET_KEY).await?)
+}
+
+/// All subscriptions grouped by symbol.
+pub async fn load_all(
+    cache: &RedisCache,
+) -> Result<HashMap<String, Vec<EarningsSubscription>>, SubscriptionStoreError> {
+    let mut conn = cache.connection();
+    let symbols: Vec<String> = conn.smembers(SYMBOL_SET_KEY).await.unwrap_or_default();
+    let mut out = HashMap::new();
+
+    for symbol in symbols {
+        let stored: Vec<String> = conn.hvals(subs_key(&symbol)).await?;
+        if stored.is_empty() {
+            let _: () = conn.srem(SYMBOL_SET_KEY, &symbol).await?;
+            continue;
+        }
+        let mut subs = Vec::with_capacity(stored.len());
+        for json in stored {
+            subs.push(serde_json::from_str::<EarningsSubscription>(&json)?);
+        }
+        out.insert(symbol, subs);
+    }
+
+    Ok(out)
+}
+
+/// Symbols a user is subscribed to, sorted.
+pub async fn list_for_user(
+    cache: &RedisCache,
+    user_id: u64,
+) -> Result<Vec<EarningsSubscription>, SubscriptionStoreError> {
+    let mut subs: Vec<EarningsSubscription> = load_all(cache)
+        .await?
+        .into_values()
+        .flatten()
+        .filter(|s| s.user_id == user_id)
+        .collect();
+    subs.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+    Ok(subs)
+}
+
+/// Mark a stage as sent for one report. Returns `true` only for the first caller, so each
+/// reminder goes out once even across restarts.
+pub async fn mark_notified(
+    cache: &RedisCache,
+    symbol: &str,
+    date: NaiveDate,
+    stage: ReminderStage,
+) -> Result<bool, SubscriptionStoreError> {
+    let mut conn = cache.connection();
+    let key = notified_key(symbol, date);
+    let (added, _): (i64, bool) = redis::pipe()
+        .sadd(&key, stage.as_str())
+        .expire(&key, NOTIFIED_TTL_SECS)
+        .query_async(&mut conn)
+        .await?;
+    Ok(added == 1)
+}
+
+/// Whether a stage was already sent for one report.
+pub async fn was_notified(
+    cache: &RedisCache,
+    symbol: &str,
+    date: NaiveDate,
+    stage: ReminderStage,
+) -> Result<bool, SubscriptionStoreError> {
+    let mut conn = cache.connection();
+    let sent: bool = conn
+        .sismember(notified_key(symbol, date), stage.as_str())
+        .await?;
+    Ok(sent)
+}
+
+/// Fiscal quarter end of the last results reminder sent for `symbol`.
+pub async fn last_results_quarter(
+    cache: &RedisCache,
+    symbol: &str,
+) -> Result<Option<NaiveDate>, SubscriptionStoreError> {
+    let mut conn = cache.connection();
+    let stored: Option<String> = conn.get(results_quarter_key(symbol)).await?;
+    Ok(stored.and_then(|s| NaiveDate::parse_from_str(&s, "%Y-%m-%d").ok()))
+}
+
+/// Remember the fiscal quarter a results reminder went out for, so a later report cannot
+/// announce the same quarter again.
+pub async fn record_results_quarter(
+    cache: &RedisCache,
+    symbol: &str,
+    quarter_end: NaiveDate,
+) -> Result<(), SubscriptionStoreError> {
+    let mut conn = cache.connection();
+    let _: () = conn
+        .set(
+            results_quarter_key(symbol),
+            quarter_end.format("%Y-%m-%d").to_string(),
+        )
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use redis::{AsyncCommands, RedisError};
+use tracing::warn;
+
+use crate::service::caching::RedisCache;
+
+fn calendar_theme_key(guild_id: u64) -> String {
+    format!("guild:{}:calendar_theme", guild_id)
+}
+
+/// Color scheme for the rendered earnings calendar.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CalendarTheme {
+    #[default]
+    Light,
+    Dark,
+}
+
+impl CalendarTheme {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CalendarTheme::Light => "light",
+            CalendarTheme::Dark => "dark",
+        }
+    }
+
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "light" => Some(CalendarTheme::Light),
+            "dark" => Some(CalendarTheme::Dark),
+            _ => None,
+        }
+    }
+}
+
+/// Calendar theme chosen for a guild; light when unset, outside a guild, or without Redis.
+pub async fn load_calendar_theme(
+    cache: Option<&RedisCache>,
+    guild_id: Option<u64>,
+) -> CalendarTheme {
+    let (Some(cache), Some(guild_id)) = (cache, guild_id) else {
+        return CalendarTheme::default();
+    };
+
+    let mut conn = cache.connection();
+    match conn
+        .get::<_, Option<String>>(calendar_theme_key(guild_id))
+        .await
+    {
+        Ok(stored) => stored
+            .as_deref()
+            .and_then(CalendarTheme::parse)
+            .unwrap_or_default(),
+        Err(e) => {
+            warn!(
+                "failed to load calendar theme for guild {}: {}",
+                guild_id, e
+            );
+            CalendarTheme::default()
+        }
+    }
+}
+
+pub async fn save_calendar_theme(
+    cache: &RedisCache,
+    guild_id: u64,
+    theme: CalendarTheme,
+) -> Result<(), RedisError> {
+    let mut conn = cache.connection();
+    let _: () = conn
+        .set(calendar_theme_key(guild_id), theme.as_str())
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use redis::{AsyncCommands, RedisError};
+use thiserror::Error;
+
+use crate::models::InsiderTransaction;
+use crate::service::caching::{CacheError, RedisCache};
+
+/// Seen markers outlive Yahoo's insider history (roughly the last six months).
+const SEEN_TTL_SECS: i64 = 400 * 24 * 60 * 60;
+
+fn seen_key(symbol: &str) -> String {
+    format!("insider:seen:{}", symbol.to_ascii_uppercase())
+}
+
+#[derive(Debug, Error)]
+pub enum InsiderFeedStoreError {
+    #[error(transparent)]
+    Cache(#[from] CacheError),
+    #[error(transparent)]
+    Redis(#[from] RedisError),
+}
+
+/// Stable identity of a filing row: `INSIDER|YYYY-MM-DD|shares|value`, with `-` for
+/// missing numbers. Yahoo has no transaction id and reorders rows between fetches.
+pub fn transaction_key(tx: &InsiderTransaction) -> String {
+    let number = |v: Option<i64>| v.map_or_else(|| "-".to_string(), |v| v.to_string());
+    format!(
+        "{}|{}|{}|{}",
+        tx.insider.trim().to_ascii_uppercase(),
+        tx.start_date.date_naive().format("%Y-%m-%d"),
+        number(tx.shares),
+        number(tx.value)
+    )
+}
+
+/// Whether any transaction was ever recorded for `symbol`; the first poll only seeds.
+pub async fn has_history(cache: &RedisCache, symbol: &str) -> Result<bool, InsiderFeedStoreError> {
+    let mut conn = cache.connection();
+    let exists: bool = conn.exists(seen_key(symbol)).await?;
+    Ok(exists)
+}
+
+/// The `keys` not yet recorded for `symbol`, in input order.
+pub async fn unseen(
+    cache: &RedisCache,
+    symbol: &str,
+    keys: &[String],
+) -> Result<Vec<String>, InsiderFeedStoreError> {
+    if keys.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut conn = cache.connection();
+    let key = seen_key(symbol);
+
+    let mut pipe = redis::pipe();
+    for k in keys {
+        pipe.sismember(&key, k);
+    }
+    let seen: Vec<bool> = pipe.query_async(&mut conn).await?;
+
+    Ok(keys
+        .iter()
+        .zip(seen)
+        .filter(|(_, seen)| !seen)
+        .map(|(k, _)| k.clone())
+        .collect())
+}
+
+/// Record `keys` as seen and return the ones that were new, in input order.
+pub async fn mark_seen(
+    cache: &RedisCache,
+    symbol: &str,
+    keys: &[String],
+) -> Result<Vec<String>, InsiderFeedStoreError> {
+    if keys.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut conn = cache.connection();
+    let key = seen_key(symbol);
+
+    let mut pipe = redis::pipe();
+    for k in keys {
+        pipe.sadd(&key, k);
+    }
+    pipe.expire(&key, SEEN_TTL_SECS).ignore();
+    let added: Vec<i64> = pipe.query_async(&mut conn).await?;
+
+    Ok(keys
+        .iter()
+        .zip(added)
+        .filter(|(_, added)| *added == 1)
+        .map(|(k, _)| k.clone())
+        .collect())
+}
@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+
+use chrono::{Duration, NaiveDate};
+use redis::AsyncCommands;
+
+use crate::service::caching::{CacheError, RedisCache};
+use crate::service::finance::volatility::{iv_stats, IvStats};
+
+const RETENTION_DAYS: i64 = 400;
+const LOOKBACK_DAYS: i64 = 365;
+
+fn history_key(symbol: &str) -> String {
+    format!("iv:history:{}", symbol.to_ascii_uppercase())
+}
+
+/// Store one daily ATM IV reading (decimal, e.g. 0.32) and drop readings past retention.
+pub async fn record(
+    cache: &RedisCache,
+    symbol: &str,
+    date: NaiveDate,
+    iv: f64,
+) -> Result<(), CacheError> {
+    let mut conn = cache.connection();
+    let key = history_key(symbol);
+    let _: () = conn
+        .hset(&key, date.format("%Y-%m-%d").to_string(), iv)
+        .await?;
+
+    let cutoff = date - Duration::days(RETENTION_DAYS);
+    let fields: Vec<String> = conn.hkeys(&key).await.unwrap_or_default();
+    let stale: Vec<String> = fields
+        .into_iter()
+        .filter(|f| {
+            NaiveDate::parse_from_str(f, "%Y-%m-%d")
+                .map(|d| d < cutoff)
+                .unwrap_or(true)
+        })
+        .collect();
+    if !stale.is_empty() {
+        let _: () = conn.hdel(&key, stale).await?;
+    }
+
+    Ok(())
+}
+
+/// Load readings on or after `since`, oldest first.
+pub async fn load(
+    cache: &RedisCache,
+    symbol: &str,
+    since: NaiveDate,
+) -> Result<Vec<(NaiveDate, f64)>, CacheError> {
+    let mut conn = cache.connection();
+    let raw: HashMap<String, f64> = conn.hgetall(history_key(symbol)).await?;
+    let mut out: Vec<(NaiveDate, f64)> = raw
+        .into_iter()
+        .filter_map(|(d, iv)| {
+            NaiveDate::parse_from_str(&d, "%Y-%m-%d")
+                .ok()
+                .map(|d| (d, iv))
+        })
+        .filter(|(d, _)| *d >= since)
+        .collect();
+    out.sort_by_key(|(d, _)| *d);
+    Ok(out)
+}
+
+/// 52-week IV rank/percentile. Uses `current` when given, otherwise the latest stored reading.
+pub async fn stats(
+    cache: &RedisCache,
+    symbol: &str,
+    today: NaiveDate,
+    current: Option<f64>,
+) -> Result<Option<IvStats>, CacheError> {
+    let history = load(cache, symbol, today - Duration::days(LOOKBACK_DAYS)).await?;
+    let current = match current.or_else(|| history.last().map(|(_, iv)| *iv)) {
+        Some(iv) => iv,
+        None => return Ok(None),
+    };
+    let values: Vec<f64> = history.iter().map(|(_, iv)| *iv).collect();
+    Ok(iv_stats(current, &values))
+}
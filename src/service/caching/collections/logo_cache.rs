@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use redis::{AsyncCommands, RedisError};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use crate::service::caching::{CacheError, RedisCache};
+
+/// Logos are re-fetched after this long in case the company rebrands.
+const LOGO_TTL_SECS: u64 = 30 * 24 * 60 * 60;
+
+/// Known-missing logos are retried after this long.
+const MISSING_TTL_SECS: u64 = 7 * 24 * 60 * 60;
+
+fn entry_key(symbol: &str) -> String {
+    format!("logos:entry:{}", symbol.to_ascii_uppercase())
+}
+
+fn blob_key(hash: &str) -> String {
+    format!("logos:blob:{}", hash)
+}
+
+/// What the cache knows about one symbol's logo.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogoEntry {
+    /// Source URL; a different URL from the calendar means the entry is stale.
+    pub url: String,
+    /// SHA-256 of the downloaded image, keying the rendered PNG in `logos:blob:{hash}`.
+    /// `None` records that the URL has no usable image, so the placeholder is drawn.
+    pub hash: Option<String>,
+    pub fetched_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Error)]
+pub enum LogoCacheError {
+    #[error(transparent)]
+    Cache(#[from] CacheError),
+    #[error(transparent)]
+    Serde(#[from] serde_json::Error),
+    #[error(transparent)]
+    Redis(#[from] RedisError),
+}
+
+/// Hex SHA-256 of downloaded logo bytes; identical images (e.g. share classes) share a blob.
+pub fn content_hash(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Cached entries for the given symbols (absent or expired symbols are left out).
+pub async fn load_entries(
+    cache: &RedisCache,
+    symbols: &[String],
+) -> Result<HashMap<String, LogoEntry>, LogoCacheError> {
+    if symbols.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let mut conn = cache.connection();
+    let keys: Vec<String> = symbols.iter().map(|s| entry_key(s)).collect();
+    let stored: Vec<Option<String>> = redis::cmd("MGET").arg(&keys).query_async(&mut conn).await?;
+
+    let mut out = HashMap::new();
+    for (symbol, json) in symbols.iter().zip(stored) {
+        if let Some(json) = json {
+            out.insert(symbol.clone(), serde_json::from_str(&json)?);
+        }
+    }
+    Ok(out)
+}
+
+/// Rendered PNGs for the given content hashes.
+pub async fn load_blobs(
+    cache: &RedisCache,
+    hashes: &[String],
+) -> Result<HashMap<String, Vec<u8>>, LogoCacheError> {
+    if hashes.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let mut conn = cache.connection();
+    let keys: Vec<String> = hashes.iter().map(|h| blob_key(h)).collect();
+    let stored: Vec<Option<Vec<u8>>> = redis::cmd("MGET").arg(&keys).query_async(&mut conn).await?;
+
+    Ok(hashes
+        .iter()
+        .zip(stored)
+        .filter_map(|(hash, png)| png.map(|png| (hash.clone(), png)))
+        .collect())
+}
+
+/// Store a rendered logo under its content hash and point the symbol at it.
+pub async fn store_logo(
+    cache: &RedisCache,
+    symbol: &str,
+    url: &str,
+    hash: &str,
+    png: &[u8],
+) -> Result<(), LogoCacheError> {
+    let entry = LogoEntry {
+        url: url.to_string(),
+        hash: Some(hash.to_string()),
+        fetched_at: Utc::now(),
+    };
+    let mut conn = cache.connection();
+    let _: () = redis::pipe()
+        .set_ex(blob_key(hash), png, LOGO_TTL_SECS)
+        .set_ex(
+            entry_key(symbol),
+            serde_json::to_string(&entry)?,
+            LOGO_TTL_SECS,
+        )
+        .query_async(&mut conn)
+        .await?;
+    Ok(())
+}
+
+/// Record that `url` has no usable logo so renders use the placeholder without retrying.
+pub async fn store_missing(
+    cache: &RedisCache,
+    symbol: &str,
+    url: &str,
+) -> Result<(), LogoCacheError> {
+    let entry = LogoEntry {
+        url: url.to_string(),
+        hash: None,
+        fetched_at: Utc::now(),
+    };
+    let mut conn = cache.connection();
+    let _: () = conn
+        .set_ex(
+            entry_key(symbol),
+            serde_json::to_string(&entry)?,
+            MISSING_TTL_SECS,
+        )
+        .await?;
+    Ok(())
+}
@@ -1,2 +1,10 @@
+pub mod earnings_moves;
+pub mod earnings_season;
+pub mod earnings_subscriptions;
+pub mod guild_settings;
+pub mod insider_feed;
+pub mod iv_history;
+pub mod logo_cache;
 pub mod price_alerts;
 pub mod spy_data;
+pub mod watchlist;
@@ -1,8 +1,9 @@
 use std::collections::HashMap;
 
 use chrono::{DateTime, Utc};
+use finance_query_core::OptionContract;
 use redis::AsyncCommands;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::service::caching::{CacheError, RedisCache};
 use crate::service::finance::options::OptionSlice;
@@ -10,10 +11,124 @@ use crate::service::finance::options::OptionSlice;
 const HISTORY_LIMIT: isize = 200;
 const HISTORY_TTL_SECS: i64 = 60 * 60 * 24 * 7; // 7 days
 
-#[derive(Debug, Deserialize)]
+/// Stored JSON entry. Older entries only carry `{t, p}`, so every newer field is optional.
+#[derive(Debug, Serialize, Deserialize)]
 struct Point {
     t: String,
     p: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    b: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    a: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    iv: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    v: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    oi: Option<u64>,
+}
+
+/// One recorded observation of a strike.
+#[derive(Debug, Clone)]
+pub struct HistoryPoint {
+    pub time: DateTime<Utc>,
+    pub last: f64,
+    pub bid: Option<f64>,
+    pub ask: Option<f64>,
+    pub implied_volatility: Option<f64>,
+    pub volume: Option<u64>,
+    pub open_interest: Option<u64>,
+}
+
+impl HistoryPoint {
+    fn from_contract(time: DateTime<Utc>, contract: &OptionContract) -> Self {
+        Self {
+            time,
+            last: contract.last_price,
+            bid: Some(contract.bid),
+            ask: Some(contract.ask),
+            implied_volatility: Some(contract.implied_volatility),
+            volume: contract.volume,
+            open_interest: contract.open_interest,
+        }
+    }
+}
+
+/// Which value of a `HistoryPoint` to plot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistorySeries {
+    Last,
+    Bid,
+    Ask,
+    Mid,
+    ImpliedVolatility,
+    Volume,
+    OpenInterest,
+}
+
+impl HistorySeries {
+    pub const ALL: [HistorySeries; 7] = [
+        HistorySeries::Last,
+        HistorySeries::Bid,
+        HistorySeries::Ask,
+        HistorySeries::Mid,
+        HistorySeries::ImpliedVolatility,
+        HistorySeries::Volume,
+        HistorySeries::OpenInterest,
+    ];
+
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "last" | "price" | "ltp" => Some(HistorySeries::Last),
+            "bid" => Some(HistorySeries::Bid),
+            "ask" => Some(HistorySeries::Ask),
+            "mid" => Some(HistorySeries::Mid),
+            "iv" | "implied_volatility" => Some(HistorySeries::ImpliedVolatility),
+            "volume" | "vol" => Some(HistorySeries::Volume),
+            "oi" | "open_interest" => Some(HistorySeries::OpenInterest),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HistorySeries::Last => "last",
+            HistorySeries::Bid => "bid",
+            HistorySeries::Ask => "ask",
+            HistorySeries::Mid => "mid",
+            HistorySeries::ImpliedVolatility => "iv",
+            HistorySeries::Volume => "volume",
+            HistorySeries::OpenInterest => "oi",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            HistorySeries::Last => "Last price",
+            HistorySeries::Bid => "Bid",
+            HistorySeries::Ask => "Ask",
+            HistorySeries::Mid => "Mid price",
+            HistorySeries::ImpliedVolatility => "IV (%)",
+            HistorySeries::Volume => "Volume",
+            HistorySeries::OpenInterest => "Open interest",
+        }
+    }
+
+    /// Extract the plotted value; `None` when the point predates that field.
+    pub fn value(&self, point: &HistoryPoint) -> Option<f64> {
+        match self {
+            HistorySeries::Last => Some(point.last),
+            HistorySeries::Bid => point.bid,
+            HistorySeries::Ask => point.ask,
+            HistorySeries::Mid => match (point.bid, point.ask) {
+                (Some(b), Some(a)) if b > 0.0 && a > 0.0 => Some((b + a) / 2.0),
+                _ => None,
+            },
+            HistorySeries::ImpliedVolatility => point.implied_volatility.map(|iv| iv * 100.0),
+            HistorySeries::Volume => point.volume.map(|v| v as f64),
+            HistorySeries::OpenInterest => point.open_interest.map(|v| v as f64),
+        }
+    }
 }
 
 fn strikes_key(expiration: &str) -> String {
@@ -24,7 +139,7 @@ fn strike_key(expiration: &str, strike: &str) -> String {
     format!("spy:history:{expiration}:{strike}")
 }
 
-/// Append the latest slice prices to Redis and keep the history bounded.
+/// Append the latest slice quotes to Redis and keep the history bounded.
 pub async fn append_slice(cache: &RedisCache, slice: &OptionSlice) -> Result<(), CacheError> {
     let mut conn = cache.connection();
     let now = Utc::now().to_rfc3339();
@@ -33,7 +148,16 @@ pub async fn append_slice(cache: &RedisCache, slice: &OptionSlice) -> Result<(),
     for contract in slice.calls.iter().chain(slice.puts.iter()) {
         let strike = format!("{:.2}", contract.strike);
         let key = strike_key(&slice.expiration, &strike);
-        let entry = serde_json::json!({ "t": now, "p": contract.last_price }).to_string();
+        let point = Point {
+            t: now.clone(),
+            p: contract.last_price,
+            b: Some(contract.bid),
+            a: Some(contract.ask),
+            iv: Some(contract.implied_volatility),
+            v: contract.volume,
+            oi: contract.open_interest,
+        };
+        let entry = serde_json::to_string(&point).unwrap_or_default();
 
         redis::pipe()
             .lpush(&key, entry)
@@ -53,7 +177,7 @@ pub async fn load_history(
     cache: &RedisCache,
     expiration: &str,
     max_points: usize,
-) -> Result<HashMap<String, Vec<(DateTime<Utc>, f64)>>, CacheError> {
+) -> Result<HashMap<String, Vec<HistoryPoint>>, CacheError> {
     let mut conn = cache.connection();
     let strikes: Vec<String> = conn.smembers(strikes_key(expiration)).await.unwrap_or_default();
     let mut out = HashMap::new();
@@ -66,14 +190,11 @@ pub async fn load_history(
     for strike in strikes {
         let key = strike_key(expiration, &strike);
         let entries: Vec<String> = conn.lrange(&key, 0, end).await.unwrap_or_default();
-        let mut points = Vec::new();
-        for entry in entries.into_iter().rev() {
-            if let Ok(point) = serde_json::from_str::<Point>(&entry) {
-                if let Ok(dt) = DateTime::parse_from_rfc3339(&point.t) {
-                    points.push((dt.with_timezone(&Utc), point.p));
-                }
-            }
-        }
+        let points: Vec<HistoryPoint> = entries
+            .iter()
+            .rev()
+            .filter_map(|entry| parse_entry(entry))
+            .collect();
         if !points.is_empty() {
             out.insert(strike, points);
         }
@@ -82,16 +203,30 @@ pub async fn load_history(
     Ok(out)
 }
 
+/// Decode one stored history entry; `None` when it is not valid JSON or has a bad timestamp.
+pub fn parse_entry(entry: &str) -> Option<HistoryPoint> {
+    let point = serde_json::from_str::<Point>(entry).ok()?;
+    let time = DateTime::parse_from_rfc3339(&point.t).ok()?;
+    Some(HistoryPoint {
+        time: time.with_timezone(&Utc),
+        last: point.p,
+        bid: point.b,
+        ask: point.a,
+        implied_volatility: point.iv,
+        volume: point.v,
+        open_interest: point.oi,
+    })
+}
+
 /// Fallback helper to build a minimal history map from the current slice.
-pub fn history_from_slice(slice: &OptionSlice) -> HashMap<String, Vec<(DateTime<Utc>, f64)>> {
+pub fn history_from_slice(slice: &OptionSlice) -> HashMap<String, Vec<HistoryPoint>> {
     let mut out = HashMap::new();
     let now = Utc::now();
     for contract in slice.calls.iter().chain(slice.puts.iter()) {
         let strike = format!("{:.2}", contract.strike);
-        out.insert(strike, vec![(now, contract.last_price)]);
+        out.insert(strike, vec![HistoryPoint::from_contract(now, contract)]);
     }
     out
 }
 
 pub const DEFAULT_HISTORY_POINTS: usize = HISTORY_LIMIT as usize;
-
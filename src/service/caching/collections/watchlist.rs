@@ -0,0 +1,62 @@
+use std::env;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use redis::AsyncCommands;
+use tracing::warn;
+
+use super::earnings_subscriptions;
+use crate::service::caching::RedisCache;
+use crate::service::finance::earnings::WatchlistProvider;
+
+const WATCHLIST_KEY: &str = "watchlist:symbols";
+
+/// Symbols tracked by the scheduled jobs: the Redis `watchlist:symbols` set merged
+/// with the comma-separated `WATCHLIST_SYMBOLS` env var.
+pub async fn load_symbols(cache: Option<&RedisCache>) -> Vec<String> {
+    let mut symbols: Vec<String> = env::var("WATCHLIST_SYMBOLS")
+        .unwrap_or_default()
+        .split(',')
+        .map(|s| s.trim().to_ascii_uppercase())
+        .filter(|s| !s.is_empty())
+        .collect();
+
+    if let Some(cache) = cache {
+        let mut conn = cache.connection();
+        let stored: Vec<String> = conn.smembers(WATCHLIST_KEY).await.unwrap_or_default();
+        symbols.extend(stored.into_iter().map(|s| s.trim().to_ascii_uppercase()));
+    }
+
+    symbols.sort();
+    symbols.dedup();
+    symbols
+}
+
+/// Symbols for the Yahoo earnings calendar fallback: the watchlist plus every symbol with
+/// an earnings subscription. Global, not per guild.
+pub struct EarningsWatchlist {
+    cache: Option<Arc<RedisCache>>,
+}
+
+impl EarningsWatchlist {
+    pub fn new(cache: Option<Arc<RedisCache>>) -> Self {
+        Self { cache }
+    }
+}
+
+#[async_trait]
+impl WatchlistProvider for EarningsWatchlist {
+    async fn load_symbols(&self) -> Vec<String> {
+        let cache = self.cache.as_deref();
+        let mut symbols = load_symbols(cache).await;
+        if let Some(cache) = cache {
+            match earnings_subscriptions::symbols(cache).await {
+                Ok(subscribed) => symbols.extend(subscribed),
+                Err(e) => warn!("failed to load subscribed symbols: {}", e),
+            }
+            symbols.sort();
+            symbols.dedup();
+        }
+        symbols
+    }
+}
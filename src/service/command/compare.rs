@@ -0,0 +1,429 @@
+use std::io::Cursor;
+
+use ab_glyph::{FontArc, PxScale};
+use image::{ImageFormat, Rgba, RgbaImage};
+use imageproc::drawing::{draw_filled_rect_mut, draw_text_mut, text_size};
+use imageproc::rect::Rect;
+use serenity::all::{
+    CommandDataOptionValue, CommandInteraction, CommandOptionType, CreateCommand,
+    CreateCommandOption,
+};
+use tracing::warn;
+
+use crate::service::automation::earnings::weekly_report::load_font;
+use crate::service::finance::compare::CompanySnapshot;
+use crate::service::finance::statements::abbreviate;
+use crate::service::finance::FinanceService;
+
+const MIN_TICKERS: usize = 2;
+const MAX_TICKERS: usize = 5;
+const DASH: &str = "—";
+
+const MARGIN: u32 = 24;
+const ROW_H: u32 = 30;
+const LABEL_COL_W: u32 = 240;
+const TICKER_COL_W: u32 = 150;
+const HEADER_H: u32 = 120;
+
+const BG: Rgba<u8> = Rgba([255, 255, 255, 255]);
+const TEXT: Rgba<u8> = Rgba([40, 40, 40, 255]);
+const MUTED: Rgba<u8> = Rgba([110, 110, 110, 255]);
+const SECTION_BG: Rgba<u8> = Rgba([238, 242, 247, 255]);
+const BEST_BG: Rgba<u8> = Rgba([214, 240, 219, 255]);
+
+/// Which value in a row is highlighted as the best.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Better {
+    Higher,
+    /// Lowest non-negative value; negative multiples are not meaningful.
+    Lower,
+    /// Not ranked (e.g. market cap).
+    Neither,
+}
+
+/// One metric across the compared companies.
+#[derive(Debug, Clone)]
+pub struct ComparisonRow {
+    pub label: &'static str,
+    pub values: Vec<Option<f64>>,
+    pub cells: Vec<String>,
+    /// Column of the best value, when at least two companies report one.
+    pub best: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ComparisonSection {
+    pub title: &'static str,
+    pub rows: Vec<ComparisonRow>,
+}
+
+/// Response payload for the /compare command.
+pub struct CompareResponse {
+    pub content: String,
+    pub image: Option<Vec<u8>>,
+}
+
+pub fn register_command() -> CreateCommand {
+    CreateCommand::new("compare")
+        .description("Compare valuation, growth, margins, balance sheet and returns of peers")
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::String,
+                "tickers",
+                "2–5 tickers, e.g., NVDA AMD AVGO",
+            )
+            .required(true),
+        )
+}
+
+pub async fn handle(
+    command: &CommandInteraction,
+    finance: &FinanceService,
+) -> Result<CompareResponse, String> {
+    let tickers = get_str_opt(command, "tickers").ok_or("tickers are required")?;
+    handle_text(finance, tickers).await
+}
+
+pub async fn handle_text(
+    finance: &FinanceService,
+    tickers: &str,
+) -> Result<CompareResponse, String> {
+    let symbols = parse_tickers(tickers)?;
+
+    let mut compared: Vec<(String, CompanySnapshot)> = Vec::new();
+    let mut failed: Vec<String> = Vec::new();
+    for (symbol, result) in finance.get_company_snapshots(&symbols).await {
+        match result {
+            Ok(snapshot) => compared.push((symbol, snapshot)),
+            Err(err) => {
+                warn!("compare: failed to fetch {}: {}", symbol, err);
+                failed.push(format!("⚠️ {}: {}", symbol, err));
+            }
+        }
+    }
+    if compared.is_empty() {
+        return Err(format!("no data for {}", symbols.join(", ")));
+    }
+
+    let snapshots: Vec<CompanySnapshot> = compared.iter().map(|(_, s)| s.clone()).collect();
+    let names: Vec<&str> = compared.iter().map(|(s, _)| s.as_str()).collect();
+    let sections = comparison_sections(&snapshots);
+    let title = names.join(" vs ");
+
+    let mut lines = vec![format!("⚖️ {}", title)];
+    lines.extend(
+        names
+            .iter()
+            .zip(&snapshots)
+            .map(|(n, s)| format_company_line(n, s)),
+    );
+    lines.extend(failed);
+
+    let image = match render_comparison_image(&title, &names, &sections) {
+        Ok(bytes) => Some(bytes),
+        Err(err) => {
+            warn!("compare render failed for {}: {}", title, err);
+            None
+        }
+    };
+
+    Ok(CompareResponse {
+        content: lines.join("\n"),
+        image,
+    })
+}
+
+/// Split `NVDA AMD, AVGO` into 2–5 distinct upper-cased symbols.
+pub fn parse_tickers(input: &str) -> Result<Vec<String>, String> {
+    let mut symbols: Vec<String> = Vec::new();
+    for raw in input.split([',', ' ']).filter(|t| !t.is_empty()) {
+        let symbol = raw.to_ascii_uppercase();
+        if !symbol
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '^'))
+        {
+            return Err(format!("invalid symbol: {raw}"));
+        }
+        if !symbols.contains(&symbol) {
+            symbols.push(symbol);
+        }
+    }
+    if !(MIN_TICKERS..=MAX_TICKERS).contains(&symbols.len()) {
+        return Err(format!(
+            "compare takes {MIN_TICKERS}–{MAX_TICKERS} distinct tickers, e.g., NVDA AMD AVGO"
+        ));
+    }
+    Ok(symbols)
+}
+
+/// Rows of the comparison, one column per snapshot.
+pub fn comparison_sections(snapshots: &[CompanySnapshot]) -> Vec<ComparisonSection> {
+    let row = |label: &'static str,
+               better: Better,
+               format: fn(f64) -> String,
+               get: fn(&CompanySnapshot) -> Option<f64>| {
+        let values: Vec<Option<f64>> = snapshots.iter().map(get).collect();
+        ComparisonRow {
+            label,
+            cells: values
+                .iter()
+                .map(|v| v.map_or(DASH.to_string(), format))
+                .collect(),
+            best: best_column(&values, better),
+            values,
+        }
+    };
+
+    vec![
+        ComparisonSection {
+            title: "Valuation",
+            rows: vec![
+                row("Market cap", Better::Neither, abbreviate, |s| {
+                    s.summary.market_cap
+                }),
+                row("P/E", Better::Lower, multiple, |s| s.summary.pe_ratio),
+                row("PEG", Better::Lower, multiple, |s| s.summary.ratios.peg),
+                row("EV / EBITDA", Better::Lower, multiple, |s| {
+                    s.summary.ratios.ev_to_ebitda
+                }),
+                row("FCF yield", Better::Higher, pct, |s| {
+                    s.summary.ratios.fcf_yield
+                }),
+            ],
+        },
+        ComparisonSection {
+            title: "Growth (fiscal year)",
+            rows: vec![
+                row("Revenue growth", Better::Higher, signed_pct, |s| {
+                    s.summary.ratios.revenue_growth
+                }),
+                row("EPS growth", Better::Higher, signed_pct, |s| {
+                    s.summary.ratios.eps_growth
+                }),
+            ],
+        },
+        ComparisonSection {
+            title: "Margins & returns (TTM)",
+            rows: vec![
+                row("Gross margin", Better::Higher, pct, |s| {
+                    s.summary.ratios.gross_margin
+                }),
+                row("Operating margin", Better::Higher, pct, |s| {
+                    s.summary.ratios.operating_margin
+                }),
+                row("Net margin", Better::Higher, pct, |s| {
+                    s.summary.ratios.net_margin
+                }),
+                row("ROE", Better::Higher, pct, |s| s.summary.ratios.roe),
+                row("ROIC", Better::Higher, pct, |s| s.summary.ratios.roic),
+            ],
+        },
+        ComparisonSection {
+            title: "Balance sheet",
+            rows: vec![
+                row("Debt / equity", Better::Lower, multiple, |s| {
+                    s.summary.ratios.debt_to_equity
+                }),
+                row("Current ratio", Better::Higher, multiple, |s| {
+                    s.summary.ratios.current_ratio
+                }),
+            ],
+        },
+        ComparisonSection {
+            title: "Price performance",
+            rows: vec![
+                row("1 month", Better::Higher, signed_pct, |s| {
+                    s.performance.one_month
+                }),
+                row("3 months", Better::Higher, signed_pct, |s| {
+                    s.performance.three_months
+                }),
+                row("Year to date", Better::Higher, signed_pct, |s| {
+                    s.performance.year_to_date
+                }),
+                row("1 year", Better::Higher, signed_pct, |s| {
+                    s.performance.one_year
+                }),
+            ],
+        },
+    ]
+}
+
+/// Column holding the best value, when at least two columns can be ranked and they differ.
+pub fn best_column(values: &[Option<f64>], better: Better) -> Option<usize> {
+    let ranked: Vec<(usize, f64)> = values
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, v)| Some((idx, (*v)?)))
+        .filter(|(_, v)| better != Better::Lower || *v >= 0.0)
+        .collect();
+    if better == Better::Neither || ranked.len() < 2 {
+        return None;
+    }
+    if ranked.iter().all(|(_, v)| *v == ranked[0].1) {
+        return None;
+    }
+    ranked
+        .into_iter()
+        .reduce(|best, next| {
+            let next_wins = match better {
+                Better::Lower => next.1 < best.1,
+                _ => next.1 > best.1,
+            };
+            if next_wins {
+                next
+            } else {
+                best
+            }
+        })
+        .map(|(idx, _)| idx)
+}
+
+fn pct(value: f64) -> String {
+    format!("{:.1}%", value)
+}
+
+fn signed_pct(value: f64) -> String {
+    format!("{:+.1}%", value)
+}
+
+fn multiple(value: f64) -> String {
+    format!("{:.2}x", value)
+}
+
+/// `**NVDA** P/E 52.10x · rev growth +114.2% · net margin 55.8% · D/E 0.13x · 1Y +40.2%`
+fn format_company_line(symbol: &str, s: &CompanySnapshot) -> String {
+    let cell = |value: Option<f64>, format: fn(f64) -> String| value.map_or(DASH.into(), format);
+    format!(
+        "**{}** P/E {} · rev growth {} · net margin {} · D/E {} · 1Y {}",
+        symbol,
+        cell(s.summary.pe_ratio, multiple),
+        cell(s.summary.ratios.revenue_growth, signed_pct),
+        cell(s.summary.ratios.net_margin, pct),
+        cell(s.summary.ratios.debt_to_equity, multiple),
+        cell(s.performance.one_year, signed_pct)
+    )
+}
+
+fn render_comparison_image(
+    title: &str,
+    names: &[&str],
+    sections: &[ComparisonSection],
+) -> Result<Vec<u8>, String> {
+    let font = load_font()?;
+    let scale = PxScale::from(17.0);
+    let width = MARGIN * 2 + LABEL_COL_W + names.len() as u32 * TICKER_COL_W;
+    let rows: u32 = sections.iter().map(|s| s.rows.len() as u32 + 1).sum();
+    let height = HEADER_H + rows * ROW_H + MARGIN;
+    let mut img = RgbaImage::from_pixel(width, height, BG);
+
+    draw_text_mut(
+        &mut img,
+        TEXT,
+        MARGIN as i32,
+        MARGIN as i32,
+        PxScale::from(28.0),
+        &font,
+        title,
+    );
+    draw_text_mut(
+        &mut img,
+        MUTED,
+        MARGIN as i32,
+        MARGIN as i32 + 38,
+        scale,
+        &font,
+        "Best value in each row highlighted",
+    );
+
+    let right_edge = |col: usize| MARGIN + LABEL_COL_W + (col as u32 + 1) * TICKER_COL_W;
+    let header_y = HEADER_H - ROW_H - 4;
+    for (col, name) in names.iter().enumerate() {
+        draw_right(
+            &mut img,
+            name,
+            right_edge(col),
+            header_y,
+            PxScale::from(20.0),
+            &font,
+            TEXT,
+        );
+    }
+
+    let mut y = HEADER_H;
+    for section in sections {
+        draw_filled_rect_mut(
+            &mut img,
+            Rect::at(MARGIN as i32, y as i32).of_size(width - MARGIN * 2, ROW_H),
+            SECTION_BG,
+        );
+        draw_text_mut(
+            &mut img,
+            TEXT,
+            MARGIN as i32 + 8,
+            y as i32 + 6,
+            scale,
+            &font,
+            section.title,
+        );
+        y += ROW_H;
+
+        for row in &section.rows {
+            draw_text_mut(
+                &mut img,
+                MUTED,
+                MARGIN as i32 + 8,
+                y as i32 + 6,
+                scale,
+                &font,
+                row.label,
+            );
+            for (col, cell) in row.cells.iter().enumerate() {
+                if row.best == Some(col) {
+                    draw_filled_rect_mut(
+                        &mut img,
+                        Rect::at((right_edge(col) - TICKER_COL_W + 16) as i32, y as i32 + 2)
+                            .of_size(TICKER_COL_W - 16, ROW_H - 4),
+                        BEST_BG,
+                    );
+                }
+                draw_right(&mut img, cell, right_edge(col), y + 6, scale, &font, TEXT);
+            }
+            y += ROW_H;
+        }
+    }
+
+    let mut buffer = Vec::new();
+    image::DynamicImage::ImageRgba8(img)
+        .write_to(&mut Cursor::new(&mut buffer), ImageFormat::Png)
+        .map_err(|e| format!("failed to encode png: {e}"))?;
+
+    Ok(buffer)
+}
+
+/// Draw `text` so it ends 8px left of `right`.
+fn draw_right(
+    img: &mut RgbaImage,
+    text: &str,
+    right: u32,
+    y: u32,
+    scale: PxScale,
+    font: &FontArc,
+    color: Rgba<u8>,
+) {
+    let (w, _) = text_size(scale, font, text);
+    let x = right.saturating_sub(w + 8);
+    draw_text_mut(img, color, x as i32, y as i32, scale, font, text);
+}
+
+fn get_str_opt<'a>(command: &'a CommandInteraction, name: &str) -> Option<&'a str> {
+    command
+        .data
+        .options
+        .iter()
+        .find(|o| o.name == name)
+        .and_then(|o| match o.value {
+            CommandDataOptionValue::String(ref s) => Some(s.as_str()),
+            _ => None,
+        })
+}
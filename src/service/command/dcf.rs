@@ -0,0 +1,445 @@
+use std::io::Cursor;
+
+use ab_glyph::{FontArc, PxScale};
+use image::{ImageFormat, Rgba, RgbaImage};
+use imageproc::drawing::{draw_filled_rect_mut, draw_hollow_rect_mut, draw_text_mut, text_size};
+use imageproc::rect::Rect;
+use serenity::all::{
+    CommandDataOptionValue, CommandInteraction, CommandOptionType, CreateCommand,
+    CreateCommandOption,
+};
+use tracing::warn;
+
+use crate::models::FinancialSummary;
+use crate::service::automation::earnings::weekly_report::load_font;
+use crate::service::finance::dcf::{
+    self, DcfAssumptions, DcfInputs, DcfValuation, SensitivityGrid, STAGE_ONE_YEARS,
+    STAGE_TWO_YEARS,
+};
+use crate::service::finance::statements::abbreviate;
+use crate::service::finance::FinanceService;
+
+/// Accepted rates (percent), shared by the slash options and the mention path.
+pub const GROWTH_RANGE: (f64, f64) = (-50.0, 100.0);
+pub const DISCOUNT_RANGE: (f64, f64) = (1.0, 30.0);
+pub const TERMINAL_RANGE: (f64, f64) = (-5.0, 10.0);
+
+/// Sensitivity grid: this many steps either side of the base case.
+const GRID_STEPS: i32 = 2;
+const DISCOUNT_STEP: f64 = 1.0;
+const GROWTH_STEP: f64 = 2.0;
+
+const MARGIN: u32 = 24;
+const HEADER_H: u32 = 104;
+const AXIS_H: u32 = 30;
+const ROW_LABEL_W: u32 = 130;
+const CELL_W: u32 = 118;
+const CELL_H: u32 = 44;
+const FOOTER_H: u32 = 40;
+const DASH: &str = "—";
+
+const BG: Rgba<u8> = Rgba([255, 255, 255, 255]);
+const HEADER_BG: Rgba<u8> = Rgba([243, 246, 250, 255]);
+const ABOVE_BG: Rgba<u8> = Rgba([220, 243, 227, 255]);
+const BELOW_BG: Rgba<u8> = Rgba([250, 225, 225, 255]);
+const TEXT: Rgba<u8> = Rgba([40, 40, 40, 255]);
+const MUTED: Rgba<u8> = Rgba([110, 110, 110, 255]);
+const BASE_BORDER: Rgba<u8> = Rgba([40, 40, 40, 255]);
+
+/// Response payload for the /dcf command.
+pub struct DcfResponse {
+    pub content: String,
+    pub image: Option<Vec<u8>>,
+}
+
+pub fn register_command() -> CreateCommand {
+    CreateCommand::new("dcf")
+        .description("Discounted cash flow value, implied growth and a sensitivity grid")
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::String,
+                "ticker",
+                "Ticker symbol, e.g., AAPL",
+            )
+            .required(true),
+        )
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::Number,
+                "growth",
+                "Free cash flow growth % for the first 5 years (default: last year's revenue growth)",
+            )
+            .min_number_value(GROWTH_RANGE.0)
+            .max_number_value(GROWTH_RANGE.1),
+        )
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::Number,
+                "discount",
+                "Discount rate % (default 9)",
+            )
+            .min_number_value(DISCOUNT_RANGE.0)
+            .max_number_value(DISCOUNT_RANGE.1),
+        )
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::Number,
+                "terminal",
+                "Terminal growth % (default 2.5)",
+            )
+            .min_number_value(TERMINAL_RANGE.0)
+            .max_number_value(TERMINAL_RANGE.1),
+        )
+}
+
+pub async fn handle(
+    command: &CommandInteraction,
+    finance: &FinanceService,
+) -> Result<DcfResponse, String> {
+    let ticker = get_str_opt(command, "ticker").ok_or("ticker is required")?;
+    let growth = get_f64_opt(command, "growth");
+    let discount = get_f64_opt(command, "discount");
+    let terminal = get_f64_opt(command, "terminal");
+    handle_text(finance, ticker, growth, discount, terminal).await
+}
+
+pub async fn handle_text(
+    finance: &FinanceService,
+    ticker: &str,
+    growth: Option<f64>,
+    discount: Option<f64>,
+    terminal: Option<f64>,
+) -> Result<DcfResponse, String> {
+    let growth = growth
+        .map(|v| check_rate("growth", v, GROWTH_RANGE))
+        .transpose()?;
+    let discount = discount
+        .map(|v| check_rate("discount", v, DISCOUNT_RANGE))
+        .transpose()?;
+    let terminal = terminal
+        .map(|v| check_rate("terminal", v, TERMINAL_RANGE))
+        .transpose()?;
+
+    let symbol = ticker.trim().to_ascii_uppercase();
+    let summary = finance
+        .get_financials(&symbol)
+        .await
+        .map_err(|e| format!("fetch error: {e}"))?;
+
+    let inputs = DcfInputs::from_summary(&summary).map_err(|e| e.to_string())?;
+    let defaults = DcfAssumptions::defaults(&summary);
+    let assumptions = DcfAssumptions {
+        growth_pct: growth.unwrap_or(defaults.growth_pct),
+        discount_pct: discount.unwrap_or(defaults.discount_pct),
+        terminal_pct: terminal.unwrap_or(defaults.terminal_pct),
+    };
+    let valuation = dcf::value(&inputs, &assumptions).map_err(|e| e.to_string())?;
+    let implied = summary
+        .price
+        .and_then(|price| dcf::implied_growth(&inputs, &assumptions, price));
+    let grid = dcf::sensitivity_grid(
+        &inputs,
+        &assumptions,
+        DISCOUNT_STEP,
+        GROWTH_STEP,
+        GRID_STEPS,
+    );
+
+    let content = format_summary(&summary, &inputs, &assumptions, &valuation, implied);
+    let image = match render_grid_image(&summary, &assumptions, &grid, implied) {
+        Ok(bytes) => Some(bytes),
+        Err(err) => {
+            warn!("dcf grid render failed for {}: {}", symbol, err);
+            None
+        }
+    };
+
+    Ok(DcfResponse { content, image })
+}
+
+/// Parse a mention rate such as `12`, `12%` or `-3.5`.
+pub fn parse_rate(raw: &str) -> Result<f64, String> {
+    raw.trim()
+        .trim_end_matches('%')
+        .parse::<f64>()
+        .ok()
+        .filter(|v| v.is_finite())
+        .ok_or_else(|| format!("invalid rate `{}`; use a percentage such as 8 or 8.5%", raw))
+}
+
+/// `value` when it lies within `range` (inclusive), else an error naming the argument.
+pub fn check_rate(name: &str, value: f64, (low, high): (f64, f64)) -> Result<f64, String> {
+    if (low..=high).contains(&value) {
+        Ok(value)
+    } else {
+        Err(format!(
+            "{name} rate must be between {low}% and {high}% (got {value}%)"
+        ))
+    }
+}
+
+fn format_summary(
+    summary: &FinancialSummary,
+    inputs: &DcfInputs,
+    assumptions: &DcfAssumptions,
+    valuation: &DcfValuation,
+    implied: Option<f64>,
+) -> String {
+    let currency = summary.currency.as_deref().unwrap_or("");
+    let mut lines = vec![format!("🧮 **{}** discounted cash flow", summary.symbol)];
+    lines.push(format!(
+        "Inputs: TTM free cash flow {} · shares {} · net debt {}",
+        abbreviate(inputs.free_cash_flow),
+        abbreviate(inputs.shares),
+        abbreviate(inputs.net_debt)
+    ));
+    lines.push(format!(
+        "Assumptions: {:.1}% growth for {}y, fading to {:.1}% terminal over {}y · {:.1}% discount",
+        assumptions.growth_pct,
+        STAGE_ONE_YEARS,
+        assumptions.terminal_pct,
+        STAGE_TWO_YEARS,
+        assumptions.discount_pct
+    ));
+
+    let mut value_line = format!(
+        "Intrinsic value: **{}** per share",
+        money(valuation.per_share, currency)
+    );
+    if let Some(price) = summary.price.filter(|p| *p > 0.0) {
+        value_line.push_str(&format!(
+            " vs price {} ({:+.1}%)",
+            money(price, currency),
+            (valuation.per_share / price - 1.0) * 100.0
+        ));
+    }
+    lines.push(value_line);
+
+    let terminal_share = if valuation.enterprise_value > 0.0 {
+        valuation.pv_terminal_value / valuation.enterprise_value * 100.0
+    } else {
+        0.0
+    };
+    lines.push(format!(
+        "Enterprise value {} · equity value {} · terminal value {:.0}% of EV",
+        abbreviate(valuation.enterprise_value),
+        abbreviate(valuation.equity_value),
+        terminal_share
+    ));
+
+    match (summary.price, implied) {
+        (Some(_), Some(growth)) => lines.push(format!(
+            "Reverse DCF: the current price implies {:.1}% growth for {}y at the same discount and terminal rates",
+            growth, STAGE_ONE_YEARS
+        )),
+        (Some(_), None) => lines.push(
+            "Reverse DCF: the current price is outside what -50% to 100% growth can explain"
+                .to_string(),
+        ),
+        (None, _) => {}
+    }
+
+    lines.join("\n")
+}
+
+fn money(value: f64, currency: &str) -> String {
+    format!("{:.2} {}", value, currency).trim_end().to_string()
+}
+
+fn render_grid_image(
+    summary: &FinancialSummary,
+    base: &DcfAssumptions,
+    grid: &SensitivityGrid,
+    implied: Option<f64>,
+) -> Result<Vec<u8>, String> {
+    let font = load_font()?;
+    let cols = grid.growth_pcts.len() as u32;
+    let rows = grid.discount_pcts.len() as u32;
+    let width = MARGIN * 2 + ROW_LABEL_W + cols * CELL_W;
+    let height = HEADER_H + AXIS_H + (rows + 1) * CELL_H + FOOTER_H + MARGIN;
+    let mut img = RgbaImage::from_pixel(width, height, BG);
+
+    let currency = summary.currency.as_deref().unwrap_or("");
+    draw_text_mut(
+        &mut img,
+        TEXT,
+        MARGIN as i32,
+        MARGIN as i32,
+        PxScale::from(30.0),
+        &font,
+        &format!("{} DCF value per share", summary.symbol),
+    );
+    let mut subtitle = vec![format!("Terminal growth {:.1}%", base.terminal_pct)];
+    if let Some(price) = summary.price {
+        subtitle.insert(0, format!("Price {}", money(price, currency)));
+    }
+    if let Some(growth) = implied {
+        subtitle.push(format!("Price implies {:.1}% growth", growth));
+    }
+    draw_text_mut(
+        &mut img,
+        MUTED,
+        MARGIN as i32,
+        MARGIN as i32 + 40,
+        PxScale::from(17.0),
+        &font,
+        &subtitle.join(" · "),
+    );
+
+    let grid_x = MARGIN + ROW_LABEL_W;
+    let label_scale = PxScale::from(15.0);
+    let value_scale = PxScale::from(19.0);
+    draw_text_mut(
+        &mut img,
+        MUTED,
+        grid_x as i32,
+        HEADER_H as i32,
+        label_scale,
+        &font,
+        &format!("Growth, first {} years →", STAGE_ONE_YEARS),
+    );
+
+    // Column headers: growth rates; row headers: discount rates
+    let header_y = HEADER_H + AXIS_H;
+    draw_filled_rect_mut(
+        &mut img,
+        Rect::at(MARGIN as i32, header_y as i32).of_size(ROW_LABEL_W + cols * CELL_W, CELL_H),
+        HEADER_BG,
+    );
+    draw_text_mut(
+        &mut img,
+        MUTED,
+        MARGIN as i32 + 10,
+        header_y as i32 + 13,
+        label_scale,
+        &font,
+        "Discount ↓",
+    );
+    for (col, growth) in grid.growth_pcts.iter().enumerate() {
+        let right = grid_x + (col as u32 + 1) * CELL_W;
+        draw_right(
+            &mut img,
+            &format!("{:.1}%", growth),
+            right,
+            header_y + 12,
+            value_scale,
+            &font,
+            TEXT,
+        );
+    }
+
+    let price = summary.price.filter(|p| *p > 0.0);
+    for (row, (discount, values)) in grid.discount_pcts.iter().zip(&grid.values).enumerate() {
+        let y = header_y + (row as u32 + 1) * CELL_H;
+        draw_filled_rect_mut(
+            &mut img,
+            Rect::at(MARGIN as i32, y as i32).of_size(ROW_LABEL_W, CELL_H),
+            HEADER_BG,
+        );
+        draw_text_mut(
+            &mut img,
+            TEXT,
+            MARGIN as i32 + 10,
+            y as i32 + 12,
+            value_scale,
+            &font,
+            &format!("{:.1}%", discount),
+        );
+
+        for (col, value) in values.iter().enumerate() {
+            let x = grid_x + col as u32 * CELL_W;
+            let fill = match (value, price) {
+                (Some(v), Some(p)) if *v >= p => ABOVE_BG,
+                (Some(_), Some(_)) => BELOW_BG,
+                _ => BG,
+            };
+            draw_filled_rect_mut(
+                &mut img,
+                Rect::at(x as i32 + 1, y as i32 + 1).of_size(CELL_W - 2, CELL_H - 2),
+                fill,
+            );
+            let text = value.map_or(DASH.to_string(), |v| format!("{:.2}", v));
+            draw_right(
+                &mut img,
+                &text,
+                x + CELL_W,
+                y + 12,
+                value_scale,
+                &font,
+                TEXT,
+            );
+
+            if row as i32 == GRID_STEPS && col as i32 == GRID_STEPS {
+                for inset in 0..2 {
+                    draw_hollow_rect_mut(
+                        &mut img,
+                        Rect::at(x as i32 + inset, y as i32 + inset)
+                            .of_size(CELL_W - 2 * inset as u32, CELL_H - 2 * inset as u32),
+                        BASE_BORDER,
+                    );
+                }
+            }
+        }
+    }
+
+    let footer_y = header_y + (rows + 1) * CELL_H + 14;
+    let legend = if price.is_some() {
+        "Green: above the current price · red: below · outlined: base case"
+    } else {
+        "Outlined: base case"
+    };
+    draw_text_mut(
+        &mut img,
+        MUTED,
+        MARGIN as i32,
+        footer_y as i32,
+        label_scale,
+        &font,
+        legend,
+    );
+
+    let mut buffer = Vec::new();
+    image::DynamicImage::ImageRgba8(img)
+        .write_to(&mut Cursor::new(&mut buffer), ImageFormat::Png)
+        .map_err(|e| format!("failed to encode png: {e}"))?;
+
+    Ok(buffer)
+}
+
+fn draw_right(
+    img: &mut RgbaImage,
+    text: &str,
+    right: u32,
+    y: u32,
+    scale: PxScale,
+    font: &FontArc,
+    color: Rgba<u8>,
+) {
+    let (w, _) = text_size(scale, font, text);
+    let x = right.saturating_sub(w + 12);
+    draw_text_mut(img, color, x as i32, y as i32, scale, font, text);
+}
+
+fn get_str_opt<'a>(command: &'a CommandInteraction, name: &str) -> Option<&'a str> {
+    command
+        .data
+        .options
+        .iter()
+        .find(|o| o.name == name)
+        .and_then(|o| match o.value {
+            CommandDataOptionValue::String(ref s) => Some(s.as_str()),
+            _ => None,
+        })
+}
+
+fn get_f64_opt(command: &CommandInteraction, name: &str) -> Option<f64> {
+    command
+        .data
+        .options
+        .iter()
+        .find(|o| o.name == name)
+        .and_then(|o| match o.value {
+            CommandDataOptionValue::Number(v) => Some(v),
+            _ => None,
+        })
+}
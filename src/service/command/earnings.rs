@@ -1,12 +1,22 @@
-use chrono::{Datelike, Duration, Utc, Weekday};
+use chrono::{Datelike, Duration, NaiveDate, Utc, Weekday};
 use chrono_tz::America::New_York;
-use serenity::all::{ChannelId, CommandInteraction, CreateCommand, Http};
+use serenity::all::{
+    ButtonStyle, ChannelId, CommandDataOptionValue, CommandInteraction, CommandOptionType,
+    CreateActionRow, CreateButton, CreateCommand, CreateCommandOption, Http, Permissions, UserId,
+};
 use std::time::Duration as StdDuration;
 use tokio::time::timeout;
 use tracing::{error, info, warn};
 
-use crate::models::EarningsEvent;
+use crate::models::{EarningsEvent, EarningsSession};
 use crate::service::automation::earnings;
+use crate::service::caching::collections::earnings_moves::{self, ActualMoveSource};
+use crate::service::caching::collections::earnings_subscriptions::{
+    self, Delivery, EarningsSubscription,
+};
+use crate::service::caching::collections::guild_settings::{self, CalendarTheme};
+use crate::service::caching::collections::watchlist;
+use crate::service::caching::RedisCache;
 use crate::service::finance::FinanceService;
 
 fn week_range_mon_fri(
@@ -24,14 +34,74 @@ fn week_range_mon_fri(
     (monday, friday)
 }
 
+const HISTORY_MAX_ROWS: usize = 12;
+
 /// Response payload for the /earnings command.
 pub struct EarningsResponse {
     pub content: String,
-    pub image: Option<Vec<u8>>,
+    /// Calendar image pages (image view).
+    pub images: Vec<Vec<u8>>,
+    /// Page buttons for the list view.
+    pub components: Vec<CreateActionRow>,
 }
 
+/// Character budget for one page of the text calendar (Discord caps messages at 2000).
+const LIST_PAGE_CHARS: usize = 1800;
+
+/// `custom_id` prefix of the weekly calendar page buttons.
+pub const WEEKLY_PAGE_PREFIX: &str = "weekly";
+
+/// Discord caps `custom_id` at 100 characters.
+const CUSTOM_ID_MAX: usize = 100;
+
 pub fn register_weekly_command() -> CreateCommand {
-    CreateCommand::new("weekly-earnings").description("Weekly earnings calendar")
+    CreateCommand::new("weekly-earnings")
+        .description("Weekly earnings calendar")
+        .add_option(
+            CreateCommandOption::new(CommandOptionType::String, "range", "Which dates to show")
+                .add_string_choice("This week", "this_week")
+                .add_string_choice("Next week", "next_week")
+                .add_string_choice("Custom (from/to)", "custom"),
+        )
+        .add_option(CreateCommandOption::new(
+            CommandOptionType::String,
+            "from",
+            "Custom start date YYYY-MM-DD",
+        ))
+        .add_option(CreateCommandOption::new(
+            CommandOptionType::String,
+            "to",
+            "Custom end date YYYY-MM-DD (max 31 days after start)",
+        ))
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::Integer,
+                "min_importance",
+                "Only events at or above this importance (1–5)",
+            )
+            .min_int_value(1)
+            .max_int_value(5),
+        )
+        .add_option(
+            CreateCommandOption::new(CommandOptionType::String, "session", "Report session")
+                .add_string_choice("Before open (BMO)", "bmo")
+                .add_string_choice("After close (AMC)", "amc"),
+        )
+        .add_option(CreateCommandOption::new(
+            CommandOptionType::Boolean,
+            "watchlist_only",
+            "Only watchlist symbols",
+        ))
+        .add_option(CreateCommandOption::new(
+            CommandOptionType::String,
+            "sector",
+            "Sector name or part of it, e.g., Technology",
+        ))
+        .add_option(
+            CreateCommandOption::new(CommandOptionType::String, "view", "Output format")
+                .add_string_choice("Calendar image", "image")
+                .add_string_choice("Paged list", "list"),
+        )
 }
 
 pub fn register_daily_command() -> CreateCommand {
@@ -43,43 +113,367 @@ pub fn register_after_daily_command() -> CreateCommand {
         .description("Post-earnings reports for companies just announcing their numbers")
 }
 
+pub fn register_history_command() -> CreateCommand {
+    CreateCommand::new("earnings-history")
+        .description("Past earnings: recorded implied move vs the realized move")
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::String,
+                "ticker",
+                "Ticker symbol, e.g., AAPL",
+            )
+            .required(true),
+        )
+}
+
+pub fn register_subscription_command() -> CreateCommand {
+    let ticker = || {
+        CreateCommandOption::new(
+            CommandOptionType::String,
+            "ticker",
+            "Ticker symbol, e.g., AAPL",
+        )
+        .required(true)
+    };
+
+    CreateCommand::new("earnings")
+        .description("Earnings reminders for the tickers you follow")
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "subscribe",
+                "Remind me the evening before, at report time, and when results are out",
+            )
+            .add_sub_option(ticker())
+            .add_sub_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "delivery",
+                    "Where to send reminders (default DM)",
+                )
+                .add_string_choice("Direct message", "dm")
+                .add_string_choice("Ping me in this channel", "channel"),
+            ),
+        )
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "unsubscribe",
+                "Stop earnings reminders for a ticker",
+            )
+            .add_sub_option(ticker()),
+        )
+        .add_option(CreateCommandOption::new(
+            CommandOptionType::SubCommand,
+            "list",
+            "Show your earnings subscriptions",
+        ))
+}
+
+pub fn register_theme_command() -> CreateCommand {
+    CreateCommand::new("earnings-theme")
+        .description("Set this server's earnings calendar image theme")
+        .default_member_permissions(Permissions::MANAGE_GUILD)
+        .dm_permission(false)
+        .add_option(
+            CreateCommandOption::new(CommandOptionType::String, "theme", "Calendar colors")
+                .required(true)
+                .add_string_choice("Light", "light")
+                .add_string_choice("Dark", "dark"),
+        )
+}
+
+/// Store the calendar theme for the invoking guild; used by `/weekly-earnings` and the
+/// scheduled calendar posts in this server.
+pub async fn handle_theme(
+    command: &CommandInteraction,
+    cache: Option<&RedisCache>,
+) -> Result<String, String> {
+    let cache = cache.ok_or("calendar themes require REDIS_URL to be configured")?;
+    let guild_id = command
+        .guild_id
+        .ok_or("the calendar theme can only be set in a server")?;
+    let raw = command
+        .data
+        .options
+        .iter()
+        .find(|o| o.name == "theme")
+        .and_then(|o| o.value.as_str())
+        .ok_or("theme is required")?;
+    let theme = CalendarTheme::parse(raw)
+        .ok_or_else(|| format!("unknown theme '{raw}'; use light | dark"))?;
+
+    guild_settings::save_calendar_theme(cache, guild_id.get(), theme)
+        .await
+        .map_err(|e| format!("failed to save theme: {e}"))?;
+    Ok(format!(
+        "🎨 Earnings calendar images in this server now use the {} theme.",
+        theme.as_str()
+    ))
+}
+
 /// Mention helpers (text entrypoints)
-pub async fn handle_weekly_mention(finance: &FinanceService) -> Result<EarningsResponse, String> {
-    handle_weekly_plain(finance).await
+pub async fn handle_weekly_mention(
+    finance: &FinanceService,
+    cache: Option<&RedisCache>,
+    theme: CalendarTheme,
+) -> Result<EarningsResponse, String> {
+    handle_weekly_plain(finance, cache, theme).await
 }
 
 pub async fn handle_daily_mention(
     finance: &FinanceService,
+    cache: Option<&RedisCache>,
     http: &Http,
     channel_id: ChannelId,
 ) -> Result<String, String> {
-    handle_daily_for_channel(finance, http, channel_id).await
+    handle_daily_for_channel(finance, cache, http, channel_id).await
 }
 
 pub async fn handle_after_daily_mention(
     finance: &FinanceService,
+    cache: Option<&RedisCache>,
     http: &Http,
     channel_id: ChannelId,
 ) -> Result<String, String> {
-    handle_after_daily_for_channel(finance, http, channel_id).await
+    handle_after_daily_for_channel(finance, cache, http, channel_id).await
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeeklyView {
+    Image,
+    List,
+}
+
+/// Filters for `/weekly-earnings`; encoded into the page buttons so paging is stateless.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeeklyFilters {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub min_importance: Option<i64>,
+    pub session: Option<EarningsSession>,
+    pub watchlist_only: bool,
+    pub sector: Option<String>,
+}
+
+impl WeeklyFilters {
+    /// Mon–Fri of the relevant week (Sunday looks ahead), no other filters.
+    pub fn this_week() -> Self {
+        let now_et = Utc::now().with_timezone(&New_York);
+        let (from, to) = week_range_mon_fri(now_et.weekday(), now_et.date_naive());
+        Self {
+            from,
+            to,
+            min_importance: None,
+            session: None,
+            watchlist_only: false,
+            sector: None,
+        }
+    }
+
+    fn is_filtered(&self) -> bool {
+        self.min_importance.is_some()
+            || self.session.is_some()
+            || self.watchlist_only
+            || self.sector.is_some()
+    }
+
+    /// Human-readable summary, e.g. `importance ≥ 4 · AMC · watchlist · sector ~ tech`.
+    pub fn describe(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(min) = self.min_importance {
+            parts.push(format!("importance ≥ {}", min));
+        }
+        if let Some(session) = self.session {
+            parts.push(session.to_string());
+        }
+        if self.watchlist_only {
+            parts.push("watchlist".to_string());
+        }
+        if let Some(sector) = &self.sector {
+            parts.push(format!("sector ~ {}", sector));
+        }
+        parts.join(" · ")
+    }
+
+    /// `weekly:{page}:{from}:{to}:{importance|-}:{b|a|-}:{1|0}:{sector}`
+    pub fn to_custom_id(&self, page: usize) -> String {
+        let mut id = format!(
+            "{}:{}:{}:{}:{}:{}:{}:",
+            WEEKLY_PAGE_PREFIX,
+            page,
+            self.from.format("%Y%m%d"),
+            self.to.format("%Y%m%d"),
+            self.min_importance
+                .map(|i| i.to_string())
+                .unwrap_or_else(|| "-".into()),
+            match self.session {
+                Some(EarningsSession::BeforeOpen) => "b",
+                Some(EarningsSession::AfterClose) => "a",
+                _ => "-",
+            },
+            if self.watchlist_only { "1" } else { "0" },
+        );
+        if let Some(sector) = &self.sector {
+            let room = CUSTOM_ID_MAX.saturating_sub(id.len());
+            id.extend(sector.chars().take(room));
+        }
+        id
+    }
+
+    pub fn parse_custom_id(id: &str) -> Option<(Self, usize)> {
+        let mut parts = id.splitn(8, ':');
+        if parts.next()? != WEEKLY_PAGE_PREFIX {
+            return None;
+        }
+        let page = parts.next()?.parse().ok()?;
+        let from = NaiveDate::parse_from_str(parts.next()?, "%Y%m%d").ok()?;
+        let to = NaiveDate::parse_from_str(parts.next()?, "%Y%m%d").ok()?;
+        let min_importance = match parts.next()? {
+            "-" => None,
+            raw => Some(raw.parse().ok()?),
+        };
+        let session = match parts.next()? {
+            "b" => Some(EarningsSession::BeforeOpen),
+            "a" => Some(EarningsSession::AfterClose),
+            _ => None,
+        };
+        let watchlist_only = parts.next()? == "1";
+        let sector = parts.next().filter(|s| !s.is_empty()).map(str::to_string);
+        Some((
+            Self {
+                from,
+                to,
+                min_importance,
+                session,
+                watchlist_only,
+                sector,
+            },
+            page,
+        ))
+    }
 }
 
+/// Longest custom range accepted, to keep the calendar API call bounded.
+const MAX_RANGE_DAYS: i64 = 31;
+
 pub async fn handle_weekly(
-    _command: &CommandInteraction,
+    command: &CommandInteraction,
     finance: &FinanceService,
+    cache: Option<&RedisCache>,
 ) -> Result<EarningsResponse, String> {
-    handle_weekly_plain(finance).await
+    let options = &command.data.options;
+    let get_str = |name: &str| {
+        options
+            .iter()
+            .find(|o| o.name == name)
+            .and_then(|o| match o.value {
+                CommandDataOptionValue::String(ref s) => Some(s.trim()),
+                _ => None,
+            })
+    };
+
+    let mut filters = WeeklyFilters::this_week();
+    let parse_date = |raw: &str| {
+        NaiveDate::parse_from_str(raw, "%Y-%m-%d")
+            .map_err(|_| format!("invalid date '{raw}', expected YYYY-MM-DD"))
+    };
+    let custom = get_str("range") == Some("custom") || get_str("from").is_some();
+    match get_str("range") {
+        Some("next_week") if !custom => {
+            filters.from += Duration::days(7);
+            filters.to += Duration::days(7);
+        }
+        _ if custom => {
+            let from = parse_date(get_str("from").ok_or("custom range needs `from`")?)?;
+            let to = match get_str("to") {
+                Some(raw) => parse_date(raw)?,
+                None => from + Duration::days(6),
+            };
+            if to < from {
+                return Err("`to` must not be before `from`".into());
+            }
+            if (to - from).num_days() > MAX_RANGE_DAYS {
+                return Err(format!("custom range is limited to {MAX_RANGE_DAYS} days"));
+            }
+            filters.from = from;
+            filters.to = to;
+        }
+        _ => {}
+    }
+
+    filters.min_importance = options
+        .iter()
+        .find(|o| o.name == "min_importance")
+        .and_then(|o| o.value.as_i64());
+    filters.session = match get_str("session") {
+        Some("bmo") => Some(EarningsSession::BeforeOpen),
+        Some("amc") => Some(EarningsSession::AfterClose),
+        _ => None,
+    };
+    filters.watchlist_only = options
+        .iter()
+        .find(|o| o.name == "watchlist_only")
+        .and_then(|o| o.value.as_bool())
+        .unwrap_or(false);
+    filters.sector = get_str("sector")
+        .filter(|s| !s.is_empty())
+        .map(str::to_string);
+
+    let view = match get_str("view") {
+        Some("list") => WeeklyView::List,
+        _ => WeeklyView::Image,
+    };
+
+    let theme = guild_settings::load_calendar_theme(cache, command.guild_id.map(|g| g.get())).await;
+    build_weekly(finance, cache, &filters, view, 0, theme).await
 }
 
-pub async fn handle_weekly_plain(finance: &FinanceService) -> Result<EarningsResponse, String> {
-    info!("Starting earnings command handler");
+pub async fn handle_weekly_plain(
+    finance: &FinanceService,
+    cache: Option<&RedisCache>,
+    theme: CalendarTheme,
+) -> Result<EarningsResponse, String> {
+    build_weekly(
+        finance,
+        cache,
+        &WeeklyFilters::this_week(),
+        WeeklyView::Image,
+        0,
+        theme,
+    )
+    .await
+}
 
-    // Compute the Monday–Friday range for the relevant week:
-    // - Mon–Fri: current week (Mon..Fri)
-    // - Sat: still the current week's Mon..Fri
-    // - Sun: next week's Mon..Fri
-    let now_et = Utc::now().with_timezone(&New_York);
-    let (start, end) = week_range_mon_fri(now_et.weekday(), now_et.date_naive());
+/// A page button on the text calendar was pressed: rebuild that page from its filters.
+pub async fn handle_weekly_page(
+    custom_id: &str,
+    finance: &FinanceService,
+    cache: Option<&RedisCache>,
+) -> Result<EarningsResponse, String> {
+    let (filters, page) = WeeklyFilters::parse_custom_id(custom_id)
+        .ok_or("this calendar page is no longer valid; run /weekly-earnings again")?;
+    build_weekly(
+        finance,
+        cache,
+        &filters,
+        WeeklyView::List,
+        page,
+        CalendarTheme::default(),
+    )
+    .await
+}
+
+async fn build_weekly(
+    finance: &FinanceService,
+    cache: Option<&RedisCache>,
+    filters: &WeeklyFilters,
+    view: WeeklyView,
+    page: usize,
+    theme: CalendarTheme,
+) -> Result<EarningsResponse, String> {
+    info!("Starting earnings command handler");
+    let (start, end) = (filters.from, filters.to);
 
     info!("Fetching earnings from {} to {}", start, end);
 
@@ -104,67 +498,150 @@ pub async fn handle_weekly_plain(finance: &FinanceService) -> Result<EarningsRes
         }
     };
 
+    let mut events = apply_filters(finance, cache, events, filters).await;
+    let filter_note = if filters.is_filtered() {
+        format!(" · {}", filters.describe())
+    } else {
+        String::new()
+    };
+
     if events.is_empty() {
-        info!("No earnings found in the next 7 days");
+        info!("No earnings match between {} and {}", start, end);
         return Ok(EarningsResponse {
-            content: "No earnings within the next 7 days.".to_string(),
-            image: None,
+            content: format!(
+                "No earnings between {} and {}{}.",
+                start.format("%Y-%m-%d"),
+                end.format("%Y-%m-%d"),
+                filter_note
+            ),
+            images: Vec::new(),
+            components: Vec::new(),
         });
     }
 
-    info!("Formatting output for {} events", events.len());
-    let output = format_output(&events);
     let summary = format!(
-        "📊 Earnings Calendar ({} to {}) — {} events",
+        "📊 Earnings Calendar ({} to {}) — {} events{}",
         start.format("%Y-%m-%d"),
         end.format("%Y-%m-%d"),
-        events.len()
+        events.len(),
+        filter_note
     );
 
-    match earnings::render_calendar_image(&events).await {
-        Ok(bytes) => Ok(EarningsResponse {
-            content: summary,
-            image: Some(bytes),
-        }),
-        Err(err) => {
-            warn!("Falling back to text earnings calendar: {}", err);
-
-            // Discord has a 2000 character limit
-            let content = if output.len() > 1900 {
-                warn!(
-                    "Output is {} characters, truncating to fit Discord limit",
-                    output.len()
-                );
-                format!(
-                    "{}\n\n⚠️ *Message truncated - showing first {} of {} events. Use filters to see more.*\n⚠️ Image render unavailable: {}",
-                    &output[..1800],
-                    output.matches("📈").count().min(30),
-                    events.len(),
-                    err
-                )
-            } else {
-                info!(
-                    "Output is {} characters, within Discord limit",
-                    output.len()
-                );
-                format!("{}\n\n⚠️ Image render unavailable: {}", output, err)
-            };
-
-            Ok(EarningsResponse {
-                content,
-                image: None,
-            })
+    let mut notice = String::new();
+    if view == WeeklyView::Image {
+        let implied_moves = earnings::enrich_calendar(finance, &mut events).await;
+        match earnings::render_calendar_pages(&events, &implied_moves, theme, cache).await {
+            Ok(images) => {
+                return Ok(EarningsResponse {
+                    content: summary,
+                    images,
+                    components: Vec::new(),
+                })
+            }
+            Err(err) => {
+                warn!("Falling back to text earnings calendar: {}", err);
+                notice = format!("\n⚠️ Image render unavailable: {}", err);
+            }
         }
     }
+
+    info!("Formatting output for {} events", events.len());
+    let budget = LIST_PAGE_CHARS.saturating_sub(summary.len() + notice.len());
+    let pages = earnings::split_message(&format_lines(&events), budget);
+    let total = pages.len().max(1);
+    let page = page.min(total - 1);
+
+    let mut content = format!(
+        "{}\n\n{}",
+        summary,
+        pages.get(page).map(String::as_str).unwrap_or_default()
+    );
+    if total > 1 {
+        content.push_str(&format!("\n\nPage {}/{}", page + 1, total));
+    }
+    content.push_str(&notice);
+
+    let components = if total > 1 {
+        vec![CreateActionRow::Buttons(vec![
+            CreateButton::new(filters.to_custom_id(page.saturating_sub(1)))
+                .label("◀ Prev")
+                .style(ButtonStyle::Secondary)
+                .disabled(page == 0),
+            CreateButton::new(filters.to_custom_id(page + 1))
+                .label("Next ▶")
+                .style(ButtonStyle::Secondary)
+                .disabled(page + 1 >= total),
+        ])]
+    } else {
+        Vec::new()
+    };
+
+    Ok(EarningsResponse {
+        content,
+        images: Vec::new(),
+        components,
+    })
+}
+
+/// Apply filters and order by date, then importance (highest first), then symbol.
+async fn apply_filters(
+    finance: &FinanceService,
+    cache: Option<&RedisCache>,
+    events: Vec<EarningsEvent>,
+    filters: &WeeklyFilters,
+) -> Vec<EarningsEvent> {
+    let watchlist = if filters.watchlist_only {
+        Some(watchlist::load_symbols(cache).await)
+    } else {
+        None
+    };
+
+    let mut events: Vec<EarningsEvent> = events
+        .into_iter()
+        .filter(|ev| (filters.from..=filters.to).contains(&ev.date.date_naive()))
+        .filter(|ev| {
+            filters
+                .min_importance
+                .is_none_or(|min| ev.importance.is_some_and(|i| i >= min))
+        })
+        .filter(|ev| filters.session.is_none_or(|session| ev.session == session))
+        .filter(|ev| {
+            watchlist
+                .as_ref()
+                .is_none_or(|w| w.contains(&ev.symbol.to_ascii_uppercase()))
+        })
+        .collect();
+
+    // Sector needs a profile lookup per symbol, so it runs last on the smallest set
+    if let Some(wanted) = &filters.sector {
+        let wanted = wanted.to_ascii_lowercase();
+        let symbols: Vec<String> = events.iter().map(|ev| ev.symbol.clone()).collect();
+        let sectors = finance.get_sectors(&symbols).await;
+        events.retain(|ev| {
+            sectors
+                .get(&ev.symbol.to_ascii_uppercase())
+                .is_some_and(|s| s.to_ascii_lowercase().contains(&wanted))
+        });
+    }
+
+    events.sort_by(|a, b| {
+        a.date
+            .date_naive()
+            .cmp(&b.date.date_naive())
+            .then(b.importance.cmp(&a.importance))
+            .then_with(|| a.symbol.cmp(&b.symbol))
+    });
+    events
 }
 
 /// Post today's earnings summary to the current channel using the daily automation helper.
 pub async fn handle_daily(
     command: &CommandInteraction,
     finance: &FinanceService,
+    cache: Option<&RedisCache>,
     http: &Http,
 ) -> Result<String, String> {
-    handle_daily_for_channel(finance, http, command.channel_id).await
+    handle_daily_for_channel(finance, cache, http, command.channel_id).await
 }
 
 /// Manually trigger the post-earnings report for today.
@@ -174,29 +651,282 @@ pub async fn handle_daily(
 pub async fn handle_after_daily(
     command: &CommandInteraction,
     finance: &FinanceService,
+    cache: Option<&RedisCache>,
     http: &Http,
 ) -> Result<String, String> {
-    handle_after_daily_for_channel(finance, http, command.channel_id).await
+    handle_after_daily_for_channel(finance, cache, http, command.channel_id).await
 }
 
 pub async fn handle_daily_for_channel(
     finance: &FinanceService,
+    cache: Option<&RedisCache>,
     http: &Http,
     channel_id: ChannelId,
 ) -> Result<String, String> {
-    earnings::send_daily_report(http, finance, channel_id).await?;
+    earnings::send_daily_report(http, finance, cache, channel_id).await?;
     Ok("Posted today's earnings report to this channel.".to_string())
 }
 
 pub async fn handle_after_daily_for_channel(
     finance: &FinanceService,
+    cache: Option<&RedisCache>,
     http: &Http,
     channel_id: ChannelId,
 ) -> Result<String, String> {
-    earnings::send_after_daily_report(http, finance, channel_id).await?;
+    earnings::send_after_daily_report(http, finance, cache, channel_id).await?;
     Ok("Posted today's post-earnings report to this channel.".to_string())
 }
 
+pub async fn handle_history(
+    command: &CommandInteraction,
+    finance: &FinanceService,
+    cache: Option<&RedisCache>,
+) -> Result<String, String> {
+    let ticker = command
+        .data
+        .options
+        .iter()
+        .find(|o| o.name == "ticker")
+        .and_then(|o| match o.value {
+            CommandDataOptionValue::String(ref s) => Some(s.as_str()),
+            _ => None,
+        })
+        .ok_or("ticker is required")?;
+    handle_history_text(finance, cache, ticker).await
+}
+
+/// Implied vs realized move for every recorded report of `ticker`. Realized moves are
+/// settled from daily closes once the reaction session is over, replacing the
+/// extended-hours estimate captured by the post-earnings report.
+pub async fn handle_history_text(
+    finance: &FinanceService,
+    cache: Option<&RedisCache>,
+    ticker: &str,
+) -> Result<String, String> {
+    let cache = cache.ok_or("earnings history requires REDIS_URL to be configured")?;
+    let symbol = ticker.trim().to_ascii_uppercase();
+
+    let mut records = earnings_moves::load_all(cache, &symbol)
+        .await
+        .map_err(|e| format!("failed to load earnings history: {e}"))?;
+    if records.is_empty() {
+        return Err(format!(
+            "No implied moves recorded for {} yet; they are captured by the daily earnings report.",
+            symbol
+        ));
+    }
+
+    let last_closed = earnings::last_closed_session(Utc::now());
+    let unsettled: Vec<_> = records
+        .iter()
+        .filter(|r| {
+            r.actual_source != Some(ActualMoveSource::SessionClose) && r.date <= last_closed
+        })
+        .map(|r| r.date)
+        .collect();
+
+    if let Some(oldest) = unsettled.iter().min() {
+        match finance
+            .get_daily_closes(&symbol, *oldest - Duration::days(7), last_closed)
+            .await
+        {
+            Ok(closes) => {
+                for record in records.iter_mut().filter(|r| unsettled.contains(&r.date)) {
+                    let Some(actual) = earnings::realized_move_pct(
+                        &closes,
+                        record.date,
+                        record.session,
+                        last_closed,
+                    ) else {
+                        continue;
+                    };
+                    record.actual_move_pct = Some(actual);
+                    record.actual_source = Some(ActualMoveSource::SessionClose);
+                    if let Err(e) = earnings_moves::save(cache, record).await {
+                        warn!("failed to store realized move for {}: {}", symbol, e);
+                    }
+                }
+            }
+            Err(e) => warn!("daily closes unavailable for {}: {}", symbol, e),
+        }
+    }
+
+    let mut lines = vec![
+        format!("📊 {} earnings: implied vs actual move", symbol),
+        String::new(),
+    ];
+    let mut compared = 0usize;
+    let mut exceeded = 0usize;
+    let mut ratio_sum = 0.0;
+
+    for record in records.iter().take(HISTORY_MAX_ROWS) {
+        let actual = match record.actual_move_pct {
+            Some(actual) => {
+                let mut text = format!("actual {:+.1}%", actual);
+                if record.actual_source == Some(ActualMoveSource::ExtendedHours) {
+                    text.push_str(" (ext. hours)");
+                }
+                if record.implied_move_pct > 0.0 {
+                    let ratio = actual.abs() / record.implied_move_pct;
+                    text.push_str(&format!(" — {:.2}×", ratio));
+                    compared += 1;
+                    ratio_sum += ratio;
+                    if ratio > 1.0 {
+                        exceeded += 1;
+                    }
+                }
+                text
+            }
+            None => "actual pending".to_string(),
+        };
+        lines.push(format!(
+            "`{}` {} — implied ±{:.1}% vs {}",
+            record.date.format("%Y-%m-%d"),
+            record.session,
+            record.implied_move_pct,
+            actual
+        ));
+    }
+
+    if compared > 0 {
+        lines.push(String::new());
+        lines.push(format!(
+            "Realized exceeded implied in {} of {} reports · avg |actual| / implied {:.2}×",
+            exceeded,
+            compared,
+            ratio_sum / compared as f64
+        ));
+    }
+
+    Ok(lines.join("\n"))
+}
+
+pub async fn handle_subscription(
+    command: &CommandInteraction,
+    finance: &FinanceService,
+    cache: Option<&RedisCache>,
+) -> Result<String, String> {
+    let sub = command
+        .data
+        .options
+        .first()
+        .ok_or("subcommand is required")?;
+    let options = match sub.value {
+        CommandDataOptionValue::SubCommand(ref options) => options.as_slice(),
+        _ => &[],
+    };
+    let get_str = |name: &str| {
+        options
+            .iter()
+            .find(|o| o.name == name)
+            .and_then(|o| match o.value {
+                CommandDataOptionValue::String(ref s) => Some(s.as_str()),
+                _ => None,
+            })
+    };
+
+    handle_subscription_text(
+        finance,
+        cache,
+        command.user.id,
+        command.channel_id,
+        &sub.name,
+        get_str("ticker"),
+        get_str("delivery"),
+    )
+    .await
+}
+
+/// `subscribe TICKER [dm|channel]`, `unsubscribe TICKER` or `list` for one user.
+pub async fn handle_subscription_text(
+    finance: &FinanceService,
+    cache: Option<&RedisCache>,
+    user_id: UserId,
+    channel_id: ChannelId,
+    action: &str,
+    ticker: Option<&str>,
+    delivery: Option<&str>,
+) -> Result<String, String> {
+    let cache = cache.ok_or("earnings subscriptions require REDIS_URL to be configured")?;
+    let symbol = ticker.map(|t| t.trim().to_ascii_uppercase());
+
+    match action {
+        "subscribe" => {
+            let symbol = symbol.ok_or("ticker is required")?;
+            let delivery = match delivery.map(|d| d.to_ascii_lowercase()).as_deref() {
+                None | Some("dm") => Delivery::Dm,
+                Some("channel") => Delivery::Channel {
+                    channel_id: channel_id.get(),
+                },
+                Some(other) => return Err(format!("unknown delivery '{other}'; use dm | channel")),
+            };
+            let quote = finance
+                .get_price(&symbol)
+                .await
+                .map_err(|e| format!("unknown ticker {symbol}: {e}"))?;
+
+            let subscription = EarningsSubscription {
+                user_id: user_id.get(),
+                symbol: symbol.clone(),
+                delivery,
+                created_at: Utc::now(),
+            };
+            let added = earnings_subscriptions::subscribe(cache, &subscription)
+                .await
+                .map_err(|e| format!("failed to save subscription: {e}"))?;
+
+            let target = match delivery {
+                Delivery::Dm => "by DM".to_string(),
+                Delivery::Channel { channel_id } => format!("in <#{}>", channel_id),
+            };
+            let name = if quote.name.is_empty() {
+                symbol.clone()
+            } else {
+                format!("{} ({})", symbol, quote.name)
+            };
+            Ok(format!(
+                "{} {} — you'll be reminded {} the evening before it reports, at report time with the implied move, and when results are out.",
+                if added { "🔔 Subscribed to" } else { "🔔 Updated subscription for" },
+                name,
+                target
+            ))
+        }
+        "unsubscribe" => {
+            let symbol = symbol.ok_or("ticker is required")?;
+            let removed = earnings_subscriptions::unsubscribe(cache, user_id.get(), &symbol)
+                .await
+                .map_err(|e| format!("failed to remove subscription: {e}"))?;
+            if removed {
+                Ok(format!(
+                    "🔕 Unsubscribed from {} earnings reminders.",
+                    symbol
+                ))
+            } else {
+                Err(format!("you are not subscribed to {symbol}"))
+            }
+        }
+        "list" => {
+            let subs = earnings_subscriptions::list_for_user(cache, user_id.get())
+                .await
+                .map_err(|e| format!("failed to load subscriptions: {e}"))?;
+            if subs.is_empty() {
+                return Ok(
+                    "You have no earnings subscriptions. Use `/earnings subscribe TICKER`.".into(),
+                );
+            }
+            let entries: Vec<String> = subs
+                .iter()
+                .map(|s| match s.delivery {
+                    Delivery::Dm => format!("{} (DM)", s.symbol),
+                    Delivery::Channel { channel_id } => format!("{} (<#{}>)", s.symbol, channel_id),
+                })
+                .collect();
+            Ok(format!("🔔 Earnings subscriptions: {}", entries.join(", ")))
+        }
+        other => Err(format!("unknown subcommand: {other}")),
+    }
+}
+
 pub fn format_output(events: &[EarningsEvent]) -> String {
     let mut lines = Vec::new();
     lines.push(format!(
@@ -214,30 +944,7 @@ pub fn format_output(events: &[EarningsEvent]) -> String {
         events
     };
 
-    for event in display_events {
-        let date_str = event.date.format("%m/%d").to_string(); // Shorter date format
-        let tod = match event.time_of_day.as_deref() {
-            Some("16:00") | Some("amc") => "AMC",
-            Some("09:00") | Some("bmo") => "BMO",
-            Some(t) => t,
-            None => "TBA",
-        };
-
-        let emoji = event.emoji.as_deref().unwrap_or("📈");
-        let importance_indicator = match event.importance {
-            Some(5) => " 🔥",
-            Some(4) => " ⭐",
-            _ => "",
-        };
-
-        // Compact format: emoji symbol date (time) importance
-        let line = format!(
-            "{} **{}** {} ({}){}",
-            emoji, event.symbol, date_str, tod, importance_indicator
-        );
-
-        lines.push(line);
-    }
+    lines.extend(format_lines(display_events));
 
     if events.len() > 50 {
         lines.push(String::new());
@@ -246,3 +953,26 @@ pub fn format_output(events: &[EarningsEvent]) -> String {
 
     lines.join("\n")
 }
+
+/// One compact line per event: emoji symbol date (time) importance.
+pub fn format_lines(events: &[EarningsEvent]) -> Vec<String> {
+    events
+        .iter()
+        .map(|event| {
+            let date_str = event.date.format("%m/%d").to_string(); // Shorter date format
+            let tod = event.session.as_str();
+
+            let emoji = event.emoji.as_deref().unwrap_or("📈");
+            let importance_indicator = match event.importance {
+                Some(5) => " 🔥",
+                Some(4) => " ⭐",
+                _ => "",
+            };
+
+            format!(
+                "{} **{}** {} ({}){}",
+                emoji, event.symbol, date_str, tod, importance_indicator
+            )
+        })
+        .collect()
+}
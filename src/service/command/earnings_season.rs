@@ -0,0 +1,337 @@
+use std::collections::HashSet;
+
+use chrono::{NaiveDate, Utc};
+use chrono_tz::America::New_York;
+use serenity::all::{
+    CommandDataOptionValue, CommandInteraction, CommandOptionType, CreateCommand,
+    CreateCommandOption,
+};
+use tracing::warn;
+
+use crate::models::{EarningsSeason, SurpriseVerdict};
+use crate::service::caching::collections::earnings_moves::{self, ActualMoveSource};
+use crate::service::caching::collections::earnings_season::{self, SeasonData, SeasonReport};
+use crate::service::caching::collections::watchlist;
+use crate::service::caching::RedisCache;
+use crate::service::finance::earnings::INLINE_SURPRISE_PCT;
+use crate::service::finance::FinanceService;
+
+/// Gainers and losers listed under "Biggest movers".
+const MOVERS: usize = 5;
+
+/// Surprises are clamped to ±this before averaging, so a near-zero EPS estimate does not
+/// swamp the season average.
+const SURPRISE_CAP_PCT: f64 = 100.0;
+
+/// Reported companies without a saved sector looked up per scoped call.
+const SECTOR_BACKFILL: usize = 20;
+
+pub fn register_command() -> CreateCommand {
+    CreateCommand::new("earnings-season")
+        .description("Current earnings season: reported so far, beat rates, reactions")
+        .add_option(CreateCommandOption::new(
+            CommandOptionType::String,
+            "scope",
+            "Sector name or part of it (e.g., Technology), or `watchlist`",
+        ))
+}
+
+pub async fn handle(
+    command: &CommandInteraction,
+    finance: &FinanceService,
+    cache: Option<&RedisCache>,
+) -> Result<String, String> {
+    let scope = command
+        .data
+        .options
+        .iter()
+        .find(|o| o.name == "scope")
+        .and_then(|o| match o.value {
+            CommandDataOptionValue::String(ref s) => Some(s.as_str()),
+            _ => None,
+        });
+    handle_text(finance, cache, scope).await
+}
+
+/// Season dashboard from the calendars recorded by the weekly poster and the actuals
+/// recorded by the post-earnings job.
+pub async fn handle_text(
+    finance: &FinanceService,
+    cache: Option<&RedisCache>,
+    scope: Option<&str>,
+) -> Result<String, String> {
+    let cache = cache.ok_or("the earnings season dashboard requires REDIS_URL to be configured")?;
+    let scope = scope.map(str::trim).filter(|s| !s.is_empty());
+
+    let today = Utc::now().with_timezone(&New_York).date_naive();
+    let season = EarningsSeason::for_report_date(today);
+    let mut data = earnings_season::load(cache, season)
+        .await
+        .map_err(|e| format!("failed to load the earnings season: {e}"))?;
+
+    if let Some(scope) = scope {
+        let keep = scope_symbols(finance, cache, season, &mut data, scope).await;
+        data.scheduled
+            .retain(|ev| keep.contains(&ev.symbol.to_ascii_uppercase()));
+        data.reported.retain(|r| keep.contains(&r.symbol));
+    }
+
+    if data.scheduled.is_empty() && data.reported.is_empty() {
+        return Err(match scope {
+            Some(scope) => format!("No {} reports recorded for the {} season.", scope, season),
+            None => format!(
+                "Nothing recorded for the {} season yet; the weekly calendar and post-earnings reports fill it in.",
+                season
+            ),
+        });
+    }
+
+    settle_reactions(cache, &mut data.reported).await;
+    let summary = summarize(&data, today);
+    Ok(format_summary(season, scope, &summary))
+}
+
+/// Upper-cased symbols of the season that fall in `scope` (`watchlist` or a sector).
+///
+/// Sectors come from those saved with the season calendar. Reported companies recorded
+/// without one (e.g. before sectors were saved) are looked up, at most
+/// `SECTOR_BACKFILL` per call, and saved so later calls skip them.
+async fn scope_symbols(
+    finance: &FinanceService,
+    cache: &RedisCache,
+    season: EarningsSeason,
+    data: &mut SeasonData,
+    scope: &str,
+) -> HashSet<String> {
+    if scope.eq_ignore_ascii_case("watchlist") {
+        return watchlist::load_symbols(Some(cache))
+            .await
+            .into_iter()
+            .collect();
+    }
+
+    let mut missing: Vec<String> = data
+        .reported
+        .iter()
+        .map(|r| r.symbol.clone())
+        .filter(|symbol| !data.sectors.contains_key(symbol))
+        .collect();
+    missing.sort();
+    missing.dedup();
+    missing.truncate(SECTOR_BACKFILL);
+    if !missing.is_empty() {
+        let found = finance.get_sectors(&missing).await;
+        if let Err(e) = earnings_season::record_sectors(cache, season, &found).await {
+            warn!("failed to save season sectors: {}", e);
+        }
+        data.sectors.extend(found);
+    }
+
+    let wanted = scope.to_ascii_lowercase();
+    data.sectors
+        .iter()
+        .filter(|(_, sector)| sector.to_ascii_lowercase().contains(&wanted))
+        .map(|(symbol, _)| symbol.clone())
+        .collect()
+}
+
+/// Prefer the close-to-close move settled by `/earnings-history` over the extended-hours
+/// reaction captured at report time.
+async fn settle_reactions(cache: &RedisCache, reports: &mut [SeasonReport]) {
+    for report in reports {
+        match earnings_moves::load(cache, &report.symbol, report.date).await {
+            Ok(Some(record)) if record.actual_source == Some(ActualMoveSource::SessionClose) => {
+                report.reaction_pct = record.actual_move_pct.or(report.reaction_pct);
+            }
+            Ok(_) => {}
+            Err(e) => warn!("failed to load settled move for {}: {}", report.symbol, e),
+        }
+    }
+}
+
+/// Beat / inline / miss counts for one line item.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BeatTally {
+    pub beat: usize,
+    pub inline: usize,
+    pub miss: usize,
+    /// Mean surprise, each clamped to ±`SURPRISE_CAP_PCT`.
+    pub avg_surprise_pct: Option<f64>,
+}
+
+impl BeatTally {
+    fn from_surprises(surprises: impl Iterator<Item = f64>) -> Self {
+        let mut tally = BeatTally::default();
+        let mut sum = 0.0;
+        for pct in surprises {
+            match SurpriseVerdict::from_surprise(pct, INLINE_SURPRISE_PCT) {
+                SurpriseVerdict::Beat => tally.beat += 1,
+                SurpriseVerdict::Inline => tally.inline += 1,
+                SurpriseVerdict::Miss => tally.miss += 1,
+            }
+            sum += pct.clamp(-SURPRISE_CAP_PCT, SURPRISE_CAP_PCT);
+        }
+        let count = tally.count();
+        tally.avg_surprise_pct = (count > 0).then(|| sum / count as f64);
+        tally
+    }
+
+    pub fn count(&self) -> usize {
+        self.beat + self.inline + self.miss
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SeasonSummary {
+    /// Companies on the season calendar or with recorded results.
+    pub companies: usize,
+    /// Companies with results recorded or a report date already past.
+    pub reported: usize,
+    pub eps: BeatTally,
+    pub revenue: BeatTally,
+    pub reactions: usize,
+    pub avg_reaction_pct: Option<f64>,
+    pub avg_abs_reaction_pct: Option<f64>,
+    /// Largest positive reactions, biggest first.
+    pub gainers: Vec<SeasonReport>,
+    /// Largest negative reactions, biggest first.
+    pub losers: Vec<SeasonReport>,
+}
+
+pub fn summarize(data: &SeasonData, today: NaiveDate) -> SeasonSummary {
+    let mut companies: HashSet<String> = HashSet::new();
+    let mut reported: HashSet<String> = HashSet::new();
+    for ev in &data.scheduled {
+        let symbol = ev.symbol.to_ascii_uppercase();
+        if ev.date.date_naive() < today {
+            reported.insert(symbol.clone());
+        }
+        companies.insert(symbol);
+    }
+    for report in &data.reported {
+        companies.insert(report.symbol.clone());
+        reported.insert(report.symbol.clone());
+    }
+
+    let mut moved: Vec<&SeasonReport> = data
+        .reported
+        .iter()
+        .filter(|r| r.reaction_pct.is_some_and(f64::is_finite))
+        .collect();
+    let moves: Vec<f64> = moved.iter().filter_map(|r| r.reaction_pct).collect();
+
+    moved.sort_by(|a, b| {
+        b.reaction_pct
+            .partial_cmp(&a.reaction_pct)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.symbol.cmp(&b.symbol))
+    });
+    let gainers = moved
+        .iter()
+        .take_while(|r| r.reaction_pct.is_some_and(|p| p > 0.0))
+        .take(MOVERS)
+        .map(|r| (*r).clone())
+        .collect();
+    let losers = moved
+        .iter()
+        .rev()
+        .take_while(|r| r.reaction_pct.is_some_and(|p| p < 0.0))
+        .take(MOVERS)
+        .map(|r| (*r).clone())
+        .collect();
+
+    SeasonSummary {
+        companies: companies.len(),
+        reported: reported.len(),
+        eps: BeatTally::from_surprises(
+            data.reported
+                .iter()
+                .filter_map(SeasonReport::eps_surprise_pct),
+        ),
+        revenue: BeatTally::from_surprises(
+            data.reported
+                .iter()
+                .filter_map(SeasonReport::revenue_surprise_pct),
+        ),
+        reactions: moves.len(),
+        avg_reaction_pct: mean(moves.iter().copied()),
+        avg_abs_reaction_pct: mean(moves.iter().map(|m| m.abs())),
+        gainers,
+        losers,
+    }
+}
+
+fn mean(values: impl Iterator<Item = f64>) -> Option<f64> {
+    let (sum, count) = values.fold((0.0, 0usize), |(sum, n), v| (sum + v, n + 1));
+    (count > 0).then(|| sum / count as f64)
+}
+
+fn format_summary(season: EarningsSeason, scope: Option<&str>, summary: &SeasonSummary) -> String {
+    let scope_text = scope.map(|s| format!(" · {}", s)).unwrap_or_default();
+    let mut lines = vec![
+        format!(
+            "📊 {} earnings season ({} – {}){}",
+            season,
+            season.start().format("%b %-d"),
+            season.end().format("%b %-d, %Y"),
+            scope_text
+        ),
+        String::new(),
+        format!(
+            "Reported: {} of {} companies ({:.0}%)",
+            summary.reported,
+            summary.companies,
+            summary.reported as f64 / summary.companies.max(1) as f64 * 100.0
+        ),
+        format!("EPS: {}", format_tally(&summary.eps)),
+        format!("Revenue: {}", format_tally(&summary.revenue)),
+    ];
+
+    match (summary.avg_reaction_pct, summary.avg_abs_reaction_pct) {
+        (Some(avg), Some(abs)) => lines.push(format!(
+            "Avg reaction: {:+.1}% (avg |move| {:.1}%, {} reports)",
+            avg, abs, summary.reactions
+        )),
+        _ => lines.push("Avg reaction: n/a".to_string()),
+    }
+
+    if !summary.gainers.is_empty() || !summary.losers.is_empty() {
+        lines.push(String::new());
+        lines.push("Biggest movers".to_string());
+        for report in summary.gainers.iter().chain(&summary.losers) {
+            let reaction = report.reaction_pct.unwrap_or_default();
+            let icon = if reaction >= 0.0 { "🟢" } else { "🔴" };
+            lines.push(format!(
+                "{} **{}** {:+.1}% — {} {}{}",
+                icon,
+                report.symbol,
+                reaction,
+                report.session,
+                report.date.format("%b %-d"),
+                report
+                    .eps_surprise_pct()
+                    .map(|pct| format!(" · EPS {:+.1}%", pct))
+                    .unwrap_or_default()
+            ));
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// `78% beat · 6% inline · 16% miss (n=290) · avg surprise +5.3%`
+fn format_tally(tally: &BeatTally) -> String {
+    let count = tally.count();
+    if count == 0 {
+        return "no results yet".to_string();
+    }
+    let pct = |n: usize| n as f64 / count as f64 * 100.0;
+    format!(
+        "{:.0}% beat · {:.0}% inline · {:.0}% miss (n={}) · avg surprise {:+.1}%",
+        pct(tally.beat),
+        pct(tally.inline),
+        pct(tally.miss),
+        count,
+        tally.avg_surprise_pct.unwrap_or_default()
+    )
+}
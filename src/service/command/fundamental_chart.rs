@@ -0,0 +1,524 @@
+use std::collections::BTreeSet;
+use std::io::Cursor;
+
+use ab_glyph::PxScale;
+use chrono::{Datelike, NaiveDate};
+use futures_util::future::join_all;
+use image::{ImageFormat, Rgba, RgbaImage};
+use imageproc::drawing::{draw_filled_rect_mut, draw_line_segment_mut, draw_text_mut, text_size};
+use imageproc::rect::Rect;
+use serenity::all::{
+    CommandDataOptionValue, CommandInteraction, CommandOptionType, CreateCommand,
+    CreateCommandOption,
+};
+use tracing::warn;
+
+use crate::models::{Frequency, StatementType};
+use crate::service::automation::earnings::weekly_report::load_font;
+use crate::service::finance::fundamentals::{
+    reshape_timeseries_to_financial_statements, FETCH_YEARS_DEFAULT,
+};
+use crate::service::finance::statements::{
+    abbreviate, cagr, find_line_item, format_value, growth_rates, metric_series, LineItem, LineKind,
+};
+use crate::service::finance::FinanceService;
+
+const MAX_METRICS: usize = 3;
+const MAX_TICKERS: usize = 2;
+const MAX_YEARS: i64 = 20;
+
+const WIDTH: u32 = 1000;
+const HEIGHT: u32 = 560;
+const PLOT_LEFT: f32 = 90.0;
+const PLOT_RIGHT: f32 = 970.0;
+const PLOT_TOP: f32 = 80.0;
+const PLOT_BOTTOM: f32 = 490.0;
+
+const BG: Rgba<u8> = Rgba([255, 255, 255, 255]);
+const AXIS: Rgba<u8> = Rgba([120, 120, 120, 255]);
+const GRID: Rgba<u8> = Rgba([232, 232, 232, 255]);
+const TEXT: Rgba<u8> = Rgba([40, 40, 40, 255]);
+const UP: Rgba<u8> = Rgba([22, 130, 60, 255]);
+const DOWN: Rgba<u8> = Rgba([200, 40, 40, 255]);
+const SERIES_COLORS: [Rgba<u8>; MAX_METRICS] = [
+    Rgba([33, 150, 243, 255]),
+    Rgba([255, 152, 0, 255]),
+    Rgba([76, 175, 80, 255]),
+];
+
+/// Parsed `/fundamental-chart` input: one or two tickers, one to three line items.
+#[derive(Debug, Clone)]
+pub struct ChartRequest {
+    pub tickers: Vec<String>,
+    pub metrics: Vec<(StatementType, LineItem)>,
+}
+
+/// One bar series: a metric for a ticker, oldest period first.
+#[derive(Debug, Clone)]
+pub struct ChartSeries {
+    pub label: String,
+    pub kind: LineKind,
+    pub points: Vec<(NaiveDate, f64)>,
+}
+
+/// Response payload for the /fundamental-chart command.
+pub struct FundamentalChartResponse {
+    pub content: String,
+    pub image: Option<Vec<u8>>,
+}
+
+pub fn register_command() -> CreateCommand {
+    CreateCommand::new("fundamental-chart")
+        .description("Chart a fundamentals metric over time, with growth rates")
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::String,
+                "ticker",
+                "Ticker, or two to compare (e.g., AAPL or AAPL,MSFT)",
+            )
+            .required(true),
+        )
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::String,
+                "metric",
+                "Metric, or up to three comma-separated (e.g., revenue, free cash flow)",
+            )
+            .required(true),
+        )
+        .add_option(
+            CreateCommandOption::new(CommandOptionType::String, "freq", "annual or quarterly")
+                .add_string_choice("Annual", "annual")
+                .add_string_choice("Quarterly", "quarterly"),
+        )
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::Integer,
+                "years",
+                "Years of history (default 5)",
+            )
+            .min_int_value(1)
+            .max_int_value(MAX_YEARS as u64),
+        )
+}
+
+pub async fn handle(
+    command: &CommandInteraction,
+    finance: &FinanceService,
+) -> Result<FundamentalChartResponse, String> {
+    let tickers = get_str_opt(command, "ticker").ok_or("ticker is required")?;
+    let metrics = get_str_opt(command, "metric").ok_or("metric is required")?;
+    let freq = get_str_opt(command, "freq");
+    let years = get_int_opt(command, "years");
+    handle_text(finance, tickers, metrics, freq, years).await
+}
+
+pub async fn handle_text(
+    finance: &FinanceService,
+    tickers: &str,
+    metrics: &str,
+    freq: Option<&str>,
+    years: Option<i64>,
+) -> Result<FundamentalChartResponse, String> {
+    let request = parse_chart_request(tickers, metrics)?;
+    let freq = match freq.map(|f| f.trim().to_ascii_lowercase()) {
+        Some(f) if f.starts_with('q') => Frequency::Quarterly,
+        _ => Frequency::Annual,
+    };
+    let years = years.unwrap_or(FETCH_YEARS_DEFAULT).clamp(1, MAX_YEARS);
+
+    let series = fetch_series(finance, &request, freq, years).await?;
+    let (charted, missing): (Vec<_>, Vec<_>) =
+        series.into_iter().partition(|s| !s.points.is_empty());
+    if charted.is_empty() {
+        return Err(format!(
+            "no {} data for the requested metrics",
+            freq_label(freq)
+        ));
+    }
+
+    let title = format!(
+        "{} · {} ({})",
+        request.tickers.join(" vs "),
+        request
+            .metrics
+            .iter()
+            .map(|(_, item)| item.label)
+            .collect::<Vec<_>>()
+            .join(" vs "),
+        freq_label(freq)
+    );
+
+    let mut lines = vec![format!("📊 {}", title)];
+    lines.extend(charted.iter().map(format_series_line));
+    for s in &missing {
+        lines.push(format!("{}: no data", s.label));
+    }
+
+    let image = match render_chart_image(&title, &charted, freq) {
+        Ok(bytes) => Some(bytes),
+        Err(err) => {
+            warn!("fundamental chart render failed for {}: {}", title, err);
+            None
+        }
+    };
+
+    Ok(FundamentalChartResponse {
+        content: lines.join("\n"),
+        image,
+    })
+}
+
+/// Split `AAPL,MSFT` / `revenue, free cash flow` into tickers and line items. Several
+/// metrics can be charted for one ticker, or one metric for two tickers; overlaid
+/// metrics must share a unit.
+pub fn parse_chart_request(tickers: &str, metrics: &str) -> Result<ChartRequest, String> {
+    let mut symbols: Vec<String> = Vec::new();
+    for raw in tickers.split([',', ' ']).filter(|t| !t.is_empty()) {
+        let symbol = raw.to_ascii_uppercase();
+        if !symbol
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '^'))
+        {
+            return Err(format!("invalid symbol: {raw}"));
+        }
+        if !symbols.contains(&symbol) {
+            symbols.push(symbol);
+        }
+    }
+    if symbols.is_empty() {
+        return Err("ticker required, e.g., AAPL or AAPL,MSFT".into());
+    }
+    if symbols.len() > MAX_TICKERS {
+        return Err(format!("at most {MAX_TICKERS} tickers can be compared"));
+    }
+
+    let mut items: Vec<(StatementType, LineItem)> = Vec::new();
+    for raw in metrics.split(',').map(str::trim).filter(|m| !m.is_empty()) {
+        let found = find_line_item(raw).ok_or_else(|| format!("unknown metric: {raw}"))?;
+        if !items.iter().any(|(_, item)| item.field == found.1.field) {
+            items.push(found);
+        }
+    }
+    if items.is_empty() {
+        return Err("metric required, e.g., revenue or revenue, free cash flow".into());
+    }
+    if items.len() > MAX_METRICS {
+        return Err(format!("at most {MAX_METRICS} metrics can be overlaid"));
+    }
+    if symbols.len() > 1 && items.len() > 1 {
+        return Err("compare two tickers on one metric, or one ticker on several metrics".into());
+    }
+    if items.iter().any(|(_, item)| item.kind != items[0].1.kind) {
+        return Err(
+            "overlaid metrics must share a unit (amounts, per-share or share counts)".into(),
+        );
+    }
+
+    Ok(ChartRequest {
+        tickers: symbols,
+        metrics: items,
+    })
+}
+
+/// Fetch each ticker's statements once per statement type and pull out the series,
+/// ticker-major.
+async fn fetch_series(
+    finance: &FinanceService,
+    request: &ChartRequest,
+    freq: Frequency,
+    years: i64,
+) -> Result<Vec<ChartSeries>, String> {
+    let mut statement_types: Vec<StatementType> = Vec::new();
+    for (statement_type, _) in &request.metrics {
+        if !statement_types.contains(statement_type) {
+            statement_types.push(*statement_type);
+        }
+    }
+
+    let jobs: Vec<(&str, StatementType)> = request
+        .tickers
+        .iter()
+        .flat_map(|t| statement_types.iter().map(move |st| (t.as_str(), *st)))
+        .collect();
+    let fetched = join_all(
+        jobs.iter()
+            .map(|(ticker, st)| finance.get_fundamentals_raw(ticker, *st, freq, years)),
+    )
+    .await;
+
+    let mut statements = Vec::new();
+    for ((ticker, _), raw) in jobs.iter().zip(fetched) {
+        let raw = raw.map_err(|e| format!("fetch error for {ticker}: {e}"))?;
+        statements.extend(reshape_timeseries_to_financial_statements(&raw));
+    }
+
+    let mut series = Vec::new();
+    for ticker in &request.tickers {
+        for (statement_type, item) in &request.metrics {
+            let points = statements
+                .iter()
+                .find(|s| {
+                    s.symbol.eq_ignore_ascii_case(ticker)
+                        && s.statement_type == statement_type.as_str()
+                        && s.frequency == freq_label(freq)
+                })
+                .map(|s| metric_series(s, item.field))
+                .unwrap_or_default();
+            let label = if request.tickers.len() > 1 {
+                ticker.clone()
+            } else {
+                item.label.to_string()
+            };
+            series.push(ChartSeries {
+                label,
+                kind: item.kind,
+                points,
+            });
+        }
+    }
+    Ok(series)
+}
+
+fn freq_label(freq: Frequency) -> &'static str {
+    match freq {
+        Frequency::Annual => "annual",
+        Frequency::Quarterly => "quarterly",
+    }
+}
+
+/// Growth labels compare with the period a year earlier: the prior year, or the same
+/// quarter a year earlier.
+pub const GROWTH_MONTHS: u32 = 12;
+
+/// X-axis bucket for a period end: its calendar year, or calendar year and quarter.
+/// Companies with different fiscal year ends line up on the calendar period.
+pub fn period_bucket(date: NaiveDate, freq: Frequency) -> (i32, u32) {
+    match freq {
+        Frequency::Annual => (date.year(), 0),
+        Frequency::Quarterly => (date.year(), (date.month() - 1) / 3 + 1),
+    }
+}
+
+fn bucket_label((year, quarter): (i32, u32)) -> String {
+    match quarter {
+        0 => year.to_string(),
+        q => format!("Q{} {}", q, year),
+    }
+}
+
+/// `Total Revenue: 416.16B (2025-09-30) · +6.4% YoY · CAGR +4.1% over 4y`
+pub fn format_series_line(series: &ChartSeries) -> String {
+    let Some(&(last_date, last)) = series.points.last() else {
+        return format!("{}: no data", series.label);
+    };
+    let mut line = format!(
+        "**{}**: {} ({})",
+        series.label,
+        format_value(last, series.kind),
+        last_date.format("%Y-%m-%d")
+    );
+    if let Some(Some(yoy)) = growth_rates(&series.points, GROWTH_MONTHS).last() {
+        line.push_str(&format!(" · {:+.1}% YoY", yoy));
+    }
+    let (first_date, first) = series.points[0];
+    let span_years = (last_date - first_date).num_days() as f64 / 365.25;
+    if span_years >= 1.5 {
+        if let Some(rate) = cagr(first, last, span_years) {
+            line.push_str(&format!(" · CAGR {:+.1}% over {:.0}y", rate, span_years));
+        }
+    }
+    line
+}
+
+fn render_chart_image(
+    title: &str,
+    series: &[ChartSeries],
+    freq: Frequency,
+) -> Result<Vec<u8>, String> {
+    let font = load_font()?;
+    let mut img = RgbaImage::from_pixel(WIDTH, HEIGHT, BG);
+    let label_scale = PxScale::from(16.0);
+    let growth_scale = PxScale::from(13.0);
+
+    let buckets: Vec<(i32, u32)> = series
+        .iter()
+        .flat_map(|s| s.points.iter().map(|(d, _)| period_bucket(*d, freq)))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    if buckets.is_empty() {
+        return Err("no data to chart".into());
+    }
+
+    let all_values = series.iter().flat_map(|s| s.points.iter().map(|(_, v)| *v));
+    let y_min = all_values.clone().fold(0.0, f64::min);
+    let y_max = all_values.fold(0.0, f64::max);
+    let span = (y_max - y_min).max(1e-9);
+    // Head (and foot, for negatives) room for the growth labels
+    let y_max = y_max + span * 0.1;
+    let y_min = if y_min < 0.0 { y_min - span * 0.1 } else { 0.0 };
+    let py =
+        |v: f64| PLOT_BOTTOM - ((v - y_min) / (y_max - y_min)) as f32 * (PLOT_BOTTOM - PLOT_TOP);
+    let zero_y = py(0.0);
+
+    for i in 0..=5 {
+        let v = y_min + (y_max - y_min) * i as f64 / 5.0;
+        let y = py(v);
+        draw_line_segment_mut(&mut img, (PLOT_LEFT, y), (PLOT_RIGHT, y), GRID);
+        let label = match series[0].kind {
+            LineKind::PerShare => format!("{:.2}", v),
+            LineKind::Amount | LineKind::Shares => abbreviate(v),
+        };
+        let (w, _) = text_size(label_scale, &font, &label);
+        draw_text_mut(
+            &mut img,
+            AXIS,
+            (PLOT_LEFT - 8.0) as i32 - w as i32,
+            y as i32 - 8,
+            label_scale,
+            &font,
+            &label,
+        );
+    }
+
+    let slot_w = (PLOT_RIGHT - PLOT_LEFT) / buckets.len() as f32;
+    let bar_w = slot_w * 0.8 / series.len() as f32;
+    let label_every = buckets.len().div_ceil(12);
+
+    for (slot, bucket) in buckets.iter().enumerate() {
+        let slot_x = PLOT_LEFT + slot as f32 * slot_w;
+        if slot % label_every == 0 {
+            let label = bucket_label(*bucket);
+            let (w, _) = text_size(label_scale, &font, &label);
+            draw_text_mut(
+                &mut img,
+                AXIS,
+                (slot_x + slot_w / 2.0) as i32 - w as i32 / 2,
+                PLOT_BOTTOM as i32 + 10,
+                label_scale,
+                &font,
+                &label,
+            );
+        }
+    }
+
+    for (idx, s) in series.iter().enumerate() {
+        let color = SERIES_COLORS[idx % SERIES_COLORS.len()];
+        let growth = growth_rates(&s.points, GROWTH_MONTHS);
+
+        for ((date, value), change) in s.points.iter().zip(growth) {
+            let Some(slot) = buckets
+                .iter()
+                .position(|b| *b == period_bucket(*date, freq))
+            else {
+                continue;
+            };
+            let x = PLOT_LEFT + slot as f32 * slot_w + slot_w * 0.1 + idx as f32 * bar_w;
+            let y = py(*value);
+            let (top, bottom) = if y < zero_y { (y, zero_y) } else { (zero_y, y) };
+            let height = (bottom - top).round().max(1.0) as u32;
+            draw_filled_rect_mut(
+                &mut img,
+                Rect::at(x.round() as i32, top.round() as i32)
+                    .of_size((bar_w - 2.0).max(1.0) as u32, height),
+                color,
+            );
+
+            let Some(change) = change else {
+                continue;
+            };
+            let label = format!("{:+.0}%", change);
+            let (w, h) = text_size(growth_scale, &font, &label);
+            if w as f32 > bar_w + 4.0 {
+                continue;
+            }
+            let label_y = if *value >= 0.0 {
+                top - h as f32 - 4.0
+            } else {
+                bottom + 4.0
+            };
+            draw_text_mut(
+                &mut img,
+                if change >= 0.0 { UP } else { DOWN },
+                (x + bar_w / 2.0) as i32 - w as i32 / 2,
+                label_y as i32,
+                growth_scale,
+                &font,
+                &label,
+            );
+        }
+    }
+
+    draw_line_segment_mut(&mut img, (PLOT_LEFT, zero_y), (PLOT_RIGHT, zero_y), AXIS);
+    draw_line_segment_mut(
+        &mut img,
+        (PLOT_LEFT, PLOT_TOP),
+        (PLOT_LEFT, PLOT_BOTTOM),
+        AXIS,
+    );
+
+    // Title, legend and growth-label note
+    draw_text_mut(&mut img, TEXT, 24, 16, PxScale::from(26.0), &font, title);
+    let mut legend_x = PLOT_LEFT as i32;
+    for (idx, s) in series.iter().enumerate() {
+        draw_filled_rect_mut(
+            &mut img,
+            Rect::at(legend_x, 54).of_size(14, 14),
+            SERIES_COLORS[idx % SERIES_COLORS.len()],
+        );
+        draw_text_mut(
+            &mut img,
+            TEXT,
+            legend_x + 20,
+            52,
+            label_scale,
+            &font,
+            &s.label,
+        );
+        let (w, _) = text_size(label_scale, &font, &s.label);
+        legend_x += w as i32 + 48;
+    }
+    let note = "bar labels: YoY growth";
+    let (w, _) = text_size(label_scale, &font, note);
+    draw_text_mut(
+        &mut img,
+        AXIS,
+        PLOT_RIGHT as i32 - w as i32,
+        52,
+        label_scale,
+        &font,
+        note,
+    );
+
+    let mut buffer = Vec::new();
+    image::DynamicImage::ImageRgba8(img)
+        .write_to(&mut Cursor::new(&mut buffer), ImageFormat::Png)
+        .map_err(|e| format!("failed to encode png: {e}"))?;
+
+    Ok(buffer)
+}
+
+fn get_str_opt<'a>(command: &'a CommandInteraction, name: &str) -> Option<&'a str> {
+    command
+        .data
+        .options
+        .iter()
+        .find(|o| o.name == name)
+        .and_then(|o| match o.value {
+            CommandDataOptionValue::String(ref s) => Some(s.as_str()),
+            _ => None,
+        })
+}
+
+fn get_int_opt(command: &CommandInteraction, name: &str) -> Option<i64> {
+    command
+        .data
+        .options
+        .iter()
+        .find(|o| o.name == name)
+        .and_then(|o| match o.value {
+            CommandDataOptionValue::Integer(v) => Some(v),
+            _ => None,
+        })
+}
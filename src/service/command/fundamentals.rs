@@ -1,7 +1,7 @@
 use chrono::{Datelike, NaiveDate, Utc};
 use serenity::all::{
-    CommandDataOptionValue, CommandInteraction, CommandOptionType, CreateCommand,
-    CreateCommandOption,
+    AutocompleteChoice, CommandDataOptionValue, CommandInteraction, CommandOptionType,
+    CreateAutocompleteResponse, CreateCommand, CreateCommandOption,
 };
 
 use ab_glyph::{FontArc, PxScale};
@@ -9,114 +9,55 @@ use font_kit::family_name::FamilyName;
 use font_kit::properties::{Properties, Weight};
 use font_kit::source::SystemSource;
 use image::{ImageFormat, Rgba, RgbaImage};
-use imageproc::drawing::draw_text_mut;
+use imageproc::drawing::{draw_filled_rect_mut, draw_text_mut, text_size};
+use imageproc::rect::Rect;
 use std::io::Cursor;
 
-use crate::models::{Frequency, StatementType};
+use crate::models::{FinancialStatement, Frequency, StatementType};
 use crate::service::finance::{
+    export::{export_statement, ExportFormat, StatementExport},
+    fiscal::FiscalCalendar,
     fundamentals::{reshape_timeseries_to_financial_statements, FETCH_YEARS_DEFAULT},
+    metric_search::{resolve_metric, search_metrics, MetricLookup, MetricMatch},
+    statements::{build_statement_table, format_value, StatementTable, STATEMENT_PERIODS},
     FinanceService,
 };
 
-#[derive(Debug, Clone)]
-struct MetricSpec {
-    slash_value: String,
-    field_key: String,
-    label: String,
-}
-
-fn get_metrics_for_statement(statement_type: StatementType) -> Vec<MetricSpec> {
-    use finance_query_core::utils::financials_constants::{
-        BALANCE_SHEET_FIELDS, CASH_FLOW_FIELDS, INCOME_STATEMENT_FIELDS,
-    };
-
-    let fields = match statement_type {
-        StatementType::IncomeStatement => INCOME_STATEMENT_FIELDS,
-        StatementType::BalanceSheet => BALANCE_SHEET_FIELDS,
-        StatementType::CashFlow => CASH_FLOW_FIELDS,
-    };
-
-    fields
-        .iter()
-        .map(|field| MetricSpec {
-            slash_value: to_snake(field),
-            field_key: field.to_string(),
-            label: to_title(field),
-        })
-        .collect()
-}
+/// Autocomplete suggestions for the `metric` option.
+const AUTOCOMPLETE_LIMIT: usize = 25;
+/// Suggestions listed when a typed metric is ambiguous.
+const SUGGESTION_LIMIT: usize = 5;
 
-fn to_snake(name: &str) -> String {
-    let mut out = String::with_capacity(name.len() + 5);
-    for (i, ch) in name.chars().enumerate() {
-        if ch.is_uppercase() {
-            if i > 0 {
-                out.push('_');
-            }
-            out.push(ch.to_ascii_lowercase());
-        } else {
-            out.push(ch);
-        }
-    }
-    out
-}
-
-fn to_title(name: &str) -> String {
-    to_snake(name)
-        .split('_')
-        .filter(|s| !s.is_empty())
-        .map(|s| {
-            let mut c = s.chars();
-            match c.next() {
-                Some(first) => format!("{}{}", first.to_ascii_uppercase(), c.as_str()),
-                None => String::new(),
-            }
-        })
-        .collect::<Vec<_>>()
-        .join(" ")
+/// Response payload for /income, /balance and /cashflow.
+pub struct FundamentalsResponse {
+    pub content: String,
+    /// Every period and line item, when an export format was requested.
+    pub export: Option<StatementExport>,
 }
 
-fn normalize_metric_value(
+/// Resolve a typed metric; the flag is set when it was a guess rather than an exact name.
+fn resolve_metric_value(
     statement_type: StatementType,
     raw: &str,
-) -> Result<(MetricSpec, bool), String> {
-    let metrics = get_metrics_for_statement(statement_type);
-    let norm = raw.trim().to_ascii_lowercase().replace(' ', "_");
-
-    // Exact / common-case matches first
-    if let Some(m) = metrics.iter().find(|m| m.slash_value == norm) {
-        return Ok((m.clone(), false));
-    }
-    if let Some(m) = metrics
-        .iter()
-        .find(|m| m.field_key.to_ascii_lowercase() == norm)
-    {
-        return Ok((m.clone(), true));
-    }
-    if let Some(m) = metrics
-        .iter()
-        .find(|m| m.label.to_ascii_lowercase() == norm.replace('_', " "))
-    {
-        return Ok((m.clone(), true));
-    }
-
-    // Prefix/substring heuristic
-    if let Some(m) = metrics.iter().find(|m| {
-        m.slash_value.starts_with(&norm)
-            || m.field_key.to_ascii_lowercase().starts_with(&norm)
-            || m.label.to_ascii_lowercase().starts_with(&norm.replace('_', " "))
-    }) {
-        return Ok((m.clone(), true));
+) -> Result<(MetricMatch, bool), String> {
+    match resolve_metric(statement_type, raw) {
+        MetricLookup::Exact(m) => Ok((m, false)),
+        MetricLookup::Guessed(m) => Ok((m, true)),
+        MetricLookup::Ambiguous(matches) => Err(format!(
+            "'{}' matches several metrics; did you mean {}?",
+            raw.trim(),
+            matches
+                .iter()
+                .take(SUGGESTION_LIMIT)
+                .map(|m| format!("`{}` ({})", m.slash_value(), m.label))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+        MetricLookup::NotFound => Err(format!(
+            "unknown metric '{}'; start typing in the metric option to see suggestions",
+            raw.trim()
+        )),
     }
-    if let Some(m) = metrics.iter().find(|m| {
-        m.slash_value.contains(&norm)
-            || m.field_key.to_ascii_lowercase().contains(&norm)
-            || m.label.to_ascii_lowercase().contains(&norm.replace('_', " "))
-    }) {
-        return Ok((m.clone(), true));
-    }
-
-    Err("unknown metric".into())
 }
 
 fn normalize_freq(raw: &str) -> (Frequency, bool) {
@@ -140,8 +81,6 @@ pub fn register_command(statement_type: StatementType) -> CreateCommand {
         StatementType::CashFlow => ("cashflow", "Get cash flow metrics (annual or quarterly)"),
     };
 
-    let metrics = get_metrics_for_statement(statement_type);
-
     CreateCommand::new(cmd_name)
         .description(description)
         .add_option(
@@ -152,20 +91,15 @@ pub fn register_command(statement_type: StatementType) -> CreateCommand {
             )
             .required(true),
         )
-        .add_option({
-            let mut opt = CreateCommandOption::new(
+        .add_option(
+            CreateCommandOption::new(
                 CommandOptionType::String,
                 "metric",
-                "Which metric to fetch",
+                "Which metric to fetch (type to search, e.g., fcf, opinc)",
             )
-            .required(true);
-
-            // Limit to 25 choices (Discord's max)
-            for m in metrics.iter().take(25) {
-                opt = opt.add_string_choice(m.label.clone(), m.slash_value.clone());
-            }
-            opt
-        })
+            .required(true)
+            .set_autocomplete(true),
+        )
         .add_option(
             CreateCommandOption::new(CommandOptionType::String, "freq", "annual or quarterly")
                 .required(true)
@@ -176,7 +110,7 @@ pub fn register_command(statement_type: StatementType) -> CreateCommand {
             CreateCommandOption::new(
                 CommandOptionType::Integer,
                 "year",
-                "Filter by year (optional)",
+                "Fiscal year (optional), e.g., 2026 for NVDA's year ending January 2026",
             )
             .min_int_value(1990),
         )
@@ -184,32 +118,61 @@ pub fn register_command(statement_type: StatementType) -> CreateCommand {
             CreateCommandOption::new(
                 CommandOptionType::String,
                 "quarter",
-                "Quarter (Q1-Q4, only with quarterly)",
+                "Fiscal quarter (Q1-Q4, only with quarterly)",
             )
             .add_string_choice("Q1", "Q1")
             .add_string_choice("Q2", "Q2")
             .add_string_choice("Q3", "Q3")
             .add_string_choice("Q4", "Q4")
         })
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::String,
+                "export",
+                "Attach the full statement (all periods and line items)",
+            )
+            .add_string_choice("CSV", "csv")
+            .add_string_choice("JSON", "json"),
+        )
+}
+
+/// Ranked `metric` suggestions for the value being typed; `None` for other options.
+pub fn autocomplete(command: &CommandInteraction) -> Option<CreateAutocompleteResponse> {
+    let focused = command.data.autocomplete()?;
+    if focused.name != "metric" {
+        return None;
+    }
+    let statement_type = statement_type_for(&command.data.name)?;
+
+    let choices = search_metrics(statement_type, focused.value, AUTOCOMPLETE_LIMIT)
+        .into_iter()
+        .map(|m| AutocompleteChoice::new(m.label.clone(), m.slash_value()))
+        .collect();
+    Some(CreateAutocompleteResponse::new().set_choices(choices))
+}
+
+fn statement_type_for(command_name: &str) -> Option<StatementType> {
+    match command_name {
+        "income" => Some(StatementType::IncomeStatement),
+        "balance" => Some(StatementType::BalanceSheet),
+        "cashflow" => Some(StatementType::CashFlow),
+        _ => None,
+    }
 }
 
 pub async fn handle(
     command: &CommandInteraction,
     finance: &FinanceService,
-) -> Result<String, String> {
+) -> Result<FundamentalsResponse, String> {
     let ticker = get_str_opt(command, "ticker").ok_or("ticker is required")?;
     let metric_val = get_str_opt(command, "metric").ok_or("metric is required")?;
     let freq_val = get_str_opt(command, "freq").ok_or("freq is required")?;
     let year = get_i64_opt(command, "year").map(|v| v as i32);
     let quarter = get_str_opt(command, "quarter");
+    let export = get_str_opt(command, "export").and_then(ExportFormat::parse);
 
     // Determine statement type from command name
-    let statement_type = match command.data.name.as_str() {
-        "income" => StatementType::IncomeStatement,
-        "balance" => StatementType::BalanceSheet,
-        "cashflow" => StatementType::CashFlow,
-        _ => return Err("unknown command".into()),
-    };
+    let statement_type = statement_type_for(&command.data.name).ok_or("unknown command")?;
 
     handle_text(
         finance,
@@ -219,10 +182,12 @@ pub async fn handle(
         freq_val,
         year,
         quarter,
+        export,
     )
     .await
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn handle_text(
     finance: &FinanceService,
     statement_type: StatementType,
@@ -231,18 +196,13 @@ pub async fn handle_text(
     freq_val: &str,
     year: Option<i32>,
     quarter: Option<&str>,
-) -> Result<String, String> {
+    export: Option<ExportFormat>,
+) -> Result<FundamentalsResponse, String> {
     let mut corrections = Vec::new();
 
-    let (metric, metric_corrected) =
-        normalize_metric_value(statement_type, metric_val).map_err(|_| {
-            format!(
-                "unknown metric '{}'; try one of the slash choices for this statement",
-                metric_val
-            )
-        })?;
-    if metric_corrected {
-        corrections.push(format!("metric→{}", metric.slash_value));
+    let (metric, metric_guessed) = resolve_metric_value(statement_type, metric_val)?;
+    if metric_guessed {
+        corrections.push(format!("metric→{}", metric.slash_value()));
     }
 
     let (freq, freq_corrected) = normalize_freq(freq_val);
@@ -267,31 +227,37 @@ pub async fn handle_text(
         Frequency::Quarterly => quarter_num,
     };
 
+    // A fiscal year can start in the previous calendar year
     let years_back = year
         .map(|y| {
             let current_year = Utc::now().year();
-            (current_year - y + 1).max(FETCH_YEARS_DEFAULT as i32) as i64
+            (current_year - y + 2).max(FETCH_YEARS_DEFAULT as i32) as i64
         })
         .unwrap_or(FETCH_YEARS_DEFAULT);
 
-    let raw = finance
-        .get_fundamentals_raw(ticker, statement_type, freq, years_back)
-        .await
-        .map_err(|e| format!("fetch error: {e}"))?;
+    let (raw, fiscal) = tokio::join!(
+        finance.get_fundamentals_raw(ticker, statement_type, freq, years_back),
+        finance.get_fiscal_calendar(ticker),
+    );
+    let raw = raw.map_err(|e| format!("fetch error: {e}"))?;
 
     let statements = reshape_timeseries_to_financial_statements(&raw);
     let selected = select_metric(
         &statements,
         statement_type,
         freq,
-        &metric.field_key,
+        metric.field,
+        fiscal,
         year,
         quarter_num,
     )
     .ok_or_else(|| "no matching data for the requested filters".to_string())?;
+    let export = match (export, find_statement(&statements, statement_type, freq)) {
+        (Some(format), Some(stmt)) => Some(export_statement(stmt, statement_type, freq, format)?),
+        _ => None,
+    };
 
     let (date, display) = selected;
-    let quarter_text = quarter.map(|q| format!("{q} ")).unwrap_or_default();
 
     let freq_label = match freq {
         Frequency::Annual => "annual",
@@ -299,20 +265,34 @@ pub async fn handle_text(
     };
 
     let response = format!(
-        "{} ({}) for {} {}on {}: {}",
+        "{} ({}) for {} {}: {}",
         metric.label,
         freq_label,
         ticker.to_uppercase(),
-        quarter_text,
-        date,
+        fiscal.period(date).label(freq),
         display
     );
 
-    if corrections.is_empty() {
-        Ok(response)
+    let content = if corrections.is_empty() {
+        response
     } else {
-        Ok(format!("{} (adjusted: {})", response, corrections.join(", ")))
-    }
+        format!("{} (adjusted: {})", response, corrections.join(", "))
+    };
+    Ok(FundamentalsResponse { content, export })
+}
+
+fn find_statement(
+    statements: &[FinancialStatement],
+    statement_type: StatementType,
+    frequency: Frequency,
+) -> Option<&FinancialStatement> {
+    let freq_str = match frequency {
+        Frequency::Annual => "annual",
+        Frequency::Quarterly => "quarterly",
+    };
+    statements
+        .iter()
+        .find(|s| s.statement_type == statement_type.as_str() && s.frequency == freq_str)
 }
 
 pub async fn render_statement_image(
@@ -322,7 +302,8 @@ pub async fn render_statement_image(
     freq_val: &str,
     year: Option<i32>,
     quarter: Option<&str>,
-) -> Result<(String, Vec<u8>), String> {
+    export: Option<ExportFormat>,
+) -> Result<(String, Vec<u8>, Option<StatementExport>), String> {
     let (freq, _) = normalize_freq(freq_val);
 
     let quarter_num = quarter.and_then(|q| match q {
@@ -337,35 +318,43 @@ pub async fn render_statement_image(
         Frequency::Quarterly => quarter_num,
     };
 
+    // Reach far enough back to fill the earlier period columns; a fiscal year can start
+    // in the previous calendar year
     let years_back = year
         .map(|y| {
             let current_year = Utc::now().year();
-            (current_year - y + 1).max(FETCH_YEARS_DEFAULT as i32) as i64
+            (current_year - y + 1 + STATEMENT_PERIODS as i32).max(FETCH_YEARS_DEFAULT as i32) as i64
         })
         .unwrap_or(FETCH_YEARS_DEFAULT);
 
-    let raw = finance
-        .get_fundamentals_raw(ticker, statement_type, freq, years_back)
-        .await
-        .map_err(|e| format!("fetch error: {e}"))?;
+    let (raw, fiscal) = tokio::join!(
+        finance.get_fundamentals_raw(ticker, statement_type, freq, years_back),
+        finance.get_fiscal_calendar(ticker),
+    );
+    let raw = raw.map_err(|e| format!("fetch error: {e}"))?;
 
     let statements = reshape_timeseries_to_financial_statements(&raw);
-    let (date, rows) = select_statement_rows(
-        &statements,
-        statement_type,
-        freq,
-        year,
-        quarter_num,
-    )
-    .ok_or_else(|| "no matching data for the requested filters".to_string())?;
-
     let freq_label = match freq {
         Frequency::Annual => "annual",
         Frequency::Quarterly => "quarterly",
     };
+    let stmt = find_statement(&statements, statement_type, freq)
+        .ok_or_else(|| "no matching data for the requested filters".to_string())?;
+
+    // With a year/quarter filter the table ends at the latest matching period
+    let latest = if year.is_some() || quarter_num.is_some() {
+        Some(
+            select_period(stmt, fiscal, year, quarter_num)
+                .ok_or_else(|| "no matching data for the requested filters".to_string())?,
+        )
+    } else {
+        None
+    };
+    let table = build_statement_table(stmt, statement_type, freq, latest, STATEMENT_PERIODS)
+        .ok_or_else(|| "no matching data for the requested filters".to_string())?;
 
     let title = format!(
-        "{} ({}) for {} on {}",
+        "{} ({}) for {} through {}",
         match statement_type {
             StatementType::IncomeStatement => "Income Statement",
             StatementType::BalanceSheet => "Balance Sheet",
@@ -373,105 +362,164 @@ pub async fn render_statement_image(
         },
         freq_label,
         ticker.to_uppercase(),
-        date
+        table
+            .periods
+            .last()
+            .map(|d| fiscal.period(*d).label(freq))
+            .unwrap_or_default()
     );
 
-    let image = render_rows_image(&title, &rows)?;
-    Ok((title, image))
+    let image = render_table_image(&title, &table, fiscal)?;
+    let export = export
+        .map(|format| export_statement(stmt, statement_type, freq, format))
+        .transpose()?;
+    Ok((title, image, export))
 }
 
-fn select_statement_rows(
-    statements: &[crate::models::FinancialStatement],
-    statement_type: StatementType,
-    frequency: Frequency,
+/// Latest period end whose fiscal year and quarter (per `fiscal`) match the filters.
+fn select_period(
+    stmt: &FinancialStatement,
+    fiscal: FiscalCalendar,
     year: Option<i32>,
     quarter: Option<u32>,
-) -> Option<(String, Vec<(String, String)>)> {
-    let freq_str = match frequency {
-        Frequency::Annual => "annual",
-        Frequency::Quarterly => "quarterly",
-    };
-
-    let stmt = statements
-        .iter()
-        .find(|s| s.statement_type == statement_type.as_str() && s.frequency == freq_str)?;
-
-    // Choose the best date entry based on year/quarter filters
-    let mut best: Option<(NaiveDate, String)> = None;
-    for (date_str, _val) in stmt.statement.values().next()?.iter() {
-        if let Ok(nd) = NaiveDate::parse_from_str(date_str, "%Y-%m-%d") {
-            if let Some(y) = year {
-                if nd.year() != y {
-                    continue;
-                }
-            }
-            if let Some(q) = quarter {
-                let month = nd.month();
-                let q_calc = ((month - 1) / 3) + 1;
-                if q_calc != q {
-                    continue;
-                }
-            }
-            match &best {
-                Some((best_date, _)) if nd <= *best_date => {}
-                _ => best = Some((nd, date_str.clone())),
-            }
-        }
-    }
-
-    let (_, best_date) = best?;
-
-    // Build rows for the selected date
-    let mut rows = Vec::new();
-    for (metric, series) in stmt.statement.iter() {
-        if let Some(val) = series.get(&best_date) {
-            let display = extract_display(val);
-            rows.push((metric.clone(), display));
-        }
-    }
-
-    // Sort rows alphabetically for consistency and cap to avoid huge images
-    rows.sort_by(|a, b| a.0.cmp(&b.0));
-    rows.truncate(40);
-
-    Some((best_date, rows))
+) -> Option<NaiveDate> {
+    stmt.statement
+        .values()
+        .flat_map(|series| series.keys())
+        .filter_map(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+        .filter(|d| fiscal.matches(*d, year, quarter))
+        .max()
 }
 
-fn render_rows_image(title: &str, rows: &[(String, String)]) -> Result<Vec<u8>, String> {
+const TABLE_MARGIN: u32 = 24;
+const TABLE_ROW_H: u32 = 28;
+const LABEL_COL_W: u32 = 360;
+const PERIOD_COL_W: u32 = 130;
+const CHANGE_COL_W: u32 = 100;
+const INDENT_W: u32 = 18;
+
+/// Draw a statement as line items × periods with change columns for the latest period:
+/// YoY for annual tables, QoQ and YoY for quarterly ones.
+fn render_table_image(
+    title: &str,
+    table: &StatementTable,
+    fiscal: FiscalCalendar,
+) -> Result<Vec<u8>, String> {
     let font = load_font()?;
-    let header_scale = PxScale::from(28.0);
-    let row_scale = PxScale::from(20.0);
-
-    let margin = 24;
-    let line_h = 30;
-    let width = 1200;
-    let height = margin * 2 + 50 + rows.len() as u32 * line_h;
+    let title_scale = PxScale::from(26.0);
+    let scale = PxScale::from(17.0);
 
+    let change_headers: &[&str] = match table.frequency {
+        Frequency::Annual => &["YoY"],
+        Frequency::Quarterly => &["QoQ", "YoY"],
+    };
+    let columns_w = LABEL_COL_W
+        + table.periods.len() as u32 * PERIOD_COL_W
+        + change_headers.len() as u32 * CHANGE_COL_W;
+    let width = TABLE_MARGIN * 2 + columns_w.max(text_size(title_scale, &font, title).0);
+    // Two header lines: fiscal period over its end date
+    let header_y = TABLE_MARGIN + 64;
+    let body_y = header_y + TABLE_ROW_H * 2 + 8;
+    let height = body_y + table.rows.len() as u32 * TABLE_ROW_H + TABLE_MARGIN;
+
+    let text = Rgba([40, 40, 40, 255]);
+    let muted = Rgba([110, 110, 110, 255]);
+    let up = Rgba([22, 130, 60, 255]);
+    let down = Rgba([200, 40, 40, 255]);
     let mut img = RgbaImage::from_pixel(width, height, Rgba([255, 255, 255, 255]));
 
+    let margin = TABLE_MARGIN as i32;
+    draw_text_mut(&mut img, text, margin, margin, title_scale, &font, title);
+    let subtitle = format!(
+        "{} · K / M / B / T abbreviations · EPS per share",
+        table.currency.as_deref().unwrap_or("Values")
+    );
     draw_text_mut(
         &mut img,
-        Rgba([40, 40, 40, 255]),
-        margin as i32,
-        margin as i32,
-        header_scale,
+        muted,
+        margin,
+        margin + 34,
+        scale,
         &font,
-        title,
+        &subtitle,
     );
 
-    let mut y = margin + 50;
-    for (metric, value) in rows {
-        let line = format!("{}: {}", to_title(metric), value);
+    // Column right edges: one per period, then the change columns, flush right when a
+    // long title widens the image
+    let mut right_edges = Vec::new();
+    let mut x = width - TABLE_MARGIN - (columns_w - LABEL_COL_W);
+    for _ in &table.periods {
+        x += PERIOD_COL_W;
+        right_edges.push(x);
+    }
+    for _ in change_headers {
+        x += CHANGE_COL_W;
+        right_edges.push(x);
+    }
+
+    let date_y = header_y + TABLE_ROW_H - 4;
+    for (date, right) in table.periods.iter().zip(&right_edges) {
+        let period = fiscal.period(*date).short_label(table.frequency);
+        draw_right(&mut img, &period, *right, header_y, scale, &font, text);
+        let end = date.format("%Y-%m-%d").to_string();
+        draw_right(&mut img, &end, *right, date_y, scale, &font, muted);
+    }
+    for (header, right) in change_headers
+        .iter()
+        .zip(&right_edges[table.periods.len()..])
+    {
+        draw_right(&mut img, header, *right, date_y, scale, &font, muted);
+    }
+    draw_filled_rect_mut(
+        &mut img,
+        Rect::at(margin, (body_y - 6) as i32).of_size(width - TABLE_MARGIN * 2, 2),
+        Rgba([200, 200, 200, 255]),
+    );
+
+    for (idx, row) in table.rows.iter().enumerate() {
+        let y = body_y + idx as u32 * TABLE_ROW_H;
+        if row.total {
+            draw_filled_rect_mut(
+                &mut img,
+                Rect::at(margin, y as i32 - 4).of_size(width - TABLE_MARGIN * 2, TABLE_ROW_H),
+                Rgba([238, 242, 247, 255]),
+            );
+        }
+        let color = if row.total {
+            text
+        } else {
+            Rgba([70, 70, 70, 255])
+        };
+        let label_x = TABLE_MARGIN + row.depth as u32 * INDENT_W;
         draw_text_mut(
             &mut img,
-            Rgba([60, 60, 60, 255]),
-            margin as i32,
+            color,
+            label_x as i32,
             y as i32,
-            row_scale,
+            scale,
             &font,
-            &line,
+            &row.label,
         );
-        y += line_h;
+
+        for (value, right) in row.values.iter().zip(&right_edges) {
+            let cell = value
+                .map(|v| format_value(v, row.kind))
+                .unwrap_or_else(|| "—".to_string());
+            draw_right(&mut img, &cell, *right, y, scale, &font, color);
+        }
+
+        let changes = match table.frequency {
+            Frequency::Annual => vec![row.change_pct],
+            Frequency::Quarterly => vec![row.change_pct, row.yoy_pct],
+        };
+        for (change, right) in changes.iter().zip(&right_edges[table.periods.len()..]) {
+            let (cell, color) = match change {
+                Some(pct) if *pct >= 0.0 => (format!("+{:.1}%", pct), up),
+                Some(pct) => (format!("{:.1}%", pct), down),
+                None => ("—".to_string(), muted),
+            };
+            draw_right(&mut img, &cell, *right, y, scale, &font, color);
+        }
     }
 
     let mut buffer = Vec::new();
@@ -482,6 +530,21 @@ fn render_rows_image(title: &str, rows: &[(String, String)]) -> Result<Vec<u8>,
     Ok(buffer)
 }
 
+/// Draw `text` so it ends 8px left of `right`.
+fn draw_right(
+    img: &mut RgbaImage,
+    text: &str,
+    right: u32,
+    y: u32,
+    scale: PxScale,
+    font: &FontArc,
+    color: Rgba<u8>,
+) {
+    let (w, _) = text_size(scale, font, text);
+    let x = right.saturating_sub(w + 8);
+    draw_text_mut(img, color, x as i32, y as i32, scale, font, text);
+}
+
 fn load_font() -> Result<FontArc, String> {
     let source = SystemSource::new();
 
@@ -505,51 +568,26 @@ fn load_font() -> Result<FontArc, String> {
 }
 
 fn select_metric(
-    statements: &[crate::models::FinancialStatement],
+    statements: &[FinancialStatement],
     statement_type: StatementType,
     frequency: Frequency,
     metric: &str,
+    fiscal: FiscalCalendar,
     year: Option<i32>,
     quarter: Option<u32>,
-) -> Option<(String, String)> {
-    let freq_str = match frequency {
-        Frequency::Annual => "annual",
-        Frequency::Quarterly => "quarterly",
-    };
-
-    let stmt = statements
-        .iter()
-        .find(|s| s.statement_type == statement_type.as_str() && s.frequency == freq_str)?;
-
+) -> Option<(NaiveDate, String)> {
+    let stmt = find_statement(statements, statement_type, frequency)?;
     let metric_map = stmt.statement.get(metric)?;
 
-    let mut best: Option<(NaiveDate, String, String)> = None;
-
-    for (date_str, val) in metric_map {
-        if let Ok(nd) = NaiveDate::parse_from_str(date_str, "%Y-%m-%d") {
-            if let Some(y) = year {
-                if nd.year() != y {
-                    continue;
-                }
-            }
-            if let Some(q) = quarter {
-                let month = nd.month();
-                let q_calc = ((month - 1) / 3) + 1;
-                if q_calc != q {
-                    continue;
-                }
-            }
-
-            let display = extract_display(val);
-
-            match &best {
-                Some((best_date, _, _)) if nd <= *best_date => {}
-                _ => best = Some((nd, display, date_str.clone())),
-            }
-        }
-    }
-
-    best.map(|(_, display, date)| (date, display))
+    metric_map
+        .iter()
+        .filter_map(|(date_str, val)| {
+            let date = NaiveDate::parse_from_str(date_str, "%Y-%m-%d").ok()?;
+            fiscal
+                .matches(date, year, quarter)
+                .then(|| (date, extract_display(val)))
+        })
+        .max_by_key(|(date, _)| *date)
 }
 
 fn extract_display(val: &serde_json::Value) -> String {
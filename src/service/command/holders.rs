@@ -1,14 +1,137 @@
+use std::io::Cursor;
+use std::ops::Range;
+
+use ab_glyph::{FontArc, PxScale};
+use image::{ImageFormat, Rgba, RgbaImage};
+use imageproc::drawing::{draw_filled_rect_mut, draw_text_mut, text_size};
+use imageproc::rect::Rect;
 use serenity::all::{
-    CommandDataOptionValue, CommandInteraction, CommandOptionType, CreateCommand,
-    CreateCommandOption,
+    ButtonStyle, CommandDataOptionValue, CommandInteraction, CommandOptionType, CreateActionRow,
+    CreateButton, CreateCommand, CreateCommandOption,
 };
+use tracing::warn;
 
 use crate::models::{
     HolderType, InsiderPurchase, InsiderRosterMember, InsiderTransaction, InstitutionalHolder,
-    MutualFundHolder,
+    MajorHoldersBreakdown, MutualFundHolder,
 };
+use crate::service::automation::earnings::weekly_report::load_font;
+use crate::service::finance::holders::TradeSide;
 use crate::service::finance::FinanceService;
 
+/// `custom_id` prefix of the holders page buttons.
+pub const HOLDERS_PAGE_PREFIX: &str = "holders";
+
+const DEFAULT_PAGE_SIZE: usize = 10;
+const MAX_PAGE_SIZE: usize = 20;
+/// Text listings (roster, image fallbacks) stay under Discord's 2000-character limit.
+const TEXT_ROW_LIMIT: usize = 10;
+
+const WIDTH: u32 = 960;
+const MARGIN: u32 = 24;
+const HEADER_H: u32 = 92;
+const ROW_H: u32 = 38;
+const FOOTER_H: u32 = 44;
+
+const BG: Rgba<u8> = Rgba([255, 255, 255, 255]);
+const HEADER_BG: Rgba<u8> = Rgba([243, 246, 250, 255]);
+const STRIPE_BG: Rgba<u8> = Rgba([249, 250, 252, 255]);
+const BUY_BG: Rgba<u8> = Rgba([222, 244, 229, 255]);
+const SELL_BG: Rgba<u8> = Rgba([251, 227, 227, 255]);
+const BAR: Rgba<u8> = Rgba([66, 133, 244, 255]);
+const UP: Rgba<u8> = Rgba([30, 142, 62, 255]);
+const DOWN: Rgba<u8> = Rgba([200, 40, 40, 255]);
+const TEXT: Rgba<u8> = Rgba([40, 40, 40, 255]);
+const MUTED: Rgba<u8> = Rgba([110, 110, 110, 255]);
+/// Insiders, institutions, public & other.
+const SLICE_COLORS: [Rgba<u8>; 3] = [
+    Rgba([251, 140, 0, 255]),
+    Rgba([66, 133, 244, 255]),
+    Rgba([189, 189, 189, 255]),
+];
+
+/// Response payload for the /holders command.
+pub struct HoldersResponse {
+    pub content: String,
+    pub image: Option<Vec<u8>>,
+    /// Page buttons when the rows do not fit on one image.
+    pub components: Vec<CreateActionRow>,
+}
+
+impl HoldersResponse {
+    fn text(content: String) -> Self {
+        Self {
+            content,
+            image: None,
+            components: Vec::new(),
+        }
+    }
+
+    /// Add Prev/Next buttons when there is more than one page.
+    fn paged(mut self, request: &HoldersPage, page: usize, total: usize) -> Self {
+        if total > 1 {
+            let to = |page: usize| {
+                HoldersPage {
+                    page,
+                    ..request.clone()
+                }
+                .to_custom_id()
+            };
+            self.components = vec![CreateActionRow::Buttons(vec![
+                CreateButton::new(to(page.saturating_sub(1)))
+                    .label("◀ Prev")
+                    .style(ButtonStyle::Secondary)
+                    .disabled(page == 0),
+                CreateButton::new(to(page + 1))
+                    .label("Next ▶")
+                    .style(ButtonStyle::Secondary)
+                    .disabled(page + 1 >= total),
+            ])];
+        }
+        self
+    }
+}
+
+/// One page of a holders listing, round-tripped through the page buttons.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HoldersPage {
+    pub symbol: String,
+    pub holder_type: HolderType,
+    pub page: usize,
+    pub page_size: usize,
+}
+
+impl HoldersPage {
+    /// `holders:{type}:{page}:{page_size}:{symbol}`
+    pub fn to_custom_id(&self) -> String {
+        format!(
+            "{}:{}:{}:{}:{}",
+            HOLDERS_PAGE_PREFIX,
+            self.holder_type.as_str(),
+            self.page,
+            self.page_size,
+            self.symbol
+        )
+    }
+
+    pub fn parse_custom_id(id: &str) -> Option<Self> {
+        let mut parts = id.splitn(5, ':');
+        if parts.next()? != HOLDERS_PAGE_PREFIX {
+            return None;
+        }
+        let holder_type = parse_holder_type(parts.next()?)?;
+        let page = parts.next()?.parse().ok()?;
+        let page_size = parts.next()?.parse::<usize>().ok()?;
+        let symbol = parts.next().filter(|s| !s.is_empty())?.to_string();
+        Some(Self {
+            symbol,
+            holder_type,
+            page,
+            page_size: page_size.clamp(1, MAX_PAGE_SIZE),
+        })
+    }
+}
+
 pub fn register_command() -> CreateCommand {
     CreateCommand::new("holders")
         .description("Show holders information for a ticker")
@@ -34,17 +157,17 @@ pub fn register_command() -> CreateCommand {
             CreateCommandOption::new(
                 CommandOptionType::Integer,
                 "limit",
-                "Rows to show (default 5)",
+                "Rows per page (default 10)",
             )
             .min_int_value(1)
-            .max_int_value(10),
+            .max_int_value(MAX_PAGE_SIZE as u64),
         )
 }
 
 pub async fn handle(
     command: &CommandInteraction,
     finance: &FinanceService,
-) -> Result<String, String> {
+) -> Result<HoldersResponse, String> {
     let ticker = get_str_opt(command, "ticker").ok_or("ticker is required")?;
     let holder_type_raw = get_str_opt(command, "type").ok_or("type is required")?;
     let limit = get_int_opt(command, "limit").map(|v| v as usize);
@@ -56,58 +179,177 @@ pub async fn handle_text(
     ticker: &str,
     holder_type_raw: &str,
     limit: Option<usize>,
-) -> Result<String, String> {
-    let holder_type = match holder_type_raw {
-        "major" => HolderType::Major,
-        "institutional" => HolderType::Institutional,
-        "mutualfund" => HolderType::MutualFund,
-        "insider_transactions" => HolderType::InsiderTransactions,
-        "insider_purchases" => HolderType::InsiderPurchases,
-        "insider_roster" => HolderType::InsiderRoster,
-        _ => {
-            return Err(
-                "type must be major | institutional | mutualfund | insider_transactions | insider_purchases | insider_roster"
-                    .into(),
-            )
-        }
+) -> Result<HoldersResponse, String> {
+    let holder_type = parse_holder_type(holder_type_raw).ok_or(
+        "type must be major | institutional | mutualfund | insider_transactions | insider_purchases | insider_roster",
+    )?;
+    let page = HoldersPage {
+        symbol: ticker.trim().to_ascii_uppercase(),
+        holder_type,
+        page: 0,
+        page_size: limit.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE),
     };
-    let limit = limit.unwrap_or(5).clamp(1, 10) as usize;
+    build_holders(finance, &page).await
+}
+
+/// A page button was pressed: rebuild that page.
+pub async fn handle_page(
+    custom_id: &str,
+    finance: &FinanceService,
+) -> Result<HoldersResponse, String> {
+    let page = HoldersPage::parse_custom_id(custom_id)
+        .ok_or("this holders page is no longer valid; run /holders again")?;
+    build_holders(finance, &page).await
+}
 
+async fn build_holders(
+    finance: &FinanceService,
+    request: &HoldersPage,
+) -> Result<HoldersResponse, String> {
     let data = finance
-        .get_holders(ticker, holder_type)
+        .get_holders(&request.symbol, request.holder_type)
         .await
         .map_err(|e| format!("fetch error: {e}"))?;
+    let symbol = data.symbol.clone();
+    let text_limit = request.page_size.min(TEXT_ROW_LIMIT);
 
-    match holder_type {
-        HolderType::Major => format_major(&data).ok_or_else(|| "no major holders found".into()),
-        HolderType::Institutional => format_table(
-            &data.institutional_holders.unwrap_or_default(),
-            limit,
-            "Institutional holders",
-            &data.symbol,
-        ),
-        HolderType::MutualFund => format_table(
-            &data.mutualfund_holders.unwrap_or_default(),
-            limit,
-            "Mutual fund holders",
-            &data.symbol,
-        ),
-        HolderType::InsiderTransactions => format_transactions(
-            &data.insider_transactions.unwrap_or_default(),
-            limit,
-            &data.symbol,
-        ),
-        HolderType::InsiderPurchases => {
-            format_purchases(data.insider_purchases.as_ref(), &data.symbol)
+    match request.holder_type {
+        HolderType::Major => {
+            let text = format_major(&data).ok_or("no major holders found")?;
+            let breakdown = data
+                .major_breakdown
+                .as_ref()
+                .ok_or("no major holders found")?;
+            Ok(with_image(
+                format!("Major holders for {}", symbol),
+                text,
+                render_major_image(&symbol, breakdown),
+            ))
         }
-        HolderType::InsiderRoster => format_roster(
+        HolderType::Institutional | HolderType::MutualFund => {
+            let (heading, rows) = if request.holder_type == HolderType::Institutional {
+                let rows = data.institutional_holders.unwrap_or_default();
+                (
+                    "Institutional holders",
+                    rows.iter().map(holder_bar).collect(),
+                )
+            } else {
+                let rows = data.mutualfund_holders.unwrap_or_default();
+                (
+                    "Mutual fund holders",
+                    rows.iter().map(holder_bar).collect::<Vec<_>>(),
+                )
+            };
+            if rows.is_empty() {
+                return Err(format!("no {} found", heading.to_lowercase()));
+            }
+            let mut rows = rows;
+            rows.sort_by_key(|r| -r.shares);
+            for row in &mut rows {
+                row.change = data.position_changes.get(&row.name).copied();
+            }
+
+            let (range, page, total) = paginate(rows.len(), request.page, request.page_size);
+            let caption = page_caption(heading, &symbol, &range, rows.len());
+            let text = format_bars(&rows[range.clone()], heading, &symbol);
+            let max_pct = rows
+                .iter()
+                .filter_map(|r| r.percent_out)
+                .fold(0.0, f64::max);
+            let image = render_bars_image(heading, &symbol, &rows[range], max_pct, page, total);
+            Ok(with_image(caption, text, image).paged(request, page, total))
+        }
+        HolderType::InsiderTransactions => {
+            let txs = data.insider_transactions.unwrap_or_default();
+            if txs.is_empty() {
+                return Err("no insider transactions found".into());
+            }
+            let (range, page, total) = paginate(txs.len(), request.page, request.page_size);
+            let heading = "Insider transactions";
+            let caption = page_caption(heading, &symbol, &range, txs.len());
+            let text = format_transactions(&txs[range.clone()], text_limit, &symbol)?;
+            let image = render_transactions_image(&symbol, &txs[range], page, total);
+            Ok(with_image(caption, text, image).paged(request, page, total))
+        }
+        HolderType::InsiderPurchases => Ok(HoldersResponse::text(format_purchases(
+            data.insider_purchases.as_ref(),
+            &symbol,
+        )?)),
+        HolderType::InsiderRoster => Ok(HoldersResponse::text(format_roster(
             &data.insider_roster.unwrap_or_default(),
-            limit,
-            &data.symbol,
-        ),
+            text_limit,
+            &symbol,
+        )?)),
     }
 }
 
+/// Caption and image, or the text listing when the image could not be rendered.
+fn with_image(caption: String, text: String, image: Result<Vec<u8>, String>) -> HoldersResponse {
+    match image {
+        Ok(bytes) => HoldersResponse {
+            content: caption,
+            image: Some(bytes),
+            components: Vec::new(),
+        },
+        Err(err) => {
+            warn!("holders render failed: {}", err);
+            HoldersResponse::text(text)
+        }
+    }
+}
+
+pub fn parse_holder_type(raw: &str) -> Option<HolderType> {
+    match raw.trim().to_ascii_lowercase().as_str() {
+        "major" => Some(HolderType::Major),
+        "institutional" => Some(HolderType::Institutional),
+        "mutualfund" => Some(HolderType::MutualFund),
+        "insider_transactions" => Some(HolderType::InsiderTransactions),
+        "insider_purchases" => Some(HolderType::InsiderPurchases),
+        "insider_roster" => Some(HolderType::InsiderRoster),
+        _ => None,
+    }
+}
+
+/// Row range shown on `page` (clamped to the last page), that page, and the page count.
+pub fn paginate(rows: usize, page: usize, page_size: usize) -> (Range<usize>, usize, usize) {
+    let page_size = page_size.max(1);
+    let total = rows.div_ceil(page_size).max(1);
+    let page = page.min(total - 1);
+    let start = page * page_size;
+    (start..(start + page_size).min(rows), page, total)
+}
+
+fn page_caption(heading: &str, symbol: &str, range: &Range<usize>, rows: usize) -> String {
+    format!(
+        "{} for {} · {}–{} of {}",
+        heading,
+        symbol,
+        range.start + 1,
+        range.end,
+        rows
+    )
+}
+
+/// Insiders, institutions and everyone else as fractions of shares outstanding.
+pub fn major_slices(breakdown: &MajorHoldersBreakdown) -> Vec<(&'static str, f64)> {
+    let insiders = breakdown_value(breakdown, "insidersPercentHeld")
+        .unwrap_or(0.0)
+        .clamp(0.0, 1.0);
+    let institutions = breakdown_value(breakdown, "institutionsPercentHeld")
+        .unwrap_or(0.0)
+        .clamp(0.0, 1.0 - insiders);
+    vec![
+        ("Insiders", insiders),
+        ("Institutions", institutions),
+        ("Public & other", 1.0 - insiders - institutions),
+    ]
+}
+
+fn breakdown_value(breakdown: &MajorHoldersBreakdown, key: &str) -> Option<f64> {
+    let v = breakdown.breakdown_data.get(key)?;
+    v.get("raw").and_then(|r| r.as_f64()).or_else(|| v.as_f64())
+}
+
 fn format_major(data: &crate::models::HoldersOverview) -> Option<String> {
     let breakdown = data.major_breakdown.as_ref()?;
     let mut parts = Vec::new();
@@ -135,32 +377,44 @@ fn format_major(data: &crate::models::HoldersOverview) -> Option<String> {
     }
 }
 
-fn format_table<T>(rows: &[T], limit: usize, heading: &str, symbol: &str) -> Result<String, String>
-where
-    T: HolderRow,
-{
-    if rows.is_empty() {
-        return Err(format!("no {} found", heading.to_lowercase()));
-    }
-
-    let mut rows_sorted: Vec<&T> = rows.iter().collect();
-    rows_sorted.sort_by_key(|r| -r.shares());
-
+fn format_bars(rows: &[HolderBar], heading: &str, symbol: &str) -> String {
     let mut lines = Vec::new();
     lines.push(format!("{} for {}", heading, symbol));
-    for row in rows_sorted.iter().take(limit) {
+    for row in rows {
         lines.push(format!(
-            "{} — shares: {}, %out: {}, reported: {}",
-            row.name(),
-            format_shares(row.shares()),
-            row.percent_out()
+            "{} — shares: {}, %out: {}, change: {}, reported: {}",
+            row.name,
+            format_shares(row.shares),
+            row.percent_out
                 .map(format_percent)
                 .unwrap_or_else(|| "n/a".to_string()),
-            row.date_reported()
+            row.change
+                .map(format_change)
+                .unwrap_or_else(|| "n/a".to_string()),
+            row.date_reported
         ));
     }
+    lines.join("\n")
+}
 
-    Ok(lines.join("\n"))
+/// One institution or fund on the holders bar chart.
+struct HolderBar {
+    name: String,
+    shares: i64,
+    percent_out: Option<f64>,
+    /// Position change since the previous filing, as a fraction.
+    change: Option<f64>,
+    date_reported: String,
+}
+
+fn holder_bar<T: HolderRow>(row: &T) -> HolderBar {
+    HolderBar {
+        name: row.name(),
+        shares: row.shares(),
+        percent_out: row.percent_out(),
+        change: None,
+        date_reported: row.date_reported(),
+    }
 }
 
 trait HolderRow {
@@ -326,3 +580,421 @@ fn format_roster(
     }
     Ok(lines.join("\n"))
 }
+
+/// Position change as a signed percentage, e.g. `+1.25%`.
+fn format_change(change: f64) -> String {
+    format!("{:+.2}%", change * 100.0)
+}
+
+fn render_major_image(symbol: &str, breakdown: &MajorHoldersBreakdown) -> Result<Vec<u8>, String> {
+    let font = load_font()?;
+    let slices = major_slices(breakdown);
+    let height = 420;
+    let mut img = RgbaImage::from_pixel(WIDTH, height, BG);
+    draw_header(
+        &mut img,
+        &font,
+        &format!("{} major holders", symbol),
+        "Share of shares outstanding",
+    );
+
+    // Donut: slices clockwise from 12 o'clock
+    let (cx, cy) = (MARGIN as f64 + 150.0, HEADER_H as f64 + 150.0);
+    let (outer, inner) = (140.0, 80.0);
+    for y in (cy - outer) as u32..=(cy + outer) as u32 {
+        for x in (cx - outer) as u32..=(cx + outer) as u32 {
+            let (dx, dy) = (x as f64 - cx, y as f64 - cy);
+            let distance = dx.hypot(dy);
+            if distance < inner || distance > outer {
+                continue;
+            }
+            let turn = dx.atan2(-dy).rem_euclid(std::f64::consts::TAU) / std::f64::consts::TAU;
+            let mut start = 0.0;
+            for ((_, fraction), color) in slices.iter().zip(SLICE_COLORS) {
+                if turn < start + fraction {
+                    img.put_pixel(x, y, color);
+                    break;
+                }
+                start += fraction;
+            }
+        }
+    }
+    let institutions = slices[1].1;
+    let centre = format!("{:.1}%", institutions * 100.0);
+    let scale = PxScale::from(28.0);
+    let (w, _) = text_size(scale, &font, &centre);
+    draw_text_mut(
+        &mut img,
+        TEXT,
+        (cx - w as f64 / 2.0) as i32,
+        cy as i32 - 22,
+        scale,
+        &font,
+        &centre,
+    );
+    let label = "institutions";
+    let scale = PxScale::from(15.0);
+    let (w, _) = text_size(scale, &font, label);
+    draw_text_mut(
+        &mut img,
+        MUTED,
+        (cx - w as f64 / 2.0) as i32,
+        cy as i32 + 10,
+        scale,
+        &font,
+        label,
+    );
+
+    // Legend
+    let legend_x = MARGIN + 340;
+    let mut y = HEADER_H + 60;
+    for ((name, fraction), color) in slices.iter().zip(SLICE_COLORS) {
+        draw_filled_rect_mut(
+            &mut img,
+            Rect::at(legend_x as i32, y as i32 + 4).of_size(18, 18),
+            color,
+        );
+        draw_text_mut(
+            &mut img,
+            TEXT,
+            legend_x as i32 + 30,
+            y as i32,
+            PxScale::from(22.0),
+            &font,
+            &format!("{}  {:.2}%", name, fraction * 100.0),
+        );
+        y += 40;
+    }
+
+    let mut notes = Vec::new();
+    if let Some(float) = breakdown_value(breakdown, "institutionsFloatPercentHeld") {
+        notes.push(format!(
+            "Institutions hold {:.2}% of the float",
+            float * 100.0
+        ));
+    }
+    if let Some(count) = breakdown_value(breakdown, "institutionsCount") {
+        notes.push(format!("{} institutions", count as i64));
+    }
+    for note in notes {
+        y += 8;
+        draw_text_mut(
+            &mut img,
+            MUTED,
+            legend_x as i32,
+            y as i32,
+            PxScale::from(17.0),
+            &font,
+            &note,
+        );
+        y += 26;
+    }
+
+    encode_png(img)
+}
+
+fn render_bars_image(
+    heading: &str,
+    symbol: &str,
+    rows: &[HolderBar],
+    max_pct: f64,
+    page: usize,
+    total: usize,
+) -> Result<Vec<u8>, String> {
+    let font = load_font()?;
+    let height = HEADER_H + ROW_H * (rows.len() as u32 + 1) + FOOTER_H;
+    let mut img = RgbaImage::from_pixel(WIDTH, height, BG);
+    let reported = rows
+        .iter()
+        .map(|r| r.date_reported.as_str())
+        .max()
+        .unwrap_or_default();
+    draw_header(
+        &mut img,
+        &font,
+        &format!("{} {}", symbol, heading.to_lowercase()),
+        &format!(
+            "Share of shares outstanding · reported through {}",
+            reported
+        ),
+    );
+
+    let name_w = 330;
+    let change_w = 110;
+    let bar_x = MARGIN + name_w;
+    let bar_max = WIDTH - MARGIN * 2 - name_w - change_w - 150;
+    let change_right = WIDTH - MARGIN;
+    let label_scale = PxScale::from(15.0);
+    let scale = PxScale::from(17.0);
+
+    let mut y = HEADER_H;
+    draw_filled_rect_mut(
+        &mut img,
+        Rect::at(MARGIN as i32, y as i32).of_size(WIDTH - MARGIN * 2, ROW_H),
+        HEADER_BG,
+    );
+    for (text, x) in [("Holder", MARGIN + 10), ("% out · shares", bar_x)] {
+        draw_text_mut(
+            &mut img,
+            MUTED,
+            x as i32,
+            y as i32 + 11,
+            label_scale,
+            &font,
+            text,
+        );
+    }
+    draw_right(
+        &mut img,
+        "Change",
+        change_right,
+        y + 11,
+        label_scale,
+        &font,
+        MUTED,
+    );
+
+    for (idx, row) in rows.iter().enumerate() {
+        y += ROW_H;
+        if idx % 2 == 1 {
+            draw_filled_rect_mut(
+                &mut img,
+                Rect::at(MARGIN as i32, y as i32).of_size(WIDTH - MARGIN * 2, ROW_H),
+                STRIPE_BG,
+            );
+        }
+        let name = fit_text(&row.name, name_w - 20, scale, &font);
+        draw_text_mut(
+            &mut img,
+            TEXT,
+            MARGIN as i32 + 10,
+            y as i32 + 10,
+            scale,
+            &font,
+            &name,
+        );
+
+        let pct = row.percent_out.unwrap_or(0.0);
+        let bar_w = if max_pct > 0.0 {
+            ((pct / max_pct) * bar_max as f64).round().max(2.0) as u32
+        } else {
+            2
+        };
+        draw_filled_rect_mut(
+            &mut img,
+            Rect::at(bar_x as i32, y as i32 + 9).of_size(bar_w, ROW_H - 18),
+            BAR,
+        );
+        let value = format!(
+            "{} · {}",
+            row.percent_out
+                .map(format_percent)
+                .unwrap_or_else(|| "n/a".into()),
+            format_shares(row.shares)
+        );
+        draw_text_mut(
+            &mut img,
+            TEXT,
+            (bar_x + bar_w + 8) as i32,
+            y as i32 + 10,
+            scale,
+            &font,
+            &value,
+        );
+
+        let (change, color) = match row.change {
+            Some(c) if c > 0.0 => (format_change(c), UP),
+            Some(c) if c < 0.0 => (format_change(c), DOWN),
+            Some(c) => (format_change(c), MUTED),
+            None => ("—".to_string(), MUTED),
+        };
+        draw_right(&mut img, &change, change_right, y + 10, scale, &font, color);
+    }
+
+    draw_footer(
+        &mut img,
+        &font,
+        page,
+        total,
+        "Change: position since the holder's previous filing",
+    );
+    encode_png(img)
+}
+
+fn render_transactions_image(
+    symbol: &str,
+    txs: &[InsiderTransaction],
+    page: usize,
+    total: usize,
+) -> Result<Vec<u8>, String> {
+    let font = load_font()?;
+    let height = HEADER_H + ROW_H * (txs.len() as u32 + 1) + FOOTER_H;
+    let mut img = RgbaImage::from_pixel(WIDTH, height, BG);
+    draw_header(
+        &mut img,
+        &font,
+        &format!("{} insider transactions", symbol),
+        "Most recent first",
+    );
+
+    // (title, left edge, width); shares and value are right-aligned to their edge
+    let columns: [(&str, u32, u32); 6] = [
+        ("Date", MARGIN + 10, 100),
+        ("Insider", MARGIN + 110, 200),
+        ("Relation", MARGIN + 320, 140),
+        ("Transaction", MARGIN + 470, 220),
+        ("Shares", MARGIN + 700, 100),
+        ("Value", MARGIN + 810, 100),
+    ];
+    let label_scale = PxScale::from(15.0);
+    let scale = PxScale::from(16.0);
+
+    let mut y = HEADER_H;
+    draw_filled_rect_mut(
+        &mut img,
+        Rect::at(MARGIN as i32, y as i32).of_size(WIDTH - MARGIN * 2, ROW_H),
+        HEADER_BG,
+    );
+    for (idx, (title, x, w)) in columns.iter().enumerate() {
+        if idx >= 4 {
+            draw_right(&mut img, title, x + w, y + 11, label_scale, &font, MUTED);
+        } else {
+            draw_text_mut(
+                &mut img,
+                MUTED,
+                *x as i32,
+                y as i32 + 11,
+                label_scale,
+                &font,
+                title,
+            );
+        }
+    }
+
+    for (idx, tx) in txs.iter().enumerate() {
+        y += ROW_H;
+        let fill = match TradeSide::of(tx) {
+            TradeSide::Buy => BUY_BG,
+            TradeSide::Sell => SELL_BG,
+            TradeSide::Other if idx % 2 == 1 => STRIPE_BG,
+            TradeSide::Other => BG,
+        };
+        draw_filled_rect_mut(
+            &mut img,
+            Rect::at(MARGIN as i32, y as i32).of_size(WIDTH - MARGIN * 2, ROW_H),
+            fill,
+        );
+
+        let transaction = if tx.transaction.trim().is_empty() {
+            "—".to_string()
+        } else {
+            tx.transaction
+                .trim()
+                .trim_end_matches('.')
+                .trim_end_matches(" per share")
+                .to_string()
+        };
+        let cells = [
+            tx.start_date.date_naive().format("%Y-%m-%d").to_string(),
+            tx.insider.clone(),
+            tx.position.clone(),
+            transaction,
+            tx.shares.map(format_shares).unwrap_or_else(|| "—".into()),
+            tx.value.map(format_currency).unwrap_or_else(|| "—".into()),
+        ];
+        for (idx, ((_, x, w), cell)) in columns.iter().zip(cells).enumerate() {
+            let cell = fit_text(&cell, w - 8, scale, &font);
+            if idx >= 4 {
+                draw_right(&mut img, &cell, x + w, y + 11, scale, &font, TEXT);
+            } else {
+                draw_text_mut(
+                    &mut img,
+                    TEXT,
+                    *x as i32,
+                    y as i32 + 11,
+                    scale,
+                    &font,
+                    &cell,
+                );
+            }
+        }
+    }
+
+    draw_footer(
+        &mut img,
+        &font,
+        page,
+        total,
+        "Green: purchases · red: sales",
+    );
+    encode_png(img)
+}
+
+fn draw_header(img: &mut RgbaImage, font: &FontArc, title: &str, subtitle: &str) {
+    draw_text_mut(
+        img,
+        TEXT,
+        MARGIN as i32,
+        MARGIN as i32 - 4,
+        PxScale::from(28.0),
+        font,
+        title,
+    );
+    draw_text_mut(
+        img,
+        MUTED,
+        MARGIN as i32,
+        MARGIN as i32 + 34,
+        PxScale::from(16.0),
+        font,
+        subtitle,
+    );
+}
+
+fn draw_footer(img: &mut RgbaImage, font: &FontArc, page: usize, total: usize, note: &str) {
+    let y = img.height() - FOOTER_H + 14;
+    let scale = PxScale::from(15.0);
+    draw_text_mut(img, MUTED, MARGIN as i32, y as i32, scale, font, note);
+    if total > 1 {
+        let pages = format!("Page {}/{}", page + 1, total);
+        draw_right(img, &pages, WIDTH - MARGIN, y, scale, font, MUTED);
+    }
+}
+
+fn draw_right(
+    img: &mut RgbaImage,
+    text: &str,
+    right: u32,
+    y: u32,
+    scale: PxScale,
+    font: &FontArc,
+    color: Rgba<u8>,
+) {
+    let (w, _) = text_size(scale, font, text);
+    let x = right.saturating_sub(w + 8);
+    draw_text_mut(img, color, x as i32, y as i32, scale, font, text);
+}
+
+/// Truncate `text` with an ellipsis so it renders within `max_w` pixels.
+fn fit_text(text: &str, max_w: u32, scale: PxScale, font: &FontArc) -> String {
+    if text_size(scale, font, text).0 <= max_w {
+        return text.to_string();
+    }
+    let mut chars: Vec<char> = text.chars().collect();
+    while !chars.is_empty() {
+        chars.pop();
+        let candidate = format!("{}…", chars.iter().collect::<String>().trim_end());
+        if text_size(scale, font, &candidate).0 <= max_w {
+            return candidate;
+        }
+    }
+    String::new()
+}
+
+fn encode_png(img: RgbaImage) -> Result<Vec<u8>, String> {
+    let mut buffer = Vec::new();
+    image::DynamicImage::ImageRgba8(img)
+        .write_to(&mut Cursor::new(&mut buffer), ImageFormat::Png)
+        .map_err(|e| format!("failed to encode png: {e}"))?;
+    Ok(buffer)
+}
@@ -0,0 +1,165 @@
+use chrono::Utc;
+use chrono_tz::America::New_York;
+use serenity::all::{
+    CommandDataOptionValue, CommandInteraction, CommandOptionType, CreateCommand,
+    CreateCommandOption,
+};
+use tracing::warn;
+
+use crate::service::automation::options_data::render_quickchart;
+use crate::service::caching::collections::iv_history;
+use crate::service::caching::RedisCache;
+use crate::service::finance::volatility::{interpolate_iv, AtmIvPoint, IvStats, IV_TARGET_DAYS};
+use crate::service::finance::FinanceService;
+
+const TERM_MAX_DAYS: i64 = 180;
+const TERM_MAX_POINTS: usize = 8;
+
+/// Response payload for the /iv command.
+pub struct IvResponse {
+    pub content: String,
+    pub image: Option<Vec<u8>>,
+}
+
+pub fn register_command() -> CreateCommand {
+    CreateCommand::new("iv")
+        .description("ATM implied volatility, IV rank/percentile and term structure")
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::String,
+                "ticker",
+                "Ticker symbol, e.g., AAPL",
+            )
+            .required(true),
+        )
+}
+
+pub async fn handle(
+    command: &CommandInteraction,
+    finance: &FinanceService,
+    cache: Option<&RedisCache>,
+) -> Result<IvResponse, String> {
+    let ticker = get_str_opt(command, "ticker").ok_or("ticker is required")?;
+    handle_text(finance, cache, ticker).await
+}
+
+pub async fn handle_text(
+    finance: &FinanceService,
+    cache: Option<&RedisCache>,
+    ticker: &str,
+) -> Result<IvResponse, String> {
+    let symbol = ticker.trim().to_ascii_uppercase();
+
+    let points = finance
+        .get_iv_term_structure(&symbol, TERM_MAX_DAYS, TERM_MAX_POINTS)
+        .await
+        .map_err(|e| format!("fetch error: {e}"))?;
+
+    let iv30 = interpolate_iv(&points, IV_TARGET_DAYS);
+
+    let stats = match (cache, iv30) {
+        (Some(cache), Some(iv)) => {
+            let today = Utc::now().with_timezone(&New_York).date_naive();
+            iv_history::stats(cache, &symbol, today, Some(iv))
+                .await
+                .unwrap_or_else(|e| {
+                    warn!("failed to load IV history for {}: {}", symbol, e);
+                    None
+                })
+        }
+        _ => None,
+    };
+
+    let mut parts = Vec::new();
+    parts.push(format!("{} implied volatility", symbol));
+    match iv30 {
+        Some(iv) => parts.push(format!("IV30: {:.1}%", iv * 100.0)),
+        None => parts.push("IV30: n/a".to_string()),
+    }
+    match stats.as_ref() {
+        Some(s) => parts.push(format_stats(s)),
+        None => parts.push("IV rank: n/a (no recorded history)".to_string()),
+    }
+
+    let mut content = parts.join(" | ");
+    content.push('\n');
+    content.push_str(
+        &points
+            .iter()
+            .map(|p| format!("{} ({}d) {:.1}%", p.expiration, p.days, p.iv * 100.0))
+            .collect::<Vec<_>>()
+            .join(" · "),
+    );
+
+    let image = match build_term_chart(&symbol, &points).await {
+        Ok(bytes) => Some(bytes),
+        Err(err) => {
+            warn!("IV term structure chart failed for {}: {}", symbol, err);
+            None
+        }
+    };
+
+    Ok(IvResponse { content, image })
+}
+
+/// IV rank, IV percentile and the 52-week IV range with its sample count, e.g.
+/// "IV rank 45 | IV pct 60 (52w 14.2%–38.9%, 212 days)". Also used by `/quote`.
+pub fn format_stats(stats: &IvStats) -> String {
+    format!(
+        "IV rank {:.0} | IV pct {:.0} (52w {:.1}%–{:.1}%, {} days)",
+        stats.rank,
+        stats.percentile,
+        stats.low * 100.0,
+        stats.high * 100.0,
+        stats.samples
+    )
+}
+
+async fn build_term_chart(symbol: &str, points: &[AtmIvPoint]) -> Result<Vec<u8>, String> {
+    let labels: Vec<String> = points
+        .iter()
+        .map(|p| format!("{} ({}d)", p.expiration.format("%b %e"), p.days))
+        .collect();
+    let data: Vec<f64> = points
+        .iter()
+        .map(|p| (p.iv * 1000.0).round() / 10.0)
+        .collect();
+
+    let chart = serde_json::json!({
+        "type": "line",
+        "data": {
+            "labels": labels,
+            "datasets": [{
+                "label": "ATM IV (%)",
+                "data": data,
+                "tension": 0.2,
+                "borderColor": "#2196f3",
+                "backgroundColor": "#2196f333",
+                "fill": true,
+            }]
+        },
+        "options": {
+            "plugins": {
+                "legend": { "display": false },
+                "title": { "display": true, "text": format!("{} IV term structure", symbol) }
+            },
+            "scales": {
+                "y": { "title": { "display": true, "text": "IV (%)" } }
+            }
+        }
+    });
+
+    render_quickchart(chart, 600, 300).await
+}
+
+fn get_str_opt<'a>(command: &'a CommandInteraction, name: &str) -> Option<&'a str> {
+    command
+        .data
+        .options
+        .iter()
+        .find(|o| o.name == name)
+        .and_then(|o| match o.value {
+            CommandDataOptionValue::String(ref s) => Some(s.as_str()),
+            _ => None,
+        })
+}
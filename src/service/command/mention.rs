@@ -1,21 +1,29 @@
-use serenity::all::{ChannelId, CreateAttachment, Http};
+use serenity::all::{ChannelId, CreateAttachment, Http, UserId};
 
 use crate::models::StatementType;
+use crate::service::automation;
+use crate::service::caching::RedisCache;
+use crate::service::finance::export::ExportFormat;
 use crate::service::finance::FinanceService;
 use crate::service::command::fundamentals::render_statement_image;
 
-use super::{earnings, holders, news, quotes};
+use super::{
+    compare, dcf, earnings, earnings_season, fundamental_chart, fundamentals, holders, iv, news,
+    options, quotes, ratios, strategy,
+};
 
 pub struct MentionResponse {
     pub content: String,
-    pub attachment: Option<CreateAttachment>,
+    pub attachments: Vec<CreateAttachment>,
 }
 
 pub async fn handle(
     text: &str,
     http: &Http,
     channel_id: ChannelId,
+    author: UserId,
     finance: &FinanceService,
+    cache: Option<&RedisCache>,
 ) -> Result<MentionResponse, String> {
     let mut parts = text.split_whitespace();
     let cmd = parts
@@ -26,10 +34,37 @@ pub async fn handle(
     match cmd.as_str() {
         "quote" => {
             let ticker = parts.next().ok_or("ticker required, e.g., quote AAPL")?;
-            let content = quotes::handle_text(finance, ticker).await?;
+            let content = quotes::handle_text(finance, cache, ticker).await?;
             Ok(MentionResponse {
                 content,
-                attachment: None,
+                attachments: Vec::new(),
+            })
+        }
+        "iv" => {
+            let ticker = parts.next().ok_or("ticker required, e.g., iv AAPL")?;
+            let resp = iv::handle_text(finance, cache, ticker).await?;
+            let attachment = resp
+                .image
+                .map(|bytes| CreateAttachment::bytes(bytes, "iv-term-structure.png"));
+            Ok(MentionResponse {
+                content: resp.content,
+                attachments: attachment.into_iter().collect(),
+            })
+        }
+        "strategy" => {
+            let position = parts.collect::<Vec<_>>().join(" ");
+            if position.is_empty() {
+                return Err(
+                    "position required, e.g., strategy SPY 600C -1, 605C +1 2026-10-23".into(),
+                );
+            }
+            let resp = strategy::handle_text(finance, &position, None).await?;
+            let attachment = resp
+                .image
+                .map(|bytes| CreateAttachment::bytes(bytes, "strategy-payoff.png"));
+            Ok(MentionResponse {
+                content: resp.content,
+                attachments: attachment.into_iter().collect(),
             })
         }
         "holders" => {
@@ -43,10 +78,13 @@ pub async fn handle(
                 .map(parse_usize)
                 .transpose()
                 .map_err(|e| format!("invalid limit: {e}"))?;
-            let content = holders::handle_text(finance, ticker, &holder_type, limit).await?;
+            let resp = holders::handle_text(finance, ticker, &holder_type, limit).await?;
+            let attachment = resp
+                .image
+                .map(|bytes| CreateAttachment::bytes(bytes, "holders.png"));
             Ok(MentionResponse {
-                content,
-                attachment: None,
+                content: resp.content,
+                attachments: attachment.into_iter().collect(),
             })
         }
         "news" => {
@@ -61,18 +99,36 @@ pub async fn handle(
             let content = news::handle_text(finance, ticker, limit).await?;
             Ok(MentionResponse {
                 content,
-                attachment: None,
+                attachments: Vec::new(),
             })
         }
         "income" | "balance" | "cashflow" => {
             let ticker = parts.next().ok_or("ticker required, e.g., income AAPL annual")?;
-            let freq = parts.next().ok_or("freq required: annual|quarterly")?;
-            let year = parts
+            // Words before the frequency name a single metric, e.g. `income AAPL net income annual`
+            let mut metric_words = Vec::new();
+            let freq = loop {
+                let token = parts.next().ok_or("freq required: annual|quarterly")?;
+                if matches!(token.to_ascii_lowercase().as_str(), "annual" | "quarterly") {
+                    break token;
+                }
+                metric_words.push(token);
+            };
+            // `csv`/`json` may appear anywhere after the frequency
+            let mut export = None;
+            let mut rest = Vec::new();
+            for token in parts {
+                match ExportFormat::parse(token) {
+                    Some(format) => export = Some(format),
+                    None => rest.push(token),
+                }
+            }
+            let mut rest = rest.into_iter();
+            let year = rest
                 .next()
                 .map(parse_i32)
                 .transpose()
                 .map_err(|e| format!("invalid year: {e}"))?;
-            let quarter = parts.next();
+            let quarter = rest.next();
 
             let statement_type = match cmd.as_str() {
                 "income" => StatementType::IncomeStatement,
@@ -81,49 +137,214 @@ pub async fn handle(
                 _ => unreachable!(),
             };
 
-            let (content, image) =
-                render_statement_image(finance, statement_type, ticker, freq, year, quarter).await?;
+            if !metric_words.is_empty() {
+                let metric = metric_words.join(" ");
+                let resp = fundamentals::handle_text(
+                    finance,
+                    statement_type,
+                    ticker,
+                    &metric,
+                    freq,
+                    year,
+                    quarter,
+                    export,
+                )
+                .await?;
+                return Ok(MentionResponse {
+                    content: resp.content,
+                    attachments: resp
+                        .export
+                        .map(|file| CreateAttachment::bytes(file.bytes, file.filename))
+                        .into_iter()
+                        .collect(),
+                });
+            }
+
+            let (content, image, export) = render_statement_image(
+                finance,
+                statement_type,
+                ticker,
+                freq,
+                year,
+                quarter,
+                export,
+            )
+            .await?;
 
-            let attachment = CreateAttachment::bytes(image, "fundamentals.png");
+            let mut attachments = vec![CreateAttachment::bytes(image, "fundamentals.png")];
+            if let Some(file) = export {
+                attachments.push(CreateAttachment::bytes(file.bytes, file.filename));
+            }
 
             Ok(MentionResponse {
                 content,
-                attachment: Some(attachment),
+                attachments,
             })
         }
-        "earnings" => {
+        "ratios" => {
+            let ticker = parts.next().ok_or("ticker required, e.g., ratios AAPL")?;
+            let resp = ratios::handle_text(finance, ticker).await?;
+            let attachment = resp
+                .image
+                .map(|bytes| CreateAttachment::bytes(bytes, "ratios.png"));
+            Ok(MentionResponse {
+                content: resp.content,
+                attachments: attachment.into_iter().collect(),
+            })
+        }
+        "dcf" => {
+            let ticker = parts
+                .next()
+                .ok_or("ticker required, e.g., dcf AAPL 8 9 2.5")?;
+            let mut rates = parts.map(dcf::parse_rate);
+            let growth = rates.next().transpose()?;
+            let discount = rates.next().transpose()?;
+            let terminal = rates.next().transpose()?;
+            let resp = dcf::handle_text(finance, ticker, growth, discount, terminal).await?;
+            let attachment = resp
+                .image
+                .map(|bytes| CreateAttachment::bytes(bytes, "dcf.png"));
+            Ok(MentionResponse {
+                content: resp.content,
+                attachments: attachment.into_iter().collect(),
+            })
+        }
+        "compare" => {
+            let tickers = parts.collect::<Vec<_>>().join(" ");
+            if tickers.is_empty() {
+                return Err("tickers required, e.g., compare NVDA AMD AVGO".into());
+            }
+            let resp = compare::handle_text(finance, &tickers).await?;
+            let attachment = resp
+                .image
+                .map(|bytes| CreateAttachment::bytes(bytes, "compare.png"));
+            Ok(MentionResponse {
+                content: resp.content,
+                attachments: attachment.into_iter().collect(),
+            })
+        }
+        "chart" => {
+            let tickers = parts
+                .next()
+                .ok_or("ticker required, e.g., chart AAPL revenue, free cash flow quarterly 3")?;
+            // Metric names can contain spaces; freq and years are optional trailing tokens
+            let mut rest: Vec<&str> = parts.by_ref().collect();
+            let years = match rest.last().map(|t| t.parse::<i64>()) {
+                Some(Ok(years)) => {
+                    rest.pop();
+                    Some(years)
+                }
+                _ => None,
+            };
+            let freq = match rest.last().map(|t| t.to_ascii_lowercase()) {
+                Some(t) if t == "annual" || t == "quarterly" => rest.pop(),
+                _ => None,
+            };
+            let metrics = rest.join(" ");
+            let resp =
+                fundamental_chart::handle_text(finance, tickers, &metrics, freq, years).await?;
+            let attachment = resp
+                .image
+                .map(|bytes| CreateAttachment::bytes(bytes, "fundamental-chart.png"));
+            Ok(MentionResponse {
+                content: resp.content,
+                attachments: attachment.into_iter().collect(),
+            })
+        }
+        "options" => {
             let mode = parts
                 .next()
-                .ok_or("earnings mode required: weekly|daily|reports")?
+                .ok_or("options mode required: history")?
                 .to_ascii_lowercase();
             match mode.as_str() {
-                "weekly" => {
-                    let resp = earnings::handle_weekly_plain(finance).await?;
+                "history" => {
+                    let series = parts.next();
+                    let expiration = parts.next();
+                    let resp = options::handle_history(cache, series, expiration).await?;
                     let attachment = resp
                         .image
-                        .map(|bytes| CreateAttachment::bytes(bytes, "earnings-calendar.png"));
+                        .map(|bytes| CreateAttachment::bytes(bytes, "spy_options_history.png"));
+                    Ok(MentionResponse {
+                        content: resp.content,
+                        attachments: attachment.into_iter().collect(),
+                    })
+                }
+                _ => Err("options mode must be history".into()),
+            }
+        }
+        "earnings" => {
+            let mode = parts
+                .next()
+                .ok_or("earnings mode required: weekly|daily|reports|history|season|subscribe|unsubscribe|subscriptions")?
+                .to_ascii_lowercase();
+            match mode.as_str() {
+                "weekly" => {
+                    let theme = automation::earnings::theme_for_channel(http, cache, channel_id).await;
+                    let resp = earnings::handle_weekly_plain(finance, cache, theme).await?;
                     Ok(MentionResponse {
                         content: resp.content,
-                        attachment,
+                        attachments: automation::earnings::calendar_attachments(
+                            resp.images,
+                            "earnings-calendar",
+                        ),
                     })
                 }
                 "daily" => {
                     let content =
-                        earnings::handle_daily_for_channel(finance, http, channel_id).await?;
+                        earnings::handle_daily_for_channel(finance, cache, http, channel_id)
+                            .await?;
                     Ok(MentionResponse {
                         content,
-                        attachment: None,
+                        attachments: Vec::new(),
                     })
                 }
                 "reports" => {
                     let content =
-                        earnings::handle_after_daily_for_channel(finance, http, channel_id).await?;
+                        earnings::handle_after_daily_for_channel(finance, cache, http, channel_id)
+                            .await?;
+                    Ok(MentionResponse {
+                        content,
+                        attachments: Vec::new(),
+                    })
+                }
+                "history" => {
+                    let ticker = parts.next().ok_or("ticker required")?;
+                    let content = earnings::handle_history_text(finance, cache, ticker).await?;
+                    Ok(MentionResponse {
+                        content,
+                        attachments: Vec::new(),
+                    })
+                }
+                "season" => {
+                    // Sector names can contain spaces, e.g. "financial services"
+                    let scope = parts.by_ref().collect::<Vec<_>>().join(" ");
+                    let content =
+                        earnings_season::handle_text(finance, cache, Some(scope.as_str())).await?;
+                    Ok(MentionResponse {
+                        content,
+                        attachments: Vec::new(),
+                    })
+                }
+                "subscribe" | "unsubscribe" | "subscriptions" => {
+                    let action = if mode == "subscriptions" { "list" } else { mode.as_str() };
+                    let ticker = parts.next();
+                    if action != "list" && ticker.is_none() {
+                        return Err(format!("ticker required, e.g., earnings {mode} AAPL"));
+                    }
+                    let delivery = parts.next();
+                    let content = earnings::handle_subscription_text(
+                        finance, cache, author, channel_id, action, ticker, delivery,
+                    )
+                    .await?;
                     Ok(MentionResponse {
                         content,
-                        attachment: None,
+                        attachments: Vec::new(),
                     })
                 }
-                _ => Err("earnings mode must be weekly | daily | reports".into()),
+                _ => Err(
+                    "earnings mode must be weekly | daily | reports | history | season | subscribe | unsubscribe | subscriptions"
+                        .into(),
+                ),
             }
         }
         _ => Err(format!("Unknown command: {}. {}", cmd, help_text())),
@@ -131,7 +352,7 @@ pub async fn handle(
 }
 
 pub fn help_text() -> &'static str {
-    "Usage: @Bot quote TICKER | holders TICKER TYPE [LIMIT] | news TICKER [LIMIT] | income|balance|cashflow TICKER FREQ [YEAR] [QUARTER] | earnings weekly|daily|reports"
+    "Usage: @Bot quote TICKER | iv TICKER | holders TICKER TYPE [LIMIT] | news TICKER [LIMIT] | income|balance|cashflow TICKER [METRIC] FREQ [YEAR] [QUARTER] [csv|json] | ratios TICKER | compare TICKER TICKER [TICKER...] | dcf TICKER [GROWTH] [DISCOUNT] [TERMINAL] | chart TICKER[,TICKER] METRIC[, METRIC...] [FREQ] [YEARS] | earnings weekly|daily|reports | earnings history TICKER | earnings season [SECTOR|watchlist] | earnings subscribe|unsubscribe TICKER [dm|channel] | earnings subscriptions | options history [SERIES] [EXPIRATION] | strategy SYMBOL LEGS EXPIRY [T+N]"
 }
 
 fn parse_usize(raw: &str) -> Result<usize, std::num::ParseIntError> {
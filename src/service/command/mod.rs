@@ -1,6 +1,14 @@
+pub mod compare;
+pub mod dcf;
 pub mod earnings;
+pub mod earnings_season;
+pub mod fundamental_chart;
 pub mod fundamentals;
 pub mod holders;
+pub mod iv;
 pub mod news;
+pub mod options;
 pub mod quotes;
+pub mod ratios;
+pub mod strategy;
 pub mod mention;
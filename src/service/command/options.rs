@@ -0,0 +1,122 @@
+use chrono::{NaiveDate, Utc};
+use chrono_tz::America::New_York;
+use serenity::all::{
+    CommandDataOption, CommandDataOptionValue, CommandInteraction, CommandOptionType,
+    CreateCommand, CreateCommandOption,
+};
+
+use crate::service::automation::options_data::build_history_chart;
+use crate::service::caching::collections::spy_data::{self as cache_spy, HistorySeries};
+use crate::service::caching::RedisCache;
+
+/// Response payload for the /options command.
+pub struct OptionsResponse {
+    pub content: String,
+    pub image: Option<Vec<u8>>,
+}
+
+pub fn register_command() -> CreateCommand {
+    let mut series = CreateCommandOption::new(
+        CommandOptionType::String,
+        "series",
+        "Which value to plot (default last price)",
+    );
+    for s in HistorySeries::ALL {
+        series = series.add_string_choice(s.label(), s.as_str());
+    }
+
+    CreateCommand::new("options")
+        .description("SPY options data")
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "history",
+                "Chart recorded SPY option history per strike",
+            )
+            .add_sub_option(series)
+            .add_sub_option(CreateCommandOption::new(
+                CommandOptionType::String,
+                "expiration",
+                "Expiration date YYYY-MM-DD (default today)",
+            )),
+        )
+}
+
+pub async fn handle(
+    command: &CommandInteraction,
+    cache: Option<&RedisCache>,
+) -> Result<OptionsResponse, String> {
+    let sub = command
+        .data
+        .options
+        .first()
+        .ok_or("subcommand is required")?;
+
+    match sub.name.as_str() {
+        "history" => {
+            let series = get_sub_str_opt(sub, "series");
+            let expiration = get_sub_str_opt(sub, "expiration");
+            handle_history(cache, series, expiration).await
+        }
+        other => Err(format!("unknown subcommand: {other}")),
+    }
+}
+
+pub async fn handle_history(
+    cache: Option<&RedisCache>,
+    series: Option<&str>,
+    expiration: Option<&str>,
+) -> Result<OptionsResponse, String> {
+    let cache = cache.ok_or("option history requires Redis (REDIS_URL is not configured)")?;
+
+    let series = match series {
+        Some(raw) => HistorySeries::parse(raw).ok_or_else(|| {
+            format!("unknown series '{raw}'; use last | bid | ask | mid | iv | volume | oi")
+        })?,
+        None => HistorySeries::Last,
+    };
+
+    let expiration = match expiration {
+        Some(raw) => NaiveDate::parse_from_str(raw.trim(), "%Y-%m-%d")
+            .map_err(|_| format!("invalid expiration '{raw}', expected YYYY-MM-DD"))?,
+        None => Utc::now().with_timezone(&New_York).date_naive(),
+    };
+    let expiration = expiration.format("%Y-%m-%d").to_string();
+
+    let history = cache_spy::load_history(cache, &expiration, cache_spy::DEFAULT_HISTORY_POINTS)
+        .await
+        .map_err(|e| format!("failed to load history: {e}"))?;
+
+    if history.is_empty() {
+        return Err(format!("no SPY option history recorded for {expiration}"));
+    }
+
+    let points = history.values().map(Vec::len).max().unwrap_or(0);
+    let content = format!(
+        "SPY options {} history (exp {}) — {} strikes, up to {} points each",
+        series.label(),
+        expiration,
+        history.len(),
+        points
+    );
+
+    let image = build_history_chart(&expiration, &history, series).await?;
+
+    Ok(OptionsResponse {
+        content,
+        image: Some(image),
+    })
+}
+
+fn get_sub_str_opt<'a>(sub: &'a CommandDataOption, name: &str) -> Option<&'a str> {
+    match sub.value {
+        CommandDataOptionValue::SubCommand(ref options) => options
+            .iter()
+            .find(|o| o.name == name)
+            .and_then(|o| match o.value {
+                CommandDataOptionValue::String(ref s) => Some(s.as_str()),
+                _ => None,
+            }),
+        _ => None,
+    }
+}
@@ -3,8 +3,16 @@ use serenity::all::{
     CreateCommandOption,
 };
 
+use chrono::Utc;
+use chrono_tz::America::New_York;
+use tracing::warn;
+
+use crate::service::caching::collections::iv_history;
+use crate::service::caching::RedisCache;
 use crate::service::finance::FinanceService;
 
+use super::iv::format_stats;
+
 pub fn register_command() -> CreateCommand {
     CreateCommand::new("quote")
         .description("Get a simple quote for a ticker")
@@ -21,16 +29,25 @@ pub fn register_command() -> CreateCommand {
 pub async fn handle(
     command: &CommandInteraction,
     finance: &FinanceService,
+    cache: Option<&RedisCache>,
 ) -> Result<String, String> {
     let ticker = get_str_opt(command, "ticker").ok_or("ticker is required")?;
-    build_response(finance, ticker).await
+    build_response(finance, cache, ticker).await
 }
 
-pub async fn handle_text(finance: &FinanceService, ticker: &str) -> Result<String, String> {
-    build_response(finance, ticker).await
+pub async fn handle_text(
+    finance: &FinanceService,
+    cache: Option<&RedisCache>,
+    ticker: &str,
+) -> Result<String, String> {
+    build_response(finance, cache, ticker).await
 }
 
-async fn build_response(finance: &FinanceService, ticker: &str) -> Result<String, String> {
+async fn build_response(
+    finance: &FinanceService,
+    cache: Option<&RedisCache>,
+    ticker: &str,
+) -> Result<String, String> {
     let quote = finance
         .get_price(ticker)
         .await
@@ -55,6 +72,18 @@ async fn build_response(finance: &FinanceService, ticker: &str) -> Result<String
     if let Some(ah) = quote.after_hours_price {
         parts.push(format!("After-hours: {:.2}", ah));
     }
+    if let Some(cache) = cache {
+        let today = Utc::now().with_timezone(&New_York).date_naive();
+        match iv_history::stats(cache, &quote.symbol, today, None).await {
+            Ok(Some(stats)) => parts.push(format!(
+                "IV30: {:.1}% ({})",
+                stats.current * 100.0,
+                format_stats(&stats)
+            )),
+            Ok(None) => {}
+            Err(e) => warn!("failed to load IV history for {}: {}", quote.symbol, e),
+        }
+    }
 
     Ok(parts.join(" | "))
 }
@@ -0,0 +1,301 @@
+use std::io::Cursor;
+
+use ab_glyph::PxScale;
+use image::{ImageFormat, Rgba, RgbaImage};
+use imageproc::drawing::{draw_filled_rect_mut, draw_text_mut};
+use imageproc::rect::Rect;
+use serenity::all::{
+    CommandDataOptionValue, CommandInteraction, CommandOptionType, CreateCommand,
+    CreateCommandOption,
+};
+use tracing::warn;
+
+use crate::models::FinancialSummary;
+use crate::service::automation::earnings::weekly_report::load_font;
+use crate::service::finance::statements::abbreviate;
+use crate::service::finance::FinanceService;
+
+const WIDTH: u32 = 920;
+const MARGIN: u32 = 24;
+const COLUMNS: u32 = 5;
+const TILE_GAP: u32 = 12;
+const TILE_H: u32 = 72;
+const SECTION_HEADER_H: u32 = 34;
+const HEADER_H: u32 = 96;
+const DASH: &str = "—";
+
+const BG: Rgba<u8> = Rgba([255, 255, 255, 255]);
+const TILE_BG: Rgba<u8> = Rgba([243, 246, 250, 255]);
+const TEXT: Rgba<u8> = Rgba([40, 40, 40, 255]);
+const MUTED: Rgba<u8> = Rgba([110, 110, 110, 255]);
+
+/// Response payload for the /ratios command.
+pub struct RatiosResponse {
+    pub content: String,
+    pub image: Option<Vec<u8>>,
+}
+
+/// A titled group of `(label, value)` tiles on the ratios card.
+pub struct CardSection {
+    pub title: &'static str,
+    pub tiles: Vec<(&'static str, String)>,
+}
+
+pub fn register_command() -> CreateCommand {
+    CreateCommand::new("ratios")
+        .description("Margins, returns, leverage and valuation ratios from TTM statements")
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::String,
+                "ticker",
+                "Ticker symbol, e.g., AAPL",
+            )
+            .required(true),
+        )
+}
+
+pub async fn handle(
+    command: &CommandInteraction,
+    finance: &FinanceService,
+) -> Result<RatiosResponse, String> {
+    let ticker = get_str_opt(command, "ticker").ok_or("ticker is required")?;
+    handle_text(finance, ticker).await
+}
+
+pub async fn handle_text(finance: &FinanceService, ticker: &str) -> Result<RatiosResponse, String> {
+    let symbol = ticker.trim().to_ascii_uppercase();
+    let summary = finance
+        .get_financials(&symbol)
+        .await
+        .map_err(|e| format!("fetch error: {e}"))?;
+
+    let sections = card_sections(&summary);
+    if sections
+        .iter()
+        .flat_map(|s| &s.tiles)
+        .all(|(_, value)| value == DASH)
+    {
+        return Err(format!("no fundamentals available for {}", symbol));
+    }
+
+    let content = format_summary(&summary, &sections);
+    let image = match render_card_image(&summary, &sections) {
+        Ok(bytes) => Some(bytes),
+        Err(err) => {
+            warn!("ratios card render failed for {}: {}", symbol, err);
+            None
+        }
+    };
+
+    Ok(RatiosResponse { content, image })
+}
+
+/// Card contents, shared by the text summary and the image.
+pub fn card_sections(summary: &FinancialSummary) -> Vec<CardSection> {
+    let r = &summary.ratios;
+    let ttm = &summary.ttm;
+    vec![
+        CardSection {
+            title: "Profitability (TTM)",
+            tiles: vec![
+                ("Gross margin", pct(r.gross_margin)),
+                ("Operating margin", pct(r.operating_margin)),
+                ("Net margin", pct(r.net_margin)),
+                ("ROE", pct(r.roe)),
+                ("ROIC", pct(r.roic)),
+            ],
+        },
+        CardSection {
+            title: "Growth (fiscal year)",
+            tiles: vec![
+                ("Revenue", pct(r.revenue_growth)),
+                ("Diluted EPS", pct(r.eps_growth)),
+            ],
+        },
+        CardSection {
+            title: "Balance sheet",
+            tiles: vec![
+                ("Debt / equity", multiple(r.debt_to_equity)),
+                ("Current ratio", multiple(r.current_ratio)),
+            ],
+        },
+        CardSection {
+            title: "Valuation",
+            tiles: vec![
+                ("P/E", multiple(summary.pe_ratio)),
+                ("PEG", multiple(r.peg)),
+                ("EV / EBITDA", multiple(r.ev_to_ebitda)),
+                ("FCF yield", pct(r.fcf_yield)),
+                ("Market cap", amount(summary.market_cap)),
+            ],
+        },
+        CardSection {
+            title: "Trailing twelve months",
+            tiles: vec![
+                ("Revenue", amount(ttm.revenue)),
+                ("Net income", amount(ttm.net_income)),
+                ("EBITDA", amount(ttm.ebitda)),
+                ("Free cash flow", amount(ttm.free_cash_flow)),
+                (
+                    "Diluted EPS",
+                    ttm.diluted_eps.map_or(DASH.into(), |v| format!("{:.2}", v)),
+                ),
+            ],
+        },
+    ]
+}
+
+fn pct(value: Option<f64>) -> String {
+    value.map_or(DASH.into(), |v| format!("{:.1}%", v))
+}
+
+fn multiple(value: Option<f64>) -> String {
+    value.map_or(DASH.into(), |v| format!("{:.2}x", v))
+}
+
+fn amount(value: Option<f64>) -> String {
+    value.map_or(DASH.into(), abbreviate)
+}
+
+fn subtitle(summary: &FinancialSummary) -> String {
+    let mut parts = Vec::new();
+    if let Some(price) = summary.price {
+        parts.push(format!(
+            "Price {:.2} {}",
+            price,
+            summary.currency.as_deref().unwrap_or("")
+        ));
+    }
+    if let Some(ev) = summary.enterprise_value {
+        parts.push(format!("EV {}", abbreviate(ev)));
+    }
+    if let Some(as_of) = summary.ttm.as_of {
+        parts.push(format!("TTM through {}", as_of.format("%Y-%m-%d")));
+    }
+    parts
+        .iter()
+        .map(|p| p.trim())
+        .collect::<Vec<_>>()
+        .join(" · ")
+}
+
+fn format_summary(summary: &FinancialSummary, sections: &[CardSection]) -> String {
+    let mut lines = vec![format!("📐 **{}** key ratios", summary.symbol)];
+    let subtitle = subtitle(summary);
+    if !subtitle.is_empty() {
+        lines.push(subtitle);
+    }
+    for section in sections {
+        lines.push(format!(
+            "{}: {}",
+            section.title,
+            section
+                .tiles
+                .iter()
+                .map(|(label, value)| format!("{} {}", label, value))
+                .collect::<Vec<_>>()
+                .join(" · ")
+        ));
+    }
+    lines.join("\n")
+}
+
+fn render_card_image(
+    summary: &FinancialSummary,
+    sections: &[CardSection],
+) -> Result<Vec<u8>, String> {
+    let font = load_font()?;
+    let tile_w = (WIDTH - MARGIN * 2 - TILE_GAP * (COLUMNS - 1)) / COLUMNS;
+    let rows: u32 = sections
+        .iter()
+        .map(|s| (s.tiles.len() as u32).div_ceil(COLUMNS))
+        .sum();
+    let height =
+        HEADER_H + sections.len() as u32 * SECTION_HEADER_H + rows * (TILE_H + TILE_GAP) + MARGIN;
+    let mut img = RgbaImage::from_pixel(WIDTH, height, BG);
+
+    draw_text_mut(
+        &mut img,
+        TEXT,
+        MARGIN as i32,
+        MARGIN as i32,
+        PxScale::from(30.0),
+        &font,
+        &format!("{} key ratios", summary.symbol),
+    );
+    draw_text_mut(
+        &mut img,
+        MUTED,
+        MARGIN as i32,
+        MARGIN as i32 + 40,
+        PxScale::from(17.0),
+        &font,
+        &subtitle(summary),
+    );
+
+    let label_scale = PxScale::from(15.0);
+    let value_scale = PxScale::from(26.0);
+    let mut y = HEADER_H;
+    for section in sections {
+        draw_text_mut(
+            &mut img,
+            MUTED,
+            MARGIN as i32,
+            y as i32 + 8,
+            PxScale::from(18.0),
+            &font,
+            section.title,
+        );
+        y += SECTION_HEADER_H;
+
+        for (idx, (label, value)) in section.tiles.iter().enumerate() {
+            let col = idx as u32 % COLUMNS;
+            let row = idx as u32 / COLUMNS;
+            let x = MARGIN + col * (tile_w + TILE_GAP);
+            let tile_y = y + row * (TILE_H + TILE_GAP);
+            draw_filled_rect_mut(
+                &mut img,
+                Rect::at(x as i32, tile_y as i32).of_size(tile_w, TILE_H),
+                TILE_BG,
+            );
+            draw_text_mut(
+                &mut img,
+                MUTED,
+                x as i32 + 12,
+                tile_y as i32 + 10,
+                label_scale,
+                &font,
+                label,
+            );
+            draw_text_mut(
+                &mut img,
+                TEXT,
+                x as i32 + 12,
+                tile_y as i32 + 32,
+                value_scale,
+                &font,
+                value,
+            );
+        }
+        y += (section.tiles.len() as u32).div_ceil(COLUMNS) * (TILE_H + TILE_GAP);
+    }
+
+    let mut buffer = Vec::new();
+    image::DynamicImage::ImageRgba8(img)
+        .write_to(&mut Cursor::new(&mut buffer), ImageFormat::Png)
+        .map_err(|e| format!("failed to encode png: {e}"))?;
+
+    Ok(buffer)
+}
+
+fn get_str_opt<'a>(command: &'a CommandInteraction, name: &str) -> Option<&'a str> {
+    command
+        .data
+        .options
+        .iter()
+        .find(|o| o.name == name)
+        .and_then(|o| match o.value {
+            CommandDataOptionValue::String(ref s) => Some(s.as_str()),
+            _ => None,
+        })
+}
@@ -0,0 +1,534 @@
+use std::io::Cursor;
+
+use ab_glyph::PxScale;
+use chrono::{NaiveDate, Utc};
+use chrono_tz::America::New_York;
+use image::{ImageFormat, Rgba, RgbaImage};
+use imageproc::drawing::{draw_filled_rect_mut, draw_line_segment_mut, draw_text_mut, text_size};
+use imageproc::rect::Rect;
+use serenity::all::{
+    CommandDataOptionValue, CommandInteraction, CommandOptionType, CreateCommand,
+    CreateCommandOption,
+};
+use tracing::warn;
+
+use crate::service::automation::earnings::weekly_report::load_font;
+use crate::service::finance::strategy::{analyze_strategy, LegSpec, OptionKind, StrategyAnalysis};
+use crate::service::finance::FinanceService;
+
+const MAX_LEGS: usize = 8;
+
+const WIDTH: u32 = 1000;
+const HEIGHT: u32 = 560;
+const PLOT_LEFT: f32 = 90.0;
+const PLOT_RIGHT: f32 = 970.0;
+const PLOT_TOP: f32 = 70.0;
+const PLOT_BOTTOM: f32 = 500.0;
+
+const BG: Rgba<u8> = Rgba([255, 255, 255, 255]);
+const AXIS: Rgba<u8> = Rgba([120, 120, 120, 255]);
+const GRID: Rgba<u8> = Rgba([232, 232, 232, 255]);
+const TEXT: Rgba<u8> = Rgba([40, 40, 40, 255]);
+const PROFIT_FILL: Rgba<u8> = Rgba([214, 240, 219, 255]);
+const LOSS_FILL: Rgba<u8> = Rgba([248, 218, 218, 255]);
+const EXPIRY_LINE: Rgba<u8> = Rgba([33, 150, 243, 255]);
+const HORIZON_LINE: Rgba<u8> = Rgba([255, 152, 0, 255]);
+const SPOT_LINE: Rgba<u8> = Rgba([90, 90, 90, 255]);
+const BREAKEVEN_MARK: Rgba<u8> = Rgba([156, 39, 176, 255]);
+
+/// Parsed `/strategy` input, e.g. `SPY 600C -1, 605C +1 2026-10-23 T+3`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StrategyRequest {
+    pub symbol: String,
+    pub expiration: NaiveDate,
+    pub legs: Vec<LegSpec>,
+    pub horizon_days: Option<i64>,
+}
+
+/// Response payload for the /strategy command.
+pub struct StrategyResponse {
+    pub content: String,
+    pub image: Option<Vec<u8>>,
+}
+
+pub fn register_command() -> CreateCommand {
+    CreateCommand::new("strategy")
+        .description("Multi-leg option P/L: payoff at expiry and T+n, breakevens, max P/L, POP")
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::String,
+                "position",
+                "Symbol, legs and expiry, e.g. SPY 600C -1, 605C +1 2026-10-23",
+            )
+            .required(true),
+        )
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::Integer,
+                "days",
+                "Days from today for the T+n curve (default 0)",
+            )
+            .min_int_value(0)
+            .required(false),
+        )
+}
+
+pub async fn handle(
+    command: &CommandInteraction,
+    finance: &FinanceService,
+) -> Result<StrategyResponse, String> {
+    let position = get_str_opt(command, "position").ok_or("position is required")?;
+    let days = get_int_opt(command, "days");
+    handle_text(finance, position, days).await
+}
+
+pub async fn handle_text(
+    finance: &FinanceService,
+    position: &str,
+    days: Option<i64>,
+) -> Result<StrategyResponse, String> {
+    let request = parse_strategy(position)?;
+
+    let now_et = Utc::now().with_timezone(&New_York).naive_local();
+    let expiry_close = request
+        .expiration
+        .and_hms_opt(16, 0, 0)
+        .ok_or("invalid expiration")?;
+    let days_to_expiry = (expiry_close - now_et).num_minutes() as f64 / (60.0 * 24.0);
+    if days_to_expiry <= 0.0 {
+        return Err(format!("{} has already expired", request.expiration));
+    }
+
+    let horizon_days = days
+        .or(request.horizon_days)
+        .unwrap_or(0)
+        .clamp(0, days_to_expiry.floor() as i64);
+
+    let (spot, legs) = finance
+        .get_strategy_legs(
+            &request.symbol,
+            request.expiration,
+            &request.legs,
+            days_to_expiry,
+        )
+        .await
+        .map_err(|e| format!("fetch error: {e}"))?;
+
+    let analysis = analyze_strategy(spot, legs, days_to_expiry, horizon_days);
+    let title = format!(
+        "{} {} strategy",
+        request.symbol,
+        request.expiration.format("%Y-%m-%d")
+    );
+    let content = format_summary(&title, &analysis);
+
+    let image = match render_payoff_image(&title, &analysis) {
+        Ok(bytes) => Some(bytes),
+        Err(err) => {
+            warn!(
+                "strategy payoff render failed for {}: {}",
+                request.symbol, err
+            );
+            None
+        }
+    };
+
+    Ok(StrategyResponse { content, image })
+}
+
+/// Parse `SYMBOL STRIKE{C|P} [±QTY], ... YYYY-MM-DD [T+N]`. Quantity defaults to +1.
+pub fn parse_strategy(input: &str) -> Result<StrategyRequest, String> {
+    let cleaned = input.replace(',', " ");
+    let mut tokens = cleaned.split_whitespace().peekable();
+
+    let symbol = tokens
+        .next()
+        .ok_or("symbol required, e.g. SPY 600C -1, 605C +1 2026-10-23")?
+        .to_ascii_uppercase();
+    if !symbol
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '^'))
+    {
+        return Err(format!("invalid symbol: {symbol}"));
+    }
+
+    let mut expiration = None;
+    let mut horizon_days = None;
+    let mut legs = Vec::new();
+
+    while let Some(token) = tokens.next() {
+        let upper = token.to_ascii_uppercase();
+
+        if let Ok(date) = NaiveDate::parse_from_str(&upper, "%Y-%m-%d") {
+            expiration = Some(date);
+            continue;
+        }
+
+        if let Some(n) = upper.strip_prefix("T+") {
+            horizon_days = Some(
+                n.parse::<i64>()
+                    .map_err(|_| format!("invalid horizon: {token}"))?,
+            );
+            continue;
+        }
+
+        let kind = match upper.chars().last() {
+            Some('C') => OptionKind::Call,
+            Some('P') => OptionKind::Put,
+            _ => {
+                return Err(format!(
+                    "unrecognised token: {token} (expected e.g. 600C or 595P)"
+                ))
+            }
+        };
+        let strike: f64 = upper[..upper.len() - 1]
+            .parse()
+            .map_err(|_| format!("invalid strike: {token}"))?;
+        if strike <= 0.0 {
+            return Err(format!("invalid strike: {token}"));
+        }
+
+        let quantity = match tokens.peek().and_then(|t| parse_quantity(t)) {
+            Some(q) => {
+                tokens.next();
+                q
+            }
+            None => 1,
+        };
+        if quantity == 0 {
+            return Err(format!("quantity for {token} must not be zero"));
+        }
+
+        legs.push(LegSpec {
+            strike,
+            kind,
+            quantity,
+        });
+    }
+
+    if legs.is_empty() {
+        return Err("at least one leg required, e.g. 600C -1".into());
+    }
+    if legs.len() > MAX_LEGS {
+        return Err(format!("at most {MAX_LEGS} legs supported"));
+    }
+    let expiration = expiration.ok_or("expiration required as YYYY-MM-DD")?;
+
+    Ok(StrategyRequest {
+        symbol,
+        expiration,
+        legs,
+        horizon_days,
+    })
+}
+
+fn parse_quantity(token: &str) -> Option<i32> {
+    let digits = token.strip_prefix('+').unwrap_or(token);
+    let digits = digits.strip_prefix(['x', 'X']).unwrap_or(digits);
+    digits.parse::<i32>().ok()
+}
+
+fn format_summary(title: &str, a: &StrategyAnalysis) -> String {
+    let mut lines = Vec::new();
+    lines.push(format!(
+        "📐 {} — spot {:.2} | {:.1} DTE | IV {:.1}%",
+        title,
+        a.spot,
+        a.days_to_expiry,
+        a.iv * 100.0
+    ));
+
+    let legs = a
+        .legs
+        .iter()
+        .map(|l| {
+            format!(
+                "{:+} {}{} @ {:.2}{}",
+                l.quantity,
+                format_strike(l.strike),
+                l.kind.as_char(),
+                l.premium,
+                if l.via_parity { " (parity)" } else { "" }
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" · ");
+    lines.push(format!("Legs: {}", legs));
+
+    let cost = if a.net_debit >= 0.0 {
+        format!("Net debit ${:.0}", a.net_debit)
+    } else {
+        format!("Net credit ${:.0}", -a.net_debit)
+    };
+    let max_profit = a
+        .max_profit
+        .map(|p| format!("${:.0}", p))
+        .unwrap_or_else(|| "unlimited".to_string());
+    let max_loss = a
+        .max_loss
+        .map(|l| format!("${:.0}", l.min(0.0).abs()))
+        .unwrap_or_else(|| "unlimited".to_string());
+    let breakevens = if a.breakevens.is_empty() {
+        "none".to_string()
+    } else {
+        a.breakevens
+            .iter()
+            .map(|b| format!("{:.2}", b))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    lines.push(format!(
+        "{} | Max profit {} | Max loss {} | Breakevens {} | POP {:.0}%",
+        cost,
+        max_profit,
+        max_loss,
+        breakevens,
+        a.pop * 100.0
+    ));
+    lines.push(format!(
+        "Greeks: Δ {:+.1} | Γ {:+.2} | Θ {:+.2}/day | Vega {:+.2}/pt",
+        a.greeks.delta, a.greeks.gamma, a.greeks.theta, a.greeks.vega
+    ));
+
+    lines.join("\n")
+}
+
+fn format_strike(strike: f64) -> String {
+    if strike.fract() == 0.0 {
+        format!("{:.0}", strike)
+    } else {
+        format!("{}", strike)
+    }
+}
+
+fn render_payoff_image(title: &str, a: &StrategyAnalysis) -> Result<Vec<u8>, String> {
+    let font = load_font()?;
+    let mut img = RgbaImage::from_pixel(WIDTH, HEIGHT, BG);
+
+    let (Some(first), Some(last)) = (a.curve.first(), a.curve.last()) else {
+        return Err("empty payoff curve".into());
+    };
+    let (x_min, x_max) = (first.spot, last.spot);
+    let mut y_min = a
+        .curve
+        .iter()
+        .map(|p| p.at_expiry.min(p.at_horizon))
+        .fold(0.0, f64::min);
+    let mut y_max = a
+        .curve
+        .iter()
+        .map(|p| p.at_expiry.max(p.at_horizon))
+        .fold(0.0, f64::max);
+    let pad = ((y_max - y_min) * 0.08).max(1.0);
+    y_min -= pad;
+    y_max += pad;
+
+    let px = |s: f64| PLOT_LEFT + ((s - x_min) / (x_max - x_min)) as f32 * (PLOT_RIGHT - PLOT_LEFT);
+    let py =
+        |v: f64| PLOT_BOTTOM - ((v - y_min) / (y_max - y_min)) as f32 * (PLOT_BOTTOM - PLOT_TOP);
+    let zero_y = py(0.0);
+
+    // Horizontal gridlines with dollar labels
+    let label_scale = PxScale::from(16.0);
+    for i in 0..=5 {
+        let v = y_min + (y_max - y_min) * i as f64 / 5.0;
+        let y = py(v);
+        draw_line_segment_mut(&mut img, (PLOT_LEFT, y), (PLOT_RIGHT, y), GRID);
+        let label = format_dollars(v);
+        let (w, _) = text_size(label_scale, &font, &label);
+        draw_text_mut(
+            &mut img,
+            AXIS,
+            (PLOT_LEFT - 8.0) as i32 - w as i32,
+            y as i32 - 8,
+            label_scale,
+            &font,
+            &label,
+        );
+    }
+
+    // Profit/loss shading under the expiry curve
+    for pair in a.curve.windows(2) {
+        let x0 = px(pair[0].spot) as i32;
+        let x1 = px(pair[1].spot) as i32;
+        let y = py(pair[0].at_expiry);
+        let (top, bottom, fill) = if pair[0].at_expiry >= 0.0 {
+            (y, zero_y, PROFIT_FILL)
+        } else {
+            (zero_y, y, LOSS_FILL)
+        };
+        let height = (bottom - top).round() as u32;
+        let width = (x1 - x0).max(1) as u32;
+        if height > 0 {
+            draw_filled_rect_mut(
+                &mut img,
+                Rect::at(x0, top.round() as i32).of_size(width, height),
+                fill,
+            );
+        }
+    }
+
+    // Axes and zero line
+    draw_line_segment_mut(&mut img, (PLOT_LEFT, zero_y), (PLOT_RIGHT, zero_y), AXIS);
+    draw_line_segment_mut(
+        &mut img,
+        (PLOT_LEFT, PLOT_TOP),
+        (PLOT_LEFT, PLOT_BOTTOM),
+        AXIS,
+    );
+    draw_line_segment_mut(
+        &mut img,
+        (PLOT_LEFT, PLOT_BOTTOM),
+        (PLOT_RIGHT, PLOT_BOTTOM),
+        AXIS,
+    );
+
+    // Price ticks
+    for i in 0..=6 {
+        let s = x_min + (x_max - x_min) * i as f64 / 6.0;
+        let x = px(s);
+        draw_line_segment_mut(&mut img, (x, PLOT_BOTTOM), (x, PLOT_BOTTOM + 6.0), AXIS);
+        let label = format!("{:.0}", s);
+        let (w, _) = text_size(label_scale, &font, &label);
+        draw_text_mut(
+            &mut img,
+            AXIS,
+            x as i32 - w as i32 / 2,
+            PLOT_BOTTOM as i32 + 10,
+            label_scale,
+            &font,
+            &label,
+        );
+    }
+
+    // Spot marker
+    let spot_x = px(a.spot);
+    draw_dashed_vertical(&mut img, spot_x, SPOT_LINE);
+    draw_text_mut(
+        &mut img,
+        SPOT_LINE,
+        spot_x as i32 + 4,
+        PLOT_TOP as i32,
+        label_scale,
+        &font,
+        &format!("spot {:.2}", a.spot),
+    );
+
+    // Payoff curves (drawn twice for a 2px stroke)
+    for pair in a.curve.windows(2) {
+        for offset in [0.0, 1.0] {
+            draw_line_segment_mut(
+                &mut img,
+                (px(pair[0].spot), py(pair[0].at_horizon) + offset),
+                (px(pair[1].spot), py(pair[1].at_horizon) + offset),
+                HORIZON_LINE,
+            );
+            draw_line_segment_mut(
+                &mut img,
+                (px(pair[0].spot), py(pair[0].at_expiry) + offset),
+                (px(pair[1].spot), py(pair[1].at_expiry) + offset),
+                EXPIRY_LINE,
+            );
+        }
+    }
+
+    // Breakevens
+    for b in a.breakevens.iter().filter(|b| **b >= x_min && **b <= x_max) {
+        let x = px(*b);
+        draw_filled_rect_mut(
+            &mut img,
+            Rect::at(x as i32 - 4, zero_y as i32 - 4).of_size(9, 9),
+            BREAKEVEN_MARK,
+        );
+        draw_text_mut(
+            &mut img,
+            BREAKEVEN_MARK,
+            x as i32 + 6,
+            zero_y as i32 + 6,
+            label_scale,
+            &font,
+            &format!("{:.2}", b),
+        );
+    }
+
+    // Title and legend
+    draw_text_mut(&mut img, TEXT, 24, 16, PxScale::from(26.0), &font, title);
+    let legend_y = 22;
+    let mut legend_x = 560;
+    for (color, label) in [
+        (EXPIRY_LINE, "At expiry".to_string()),
+        (HORIZON_LINE, format!("T+{}", a.horizon_days)),
+    ] {
+        draw_filled_rect_mut(
+            &mut img,
+            Rect::at(legend_x, legend_y + 6).of_size(24, 4),
+            color,
+        );
+        draw_text_mut(
+            &mut img,
+            TEXT,
+            legend_x + 30,
+            legend_y - 2,
+            label_scale,
+            &font,
+            &label,
+        );
+        legend_x += 150;
+    }
+    draw_text_mut(
+        &mut img,
+        TEXT,
+        legend_x,
+        legend_y - 2,
+        label_scale,
+        &font,
+        &format!("POP {:.0}%", a.pop * 100.0),
+    );
+
+    let mut buffer = Vec::new();
+    image::DynamicImage::ImageRgba8(img)
+        .write_to(&mut Cursor::new(&mut buffer), ImageFormat::Png)
+        .map_err(|e| format!("failed to encode png: {e}"))?;
+
+    Ok(buffer)
+}
+
+fn draw_dashed_vertical(img: &mut RgbaImage, x: f32, color: Rgba<u8>) {
+    let mut y = PLOT_TOP;
+    while y < PLOT_BOTTOM {
+        draw_line_segment_mut(img, (x, y), (x, (y + 6.0).min(PLOT_BOTTOM)), color);
+        y += 12.0;
+    }
+}
+
+fn format_dollars(v: f64) -> String {
+    if v.abs() >= 1000.0 {
+        format!("${:.1}k", v / 1000.0)
+    } else {
+        format!("${:.0}", v)
+    }
+}
+
+fn get_str_opt<'a>(command: &'a CommandInteraction, name: &str) -> Option<&'a str> {
+    command
+        .data
+        .options
+        .iter()
+        .find(|o| o.name == name)
+        .and_then(|o| match o.value {
+            CommandDataOptionValue::String(ref s) => Some(s.as_str()),
+            _ => None,
+        })
+}
+
+fn get_int_opt(command: &CommandInteraction, name: &str) -> Option<i64> {
+    command
+        .data
+        .options
+        .iter()
+        .find(|o| o.name == name)
+        .and_then(|o| match o.value {
+            CommandDataOptionValue::Integer(v) => Some(v),
+            _ => None,
+        })
+}
@@ -0,0 +1,78 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use chrono_tz::America::New_York;
+use serde_json::Value;
+
+use super::{FinanceService, FinanceServiceError};
+
+impl FinanceService {
+    /// Fetch regular-session daily closes (ET trading dates) between `from` and `to` inclusive.
+    pub async fn get_daily_closes(
+        &self,
+        symbol: &str,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<(NaiveDate, f64)>, FinanceServiceError> {
+        let start = from
+            .and_hms_opt(0, 0, 0)
+            .map(|dt| dt.and_utc().timestamp())
+            .unwrap_or_default();
+        let end = to
+            .succ_opt()
+            .and_then(|d| d.and_hms_opt(23, 59, 59))
+            .map(|dt| dt.and_utc().timestamp())
+            .unwrap_or_else(|| Utc::now().timestamp());
+
+        let data = self
+            .client
+            .get_chart_with_periods(symbol, "1d", start, end)
+            .await?;
+
+        let closes: Vec<(NaiveDate, f64)> = parse_daily_closes(&data)
+            .into_iter()
+            .filter(|(d, _)| *d >= from && *d <= to)
+            .collect();
+
+        if closes.is_empty() {
+            return Err(FinanceServiceError::NotFound(format!(
+                "no daily closes for {symbol} between {from} and {to}"
+            )));
+        }
+
+        Ok(closes)
+    }
+}
+
+fn parse_daily_closes(data: &Value) -> Vec<(NaiveDate, f64)> {
+    let Some(result) = data
+        .get("chart")
+        .and_then(|c| c.get("result"))
+        .and_then(|r| r.as_array())
+        .and_then(|arr| arr.first())
+    else {
+        return Vec::new();
+    };
+
+    let timestamps = result
+        .get("timestamp")
+        .and_then(|t| t.as_array())
+        .cloned()
+        .unwrap_or_default();
+    let closes = result
+        .get("indicators")
+        .and_then(|i| i.get("quote"))
+        .and_then(|q| q.as_array())
+        .and_then(|arr| arr.first())
+        .and_then(|q| q.get("close"))
+        .and_then(|c| c.as_array())
+        .cloned()
+        .unwrap_or_default();
+
+    timestamps
+        .iter()
+        .zip(closes.iter())
+        .filter_map(|(ts, close)| {
+            let dt = DateTime::<Utc>::from_timestamp(ts.as_i64()?, 0)?;
+            Some((dt.with_timezone(&New_York).date_naive(), close.as_f64()?))
+        })
+        .collect()
+}
@@ -0,0 +1,71 @@
+use chrono::{Datelike, Duration, NaiveDate, Utc};
+use chrono_tz::America::New_York;
+use futures_util::future::join_all;
+
+use crate::models::{FinancialSummary, PricePerformance};
+
+use super::statements::pct_change;
+use super::{FinanceService, FinanceServiceError};
+
+/// Calendar days of closes fetched for the one-year return, with slack for holidays.
+const HISTORY_DAYS: i64 = 372;
+
+/// Fundamentals and price performance for one side of a comparison.
+#[derive(Debug, Clone)]
+pub struct CompanySnapshot {
+    pub summary: FinancialSummary,
+    pub performance: PricePerformance,
+}
+
+impl FinanceService {
+    /// Fetch snapshots for every symbol concurrently. Each symbol succeeds or fails on its
+    /// own; results keep the input order.
+    pub async fn get_company_snapshots(
+        &self,
+        symbols: &[String],
+    ) -> Vec<(String, Result<CompanySnapshot, FinanceServiceError>)> {
+        let results = join_all(symbols.iter().map(|s| self.get_company_snapshot(s))).await;
+        symbols.iter().cloned().zip(results).collect()
+    }
+
+    /// Ratios from `get_financials` plus trailing returns from a year of daily closes.
+    /// Missing price history leaves the returns empty rather than failing the snapshot.
+    pub async fn get_company_snapshot(
+        &self,
+        symbol: &str,
+    ) -> Result<CompanySnapshot, FinanceServiceError> {
+        let today = Utc::now().with_timezone(&New_York).date_naive();
+        let (summary, closes) = tokio::join!(
+            self.get_financials(symbol),
+            self.get_daily_closes(symbol, today - Duration::days(HISTORY_DAYS), today),
+        );
+        let performance = closes
+            .map(|closes| price_performance(&closes))
+            .unwrap_or_default();
+
+        Ok(CompanySnapshot {
+            summary: summary?,
+            performance,
+        })
+    }
+}
+
+/// Trailing returns from daily closes (oldest first), anchored at the latest close.
+pub fn price_performance(closes: &[(NaiveDate, f64)]) -> PricePerformance {
+    let Some(&(last_date, last)) = closes.last() else {
+        return PricePerformance::default();
+    };
+    let return_since = |start: NaiveDate| {
+        // No close on or before `start` means the history does not reach back that far
+        let (_, base) = closes.iter().rev().find(|(d, _)| *d <= start)?;
+        pct_change(Some(last), Some(*base))
+    };
+    let prior_year_end = NaiveDate::from_ymd_opt(last_date.year() - 1, 12, 31);
+
+    PricePerformance {
+        one_month: return_since(last_date - Duration::days(30)),
+        three_months: return_since(last_date - Duration::days(91)),
+        year_to_date: prior_year_end.and_then(return_since),
+        one_year: return_since(last_date - Duration::days(365)),
+    }
+}
@@ -0,0 +1,228 @@
+use crate::models::FinancialSummary;
+
+use super::statements::abbreviate;
+
+/// Years of forecast at the stage-one growth rate.
+pub const STAGE_ONE_YEARS: u32 = 5;
+/// Years over which growth then fades linearly to the terminal rate.
+pub const STAGE_TWO_YEARS: u32 = 5;
+
+pub const DEFAULT_DISCOUNT_PCT: f64 = 9.0;
+pub const DEFAULT_TERMINAL_PCT: f64 = 2.5;
+/// Stage-one growth when there is no revenue history to start from.
+pub const DEFAULT_GROWTH_PCT: f64 = 5.0;
+/// Default stage-one growth is last fiscal year's revenue growth, clamped to this range.
+const DEFAULT_GROWTH_RANGE: (f64, f64) = (0.0, 20.0);
+
+/// Search range for the growth implied by the price.
+const IMPLIED_GROWTH_RANGE: (f64, f64) = (-50.0, 100.0);
+const IMPLIED_GROWTH_ITERATIONS: usize = 60;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum DcfError {
+    #[error("no trailing free cash flow for {0}")]
+    MissingFreeCashFlow(String),
+    #[error("trailing free cash flow for {0} is {1}; a DCF needs it positive")]
+    NonPositiveFreeCashFlow(String, String),
+    #[error("no share count for {0}")]
+    MissingShares(String),
+    #[error("discount rate ({discount}%) must be above terminal growth ({terminal}%)")]
+    DiscountNotAboveTerminal { discount: f64, terminal: f64 },
+}
+
+/// Company figures the model starts from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DcfInputs {
+    /// Trailing-twelve-month free cash flow.
+    pub free_cash_flow: f64,
+    pub shares: f64,
+    /// Subtracted from enterprise value; negative for net cash.
+    pub net_debt: f64,
+}
+
+impl DcfInputs {
+    /// TTM free cash flow, shares outstanding and net debt (zero when unknown).
+    pub fn from_summary(summary: &FinancialSummary) -> Result<Self, DcfError> {
+        let symbol = || summary.symbol.clone();
+        let free_cash_flow = summary
+            .ttm
+            .free_cash_flow
+            .ok_or_else(|| DcfError::MissingFreeCashFlow(symbol()))?;
+        if free_cash_flow <= 0.0 {
+            return Err(DcfError::NonPositiveFreeCashFlow(
+                symbol(),
+                abbreviate(free_cash_flow),
+            ));
+        }
+        let shares = summary
+            .shares_outstanding
+            .filter(|s| *s > 0.0)
+            .ok_or_else(|| DcfError::MissingShares(symbol()))?;
+
+        Ok(Self {
+            free_cash_flow,
+            shares,
+            net_debt: summary.net_debt.unwrap_or(0.0),
+        })
+    }
+}
+
+/// Rates in percent.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DcfAssumptions {
+    /// Annual free cash flow growth for the first stage.
+    pub growth_pct: f64,
+    pub discount_pct: f64,
+    /// Perpetual growth after the forecast, reached at the end of stage two.
+    pub terminal_pct: f64,
+}
+
+impl DcfAssumptions {
+    /// Last fiscal year's revenue growth (clamped to 0–20%) for stage one, 9% discount and
+    /// 2.5% terminal growth.
+    pub fn defaults(summary: &FinancialSummary) -> Self {
+        let (low, high) = DEFAULT_GROWTH_RANGE;
+        Self {
+            growth_pct: summary
+                .ratios
+                .revenue_growth
+                .map_or(DEFAULT_GROWTH_PCT, |g| g.clamp(low, high)),
+            discount_pct: DEFAULT_DISCOUNT_PCT,
+            terminal_pct: DEFAULT_TERMINAL_PCT,
+        }
+    }
+
+    /// Growth applied in forecast year `year` (1-based).
+    pub fn growth_in_year(&self, year: u32) -> f64 {
+        if year <= STAGE_ONE_YEARS {
+            return self.growth_pct;
+        }
+        let faded = (year - STAGE_ONE_YEARS).min(STAGE_TWO_YEARS) as f64 / STAGE_TWO_YEARS as f64;
+        self.growth_pct + (self.terminal_pct - self.growth_pct) * faded
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DcfValuation {
+    /// Forecast free cash flow for each year of both stages.
+    pub cash_flows: Vec<f64>,
+    pub pv_cash_flows: f64,
+    pub terminal_value: f64,
+    pub pv_terminal_value: f64,
+    pub enterprise_value: f64,
+    pub equity_value: f64,
+    pub per_share: f64,
+}
+
+/// Two-stage DCF: stage-one growth, a linear fade to terminal growth, then a Gordon
+/// growth terminal value, all discounted at `discount_pct`.
+pub fn value(inputs: &DcfInputs, assumptions: &DcfAssumptions) -> Result<DcfValuation, DcfError> {
+    let discount = assumptions.discount_pct / 100.0;
+    let terminal = assumptions.terminal_pct / 100.0;
+    if discount <= terminal {
+        return Err(DcfError::DiscountNotAboveTerminal {
+            discount: assumptions.discount_pct,
+            terminal: assumptions.terminal_pct,
+        });
+    }
+
+    let years = STAGE_ONE_YEARS + STAGE_TWO_YEARS;
+    let mut cash_flows = Vec::with_capacity(years as usize);
+    let mut fcf = inputs.free_cash_flow;
+    let mut pv_cash_flows = 0.0;
+    for year in 1..=years {
+        fcf *= 1.0 + assumptions.growth_in_year(year) / 100.0;
+        pv_cash_flows += fcf / (1.0 + discount).powi(year as i32);
+        cash_flows.push(fcf);
+    }
+
+    let terminal_value = fcf * (1.0 + terminal) / (discount - terminal);
+    let pv_terminal_value = terminal_value / (1.0 + discount).powi(years as i32);
+    let enterprise_value = pv_cash_flows + pv_terminal_value;
+    let equity_value = enterprise_value - inputs.net_debt;
+
+    Ok(DcfValuation {
+        cash_flows,
+        pv_cash_flows,
+        terminal_value,
+        pv_terminal_value,
+        enterprise_value,
+        equity_value,
+        per_share: equity_value / inputs.shares,
+    })
+}
+
+/// Stage-one growth (percent) at which the DCF value equals `price`, keeping the discount
+/// and terminal rates. `None` when the price is outside what -50%…100% growth can explain.
+pub fn implied_growth(inputs: &DcfInputs, assumptions: &DcfAssumptions, price: f64) -> Option<f64> {
+    let gap = |growth_pct: f64| {
+        let trial = DcfAssumptions {
+            growth_pct,
+            ..*assumptions
+        };
+        value(inputs, &trial).ok().map(|v| v.per_share - price)
+    };
+
+    // Value rises with growth while free cash flow is positive, so bisect
+    let (mut low, mut high) = IMPLIED_GROWTH_RANGE;
+    if gap(low)? > 0.0 || gap(high)? < 0.0 {
+        return None;
+    }
+    for _ in 0..IMPLIED_GROWTH_ITERATIONS {
+        let mid = (low + high) / 2.0;
+        if gap(mid)? < 0.0 {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    Some((low + high) / 2.0)
+}
+
+/// Per-share values around the base case: rows are discount rates, columns growth rates.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SensitivityGrid {
+    pub discount_pcts: Vec<f64>,
+    pub growth_pcts: Vec<f64>,
+    /// `None` where the discount rate does not exceed terminal growth.
+    pub values: Vec<Vec<Option<f64>>>,
+}
+
+/// Grid of `2 * steps + 1` discount rates by `2 * steps + 1` growth rates centred on the
+/// base assumptions.
+pub fn sensitivity_grid(
+    inputs: &DcfInputs,
+    base: &DcfAssumptions,
+    discount_step: f64,
+    growth_step: f64,
+    steps: i32,
+) -> SensitivityGrid {
+    let around = |centre: f64, step: f64| -> Vec<f64> {
+        (-steps..=steps).map(|i| centre + i as f64 * step).collect()
+    };
+    let discount_pcts = around(base.discount_pct, discount_step);
+    let growth_pcts = around(base.growth_pct, growth_step);
+
+    let values = discount_pcts
+        .iter()
+        .map(|&discount_pct| {
+            growth_pcts
+                .iter()
+                .map(|&growth_pct| {
+                    let trial = DcfAssumptions {
+                        growth_pct,
+                        discount_pct,
+                        terminal_pct: base.terminal_pct,
+                    };
+                    value(inputs, &trial).ok().map(|v| v.per_share)
+                })
+                .collect()
+        })
+        .collect();
+
+    SensitivityGrid {
+        discount_pcts,
+        growth_pcts,
+        values,
+    }
+}
@@ -0,0 +1,111 @@
+use crate::models::{FinancialStatement, Frequency, StatementType};
+
+use super::statements::{build_statement_table, LineKind, StatementTable};
+
+/// File format for a downloadable statement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+}
+
+impl ExportFormat {
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "csv" => Some(Self::Csv),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Json => "json",
+        }
+    }
+}
+
+/// A statement rendered to a file, ready to attach.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatementExport {
+    pub filename: String,
+    pub bytes: Vec<u8>,
+}
+
+/// Every period and line item of `statement`, e.g. `AAPL-income-quarterly.csv`.
+///
+/// CSV has one row per line item in statement order with raw reported values, oldest
+/// period first. JSON is the reshaped `FinancialStatement` itself, keys sorted.
+pub fn export_statement(
+    statement: &FinancialStatement,
+    statement_type: StatementType,
+    frequency: Frequency,
+    format: ExportFormat,
+) -> Result<StatementExport, String> {
+    let bytes = match format {
+        ExportFormat::Csv => {
+            let table =
+                build_statement_table(statement, statement_type, frequency, None, usize::MAX)
+                    .ok_or_else(|| "no statement data to export".to_string())?;
+            statement_csv(&table).into_bytes()
+        }
+        ExportFormat::Json => serde_json::to_value(statement)
+            .and_then(|value| serde_json::to_vec_pretty(&value))
+            .map_err(|e| format!("failed to encode json: {e}"))?,
+    };
+
+    Ok(StatementExport {
+        filename: format!(
+            "{}-{}-{}.{}",
+            statement.symbol.to_ascii_uppercase(),
+            statement_type.as_str(),
+            statement.frequency,
+            format.extension()
+        ),
+        bytes,
+    })
+}
+
+/// `field,label,currency,<period dates...>` then one row per line item; missing
+/// values are left empty, as is the currency of share counts.
+pub fn statement_csv(table: &StatementTable) -> String {
+    let currency = table.currency.as_deref().unwrap_or_default();
+    let mut header = vec!["field".to_string(), "label".into(), "currency".into()];
+    header.extend(
+        table
+            .periods
+            .iter()
+            .map(|d| d.format("%Y-%m-%d").to_string()),
+    );
+
+    let mut out = csv_line(&header);
+    for row in &table.rows {
+        let unit = match row.kind {
+            LineKind::Shares => "",
+            _ => currency,
+        };
+        let mut cells = vec![row.field.clone(), row.label.clone(), unit.to_string()];
+        cells.extend(
+            row.values
+                .iter()
+                .map(|v| v.map(|v| v.to_string()).unwrap_or_default()),
+        );
+        out.push_str(&csv_line(&cells));
+    }
+    out
+}
+
+fn csv_line(cells: &[String]) -> String {
+    let quoted: Vec<String> = cells
+        .iter()
+        .map(|cell| {
+            if cell.contains([',', '"', '\n']) {
+                format!("\"{}\"", cell.replace('"', "\"\""))
+            } else {
+                cell.clone()
+            }
+        })
+        .collect();
+    quoted.join(",") + "\n"
+}
@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use chrono::{DateTime, Datelike, NaiveDate};
+use serde_json::Value;
+use tracing::warn;
+
+use crate::models::Frequency;
+
+use super::FinanceService;
+
+/// Period ends this many days into a month belong to the previous month; 52/53-week
+/// fiscal years (AAPL, NVDA) end on a weekday near the month end and sometimes spill over.
+const MONTH_SPILLOVER_DAYS: u32 = 7;
+
+/// A company's fiscal year, anchored on the month it ends. Fiscal years are named after
+/// the calendar year they end in, so NVDA's year ending January 2026 is FY2026.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FiscalCalendar {
+    pub year_end_month: u32,
+}
+
+impl Default for FiscalCalendar {
+    fn default() -> Self {
+        Self::CALENDAR_YEAR
+    }
+}
+
+/// The fiscal year and quarter a reporting period ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FiscalPeriod {
+    pub year: i32,
+    /// 1–4; the fourth quarter ends with the fiscal year.
+    pub quarter: u32,
+    pub end: NaiveDate,
+}
+
+impl FiscalCalendar {
+    pub const CALENDAR_YEAR: Self = Self { year_end_month: 12 };
+
+    /// Calendar for a fiscal year ending on `year_end`.
+    pub fn ending(year_end: NaiveDate) -> Self {
+        Self {
+            year_end_month: anchor_month(year_end).1,
+        }
+    }
+
+    /// Fiscal year and quarter of a period ending on `end`.
+    pub fn period(&self, end: NaiveDate) -> FiscalPeriod {
+        let (year, month) = anchor_month(end);
+        let months_into_year = (month + 12 - self.year_end_month) % 12;
+        FiscalPeriod {
+            year: if month <= self.year_end_month {
+                year
+            } else {
+                year + 1
+            },
+            quarter: match months_into_year {
+                0 => 4,
+                n => n.div_ceil(3),
+            },
+            end,
+        }
+    }
+
+    /// Whether a period ending on `end` matches the fiscal `year` and `quarter` filters.
+    pub fn matches(&self, end: NaiveDate, year: Option<i32>, quarter: Option<u32>) -> bool {
+        let period = self.period(end);
+        year.is_none_or(|y| period.year == y) && quarter.is_none_or(|q| period.quarter == q)
+    }
+}
+
+impl FiscalPeriod {
+    /// `FY2026 Q2 (ended Jul 27, 2025)`, or `FY2025 (ended Sep 27, 2025)` for annual periods.
+    pub fn label(&self, frequency: Frequency) -> String {
+        format!(
+            "{} (ended {})",
+            self.short_label(frequency),
+            self.end.format("%b %-d, %Y")
+        )
+    }
+
+    /// `FY2026 Q2` / `FY2025`.
+    pub fn short_label(&self, frequency: Frequency) -> String {
+        match frequency {
+            Frequency::Annual => format!("FY{}", self.year),
+            Frequency::Quarterly => format!("FY{} Q{}", self.year, self.quarter),
+        }
+    }
+}
+
+/// The month a period "belongs" to: `2024-02-01` counts as January 2024.
+fn anchor_month(date: NaiveDate) -> (i32, u32) {
+    if date.day() > MONTH_SPILLOVER_DAYS {
+        return (date.year(), date.month());
+    }
+    match date.month() {
+        1 => (date.year() - 1, 12),
+        m => (date.year(), m - 1),
+    }
+}
+
+/// Fiscal calendar per symbol, kept for the life of the process.
+#[derive(Default)]
+pub struct FiscalCalendarCache {
+    calendars: RwLock<HashMap<String, FiscalCalendar>>,
+}
+
+impl FiscalCalendarCache {
+    fn get(&self, symbol: &str) -> Option<FiscalCalendar> {
+        self.calendars
+            .read()
+            .ok()
+            .and_then(|c| c.get(symbol).copied())
+    }
+
+    fn insert(&self, symbol: String, calendar: FiscalCalendar) {
+        if let Ok(mut calendars) = self.calendars.write() {
+            calendars.insert(symbol, calendar);
+        }
+    }
+}
+
+impl FinanceService {
+    /// Fiscal calendar from Yahoo's `defaultKeyStatistics.lastFiscalYearEnd`. Falls back to
+    /// the calendar year (uncached) when Yahoo has no fiscal year end or the lookup fails.
+    pub async fn get_fiscal_calendar(&self, symbol: &str) -> FiscalCalendar {
+        let symbol = symbol.to_ascii_uppercase();
+        if let Some(calendar) = self.fiscal_calendars.get(&symbol) {
+            return calendar;
+        }
+
+        match self
+            .client
+            .get_quote_summary(&symbol, &["defaultKeyStatistics"])
+            .await
+        {
+            Ok(data) => match parse_fiscal_year_end(&data) {
+                Some(year_end) => {
+                    let calendar = FiscalCalendar::ending(year_end);
+                    self.fiscal_calendars.insert(symbol, calendar);
+                    calendar
+                }
+                None => FiscalCalendar::CALENDAR_YEAR,
+            },
+            Err(e) => {
+                warn!("defaultKeyStatistics failed for {}: {}", symbol, e);
+                FiscalCalendar::CALENDAR_YEAR
+            }
+        }
+    }
+}
+
+fn parse_fiscal_year_end(data: &Value) -> Option<NaiveDate> {
+    let stats = data
+        .get("quoteSummary")?
+        .get("result")?
+        .as_array()?
+        .first()?
+        .get("defaultKeyStatistics")?;
+    let timestamp = super::extract_f64_raw(stats, &["lastFiscalYearEnd"])
+        .or_else(|| super::extract_f64_raw(stats, &["nextFiscalYearEnd"]))?;
+    DateTime::from_timestamp(timestamp as i64, 0).map(|dt| dt.date_naive())
+}
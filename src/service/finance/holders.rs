@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use chrono::{DateTime, Utc};
 use finance_query_core::{HolderType, YahooError, YahooFinanceClient};
 use serde_json::Value;
@@ -39,6 +41,7 @@ pub async fn fetch_holders(
         insider_transactions: None,
         insider_purchases: None,
         insider_roster: None,
+        position_changes: Default::default(),
     };
 
     match holder_type {
@@ -47,9 +50,11 @@ pub async fn fetch_holders(
         }
         HolderType::Institutional => {
             overview.institutional_holders = parse_institutional_holders(result);
+            overview.position_changes = parse_position_changes(result, "institutionOwnership");
         }
         HolderType::MutualFund => {
             overview.mutualfund_holders = parse_mutualfund_holders(result);
+            overview.position_changes = parse_position_changes(result, "fundOwnership");
         }
         HolderType::InsiderTransactions | HolderType::InsiderPurchases => {
             let txs = parse_insider_transactions(result);
@@ -123,6 +128,25 @@ fn parse_institutional_holders(result: &Value) -> Option<Vec<InstitutionalHolder
     }
 }
 
+/// `pctChange` per organization in an `institutionOwnership` / `fundOwnership` list.
+fn parse_position_changes(result: &Value, module: &str) -> HashMap<String, f64> {
+    let Some(list) = result
+        .get(module)
+        .and_then(|v| v.get("ownershipList"))
+        .and_then(|v| v.as_array())
+    else {
+        return HashMap::new();
+    };
+
+    list.iter()
+        .filter_map(|item| {
+            let holder = item.get("organization")?.as_str()?;
+            let change = item.get("pctChange").and_then(value_to_f64)?;
+            Some((holder.to_string(), change))
+        })
+        .collect()
+}
+
 fn parse_mutualfund_holders(result: &Value) -> Option<Vec<MutualFundHolder>> {
     let list = result
         .get("fundOwnership")
@@ -230,6 +254,28 @@ fn parse_insider_transactions(result: &Value) -> Option<Vec<InsiderTransaction>>
     }
 }
 
+/// Direction of an insider transaction, read from Yahoo's transaction text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TradeSide {
+    Buy,
+    Sell,
+    /// Awards, gifts, option exercises and anything else without a clear direction.
+    Other,
+}
+
+impl TradeSide {
+    pub fn of(tx: &InsiderTransaction) -> Self {
+        let text = tx.transaction.to_lowercase();
+        if text.contains("buy") || text.contains("purchase") {
+            Self::Buy
+        } else if text.contains("sell") || text.contains("sale") {
+            Self::Sell
+        } else {
+            Self::Other
+        }
+    }
+}
+
 fn parse_insider_purchases(txs: Option<Vec<InsiderTransaction>>) -> Option<InsiderPurchase> {
     let txs = txs?;
     if txs.is_empty() {
@@ -242,14 +288,17 @@ fn parse_insider_purchases(txs: Option<Vec<InsiderTransaction>>) -> Option<Insid
     let mut sales_tx = 0;
 
     for tx in &txs {
-        let text = tx.transaction.to_lowercase();
         let shares = tx.shares.unwrap_or(0);
-        if text.contains("buy") || text.contains("purchase") {
-            purchases_shares += shares;
-            purchases_tx += 1;
-        } else if text.contains("sell") || text.contains("sale") {
-            sales_shares += shares;
-            sales_tx += 1;
+        match TradeSide::of(tx) {
+            TradeSide::Buy => {
+                purchases_shares += shares;
+                purchases_tx += 1;
+            }
+            TradeSide::Sell => {
+                sales_shares += shares;
+                sales_tx += 1;
+            }
+            TradeSide::Other => {}
         }
     }
 
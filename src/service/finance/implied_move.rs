@@ -0,0 +1,309 @@
+use std::f64::consts::PI;
+
+use chrono::{NaiveDate, NaiveDateTime, Utc};
+use chrono_tz::America::New_York;
+use finance_query_core::OptionContract;
+
+use super::options::OptionSlice;
+use super::{FinanceService, FinanceServiceError};
+
+/// Strikes fetched on each side of spot when pricing the straddle.
+const STRIKES_EACH_SIDE: usize = 3;
+
+/// Bid/ask spread, as a share of the straddle mid, above which the quote is flagged.
+pub const MAX_SPREAD_RATIO: f64 = 0.20;
+
+/// Reasons an implied move should be read with caution.
+#[derive(Debug, Clone, PartialEq)]
+pub enum QuoteFlag {
+    /// Straddle bid/ask spread exceeds `MAX_SPREAD_RATIO` of the mid.
+    WideSpread { ratio: f64 },
+    /// At least one leg had no two-sided market, so `last_price` was used.
+    LastPriceFallback,
+    /// Spot could not be bracketed by two strikes; the nearest strike was used.
+    SingleStrike,
+    /// No later expiration to separate the earnings move from regular volatility.
+    NoBaselineExpiry,
+    /// Later expiry is not cheaper in variance per day, so the event share is undefined.
+    InvertedTermStructure,
+}
+
+impl QuoteFlag {
+    pub fn label(&self) -> String {
+        match self {
+            QuoteFlag::WideSpread { ratio } => format!("wide spread {:.0}%", ratio * 100.0),
+            QuoteFlag::LastPriceFallback => "stale last price".to_string(),
+            QuoteFlag::SingleStrike => "single strike".to_string(),
+            QuoteFlag::NoBaselineExpiry => "no baseline expiry".to_string(),
+            QuoteFlag::InvertedTermStructure => "inverted term structure".to_string(),
+        }
+    }
+
+    /// Whether the straddle price itself is unreliable (as opposed to the event split).
+    pub fn is_pricing_issue(&self) -> bool {
+        matches!(
+            self,
+            QuoteFlag::WideSpread { .. } | QuoteFlag::LastPriceFallback
+        )
+    }
+}
+
+/// ATM straddle for one expiration, interpolated to spot between the bracketing strikes.
+#[derive(Debug, Clone)]
+pub struct AtmStraddle {
+    pub spot: f64,
+    pub lower_strike: f64,
+    pub upper_strike: f64,
+    /// Straddle mid price at spot.
+    pub price: f64,
+    /// ATM IV (average of call and put IVs) at spot.
+    pub iv: f64,
+    /// Combined bid/ask spread as a share of the mid; `None` when a leg fell back to last.
+    pub spread_ratio: Option<f64>,
+    pub flags: Vec<QuoteFlag>,
+}
+
+/// Expected earnings move for the first expiry after the report.
+#[derive(Debug, Clone)]
+pub struct ImpliedMove {
+    pub expiration: NaiveDate,
+    pub days_to_expiry: i64,
+    pub straddle: AtmStraddle,
+    /// Straddle / spot, in percent: the market's total expected move to expiry.
+    pub implied_move_pct: f64,
+    /// IV of the regular (non-event) term structure backed out of the later expiry.
+    pub base_iv: Option<f64>,
+    /// Expected absolute move attributable to the earnings event alone, in percent.
+    pub earnings_move_pct: Option<f64>,
+    pub flags: Vec<QuoteFlag>,
+}
+
+impl ImpliedMove {
+    /// Whether any flag says the straddle price cannot be trusted.
+    pub fn is_unreliable(&self) -> bool {
+        self.flags.iter().any(QuoteFlag::is_pricing_issue)
+    }
+}
+
+impl FinanceService {
+    /// Implied move for an earnings event at `earnings_at` (ET wall clock), priced from the
+    /// first expiry after the event with the regular volatility backed out of the next one.
+    pub async fn get_earnings_implied_move(
+        &self,
+        symbol: &str,
+        earnings_at: NaiveDateTime,
+    ) -> Result<ImpliedMove, FinanceServiceError> {
+        let expirations = self.get_option_expirations(symbol).await?;
+        let (expiration, baseline_expiration) = event_expirations(&expirations, earnings_at)
+            .ok_or_else(|| {
+                FinanceServiceError::NotFound(format!(
+                    "no option expiry after earnings for symbol {symbol}"
+                ))
+            })?;
+
+        let slice = self
+            .get_option_chain_near(symbol, expiration, STRIKES_EACH_SIDE)
+            .await?;
+        let straddle = atm_straddle(&slice).ok_or_else(|| {
+            FinanceServiceError::NotFound(format!("no ATM straddle for {symbol} {expiration}"))
+        })?;
+
+        let days_to_expiry = days_until(expiration);
+        let mut flags = straddle.flags.clone();
+
+        let baseline = match baseline_expiration {
+            Some(later) => match self
+                .get_option_chain_near(symbol, later, STRIKES_EACH_SIDE)
+                .await
+            {
+                Ok(later_slice) => atm_straddle(&later_slice).map(|s| (days_until(later), s.iv)),
+                Err(e) => {
+                    tracing::warn!("baseline chain failed for {} {}: {}", symbol, later, e);
+                    None
+                }
+            },
+            None => None,
+        };
+
+        let (base_iv, earnings_move_pct) =
+            split_against_baseline(straddle.iv, days_to_expiry, baseline, &mut flags);
+
+        let implied_move_pct = straddle.price / straddle.spot * 100.0;
+
+        Ok(ImpliedMove {
+            expiration,
+            days_to_expiry,
+            straddle,
+            implied_move_pct,
+            base_iv,
+            earnings_move_pct,
+            flags,
+        })
+    }
+}
+
+/// First expiry settling after the event (16:00 ET on expiration day) and the one after
+/// it, which serves as the regular-volatility baseline.
+pub fn event_expirations(
+    expirations: &[NaiveDate],
+    earnings_at: NaiveDateTime,
+) -> Option<(NaiveDate, Option<NaiveDate>)> {
+    let mut after_event = expirations
+        .iter()
+        .copied()
+        .filter(|e| e.and_hms_opt(16, 0, 0).is_some_and(|dt| dt > earnings_at));
+    let expiration = after_event.next()?;
+    Some((expiration, after_event.next()))
+}
+
+/// Base IV and event move (percent) from the event expiry and the baseline expiry's
+/// `(days, iv)`, flagging a missing baseline or an inverted term structure.
+pub fn split_against_baseline(
+    event_iv: f64,
+    event_days: i64,
+    baseline: Option<(i64, f64)>,
+    flags: &mut Vec<QuoteFlag>,
+) -> (Option<f64>, Option<f64>) {
+    let Some((later_days, later_iv)) = baseline else {
+        flags.push(QuoteFlag::NoBaselineExpiry);
+        return (None, None);
+    };
+    match split_event_variance(event_iv, event_days, later_iv, later_days) {
+        Some((base_iv, event_move)) => (Some(base_iv), Some(event_move * 100.0)),
+        None => {
+            flags.push(QuoteFlag::InvertedTermStructure);
+            (None, None)
+        }
+    }
+}
+
+/// Mid price of a contract and its bid/ask spread; falls back to `last_price` (spread
+/// `None`) when there is no two-sided market.
+pub fn contract_mid(contract: &OptionContract) -> (f64, Option<f64>) {
+    if contract.bid > 0.0 && contract.ask >= contract.bid {
+        (
+            (contract.bid + contract.ask) / 2.0,
+            Some(contract.ask - contract.bid),
+        )
+    } else {
+        (contract.last_price, None)
+    }
+}
+
+/// Price the ATM straddle at spot by interpolating linearly between the two strikes that
+/// bracket spot, using bid/ask mids for both legs.
+pub fn atm_straddle(slice: &OptionSlice) -> Option<AtmStraddle> {
+    let spot = slice.spot;
+    if spot <= 0.0 {
+        return None;
+    }
+
+    struct StrikeQuote {
+        strike: f64,
+        price: f64,
+        spread: Option<f64>,
+        iv: f64,
+    }
+
+    let quotes: Vec<StrikeQuote> = slice
+        .calls
+        .iter()
+        .filter_map(|call| {
+            let put = slice.puts.iter().find(|p| p.strike == call.strike)?;
+            let (call_mid, call_spread) = contract_mid(call);
+            let (put_mid, put_spread) = contract_mid(put);
+            let price = call_mid + put_mid;
+            if price <= 0.0 {
+                return None;
+            }
+            Some(StrikeQuote {
+                strike: call.strike,
+                price,
+                spread: call_spread.zip(put_spread).map(|(c, p)| c + p),
+                iv: (call.implied_volatility + put.implied_volatility) / 2.0,
+            })
+        })
+        .collect();
+
+    let lower = quotes
+        .iter()
+        .filter(|q| q.strike <= spot)
+        .max_by(|a, b| a.strike.total_cmp(&b.strike));
+    let upper = quotes
+        .iter()
+        .filter(|q| q.strike >= spot)
+        .min_by(|a, b| a.strike.total_cmp(&b.strike));
+
+    let mut flags = Vec::new();
+    let (lo, hi, weight) = match (lower, upper) {
+        (Some(lo), Some(hi)) if hi.strike > lo.strike => {
+            (lo, hi, (spot - lo.strike) / (hi.strike - lo.strike))
+        }
+        (Some(q), Some(_)) => (q, q, 0.0),
+        (Some(q), None) | (None, Some(q)) => {
+            flags.push(QuoteFlag::SingleStrike);
+            (q, q, 0.0)
+        }
+        (None, None) => return None,
+    };
+
+    let lerp = |a: f64, b: f64| a + (b - a) * weight;
+    let price = lerp(lo.price, hi.price);
+    let iv = lerp(lo.iv, hi.iv);
+
+    let spread_ratio = match (lo.spread, hi.spread) {
+        (Some(a), Some(b)) => Some(lerp(a, b) / price),
+        _ => {
+            flags.push(QuoteFlag::LastPriceFallback);
+            None
+        }
+    };
+    if let Some(ratio) = spread_ratio.filter(|r| *r > MAX_SPREAD_RATIO) {
+        flags.push(QuoteFlag::WideSpread { ratio });
+    }
+
+    Some(AtmStraddle {
+        spot,
+        lower_strike: lo.strike,
+        upper_strike: hi.strike,
+        price,
+        iv,
+        spread_ratio,
+        flags,
+    })
+}
+
+/// Split the event expiry's total variance into regular and earnings parts.
+///
+/// Both expiries contain the event, so the forward variance between them is the regular
+/// (base) variance; the event expiry's excess over base × time is the one-day earnings
+/// variance. Returns `(base_iv, expected_abs_event_move)` with the move as a fraction.
+pub fn split_event_variance(
+    event_iv: f64,
+    event_days: i64,
+    later_iv: f64,
+    later_days: i64,
+) -> Option<(f64, f64)> {
+    let t1 = event_days.max(1) as f64 / 365.0;
+    let t2 = later_days.max(1) as f64 / 365.0;
+    if t2 <= t1 {
+        return None;
+    }
+
+    let base_var = (later_iv * later_iv * t2 - event_iv * event_iv * t1) / (t2 - t1);
+    if base_var <= 0.0 {
+        return None;
+    }
+    let event_var = event_iv * event_iv * t1 - base_var * t1;
+    if event_var <= 0.0 {
+        return None;
+    }
+
+    // Expected absolute move of a normal jump: σ · √(2/π)
+    Some((base_var.sqrt(), event_var.sqrt() * (2.0 / PI).sqrt()))
+}
+
+fn days_until(expiration: NaiveDate) -> i64 {
+    let today = Utc::now().with_timezone(&New_York).date_naive();
+    (expiration - today).num_days()
+}
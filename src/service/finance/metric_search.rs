@@ -0,0 +1,263 @@
+use finance_query_core::utils::financials_constants::{
+    BALANCE_SHEET_FIELDS, CASH_FLOW_FIELDS, INCOME_STATEMENT_FIELDS,
+};
+
+use crate::models::StatementType;
+
+use super::statements::{field_label, layout, normalize_name, METRIC_ALIASES};
+
+/// A guess must beat the runner-up by this much to be used without asking.
+const CONFIDENT_MARGIN: u32 = 100;
+/// Lowest score a guess can be used with, i.e. roughly a whole-word or prefix match.
+const CONFIDENT_SCORE: u32 = 600;
+/// Field name or alias typed exactly.
+const EXACT_SCORE: u32 = 1000;
+/// Display label typed exactly; an alias for another field still wins.
+const LABEL_SCORE: u32 = 950;
+
+/// A statement field ranked against a query; higher scores are better.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetricMatch {
+    pub field: &'static str,
+    pub label: String,
+    pub score: u32,
+}
+
+impl MetricMatch {
+    /// Option value used by the slash commands, e.g. `net_income`.
+    pub fn slash_value(&self) -> String {
+        slash_value(self.field)
+    }
+}
+
+/// Outcome of resolving free text to a single statement field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MetricLookup {
+    /// The query is a field name, label or alias.
+    Exact(MetricMatch),
+    /// One field clearly outranks the rest; callers should say what they picked.
+    Guessed(MetricMatch),
+    /// Several fields are plausible, best first.
+    Ambiguous(Vec<MetricMatch>),
+    NotFound,
+}
+
+pub fn statement_fields(statement_type: StatementType) -> &'static [&'static str] {
+    match statement_type {
+        StatementType::IncomeStatement => INCOME_STATEMENT_FIELDS,
+        StatementType::BalanceSheet => BALANCE_SHEET_FIELDS,
+        StatementType::CashFlow => CASH_FLOW_FIELDS,
+    }
+}
+
+/// Display label: the statement layout's label when it has one, else the split field name.
+pub fn metric_label(statement_type: StatementType, field: &str) -> String {
+    layout(statement_type)
+        .iter()
+        .find(|item| item.field == field)
+        .map(|item| item.label.to_string())
+        .unwrap_or_else(|| field_label(field))
+}
+
+/// `NetIncome` → `net_income`, `EBITDA` → `ebitda`.
+pub fn slash_value(field: &str) -> String {
+    field_label(field).to_ascii_lowercase().replace(' ', "_")
+}
+
+/// Fields of `statement_type` matching `query`, best first. Ties keep the shorter
+/// (usually more general) field first. An empty query lists the statement layout.
+pub fn search_metrics(
+    statement_type: StatementType,
+    query: &str,
+    limit: usize,
+) -> Vec<MetricMatch> {
+    let fields = statement_fields(statement_type);
+    if normalize_name(query).is_empty() {
+        return layout(statement_type)
+            .iter()
+            .filter(|item| fields.contains(&item.field))
+            .take(limit)
+            .map(|item| MetricMatch {
+                field: item.field,
+                label: item.label.to_string(),
+                score: 0,
+            })
+            .collect();
+    }
+
+    let mut matches: Vec<MetricMatch> = fields
+        .iter()
+        .filter_map(|field| {
+            let label = metric_label(statement_type, field);
+            let score = score_field(query, field, &label);
+            (score > 0).then_some(MetricMatch {
+                field,
+                label,
+                score,
+            })
+        })
+        .collect();
+    matches.sort_by(|a, b| {
+        b.score
+            .cmp(&a.score)
+            .then(a.field.len().cmp(&b.field.len()))
+    });
+    matches.truncate(limit);
+    matches
+}
+
+/// Resolve `query` to one field of `statement_type`, or say why it cannot.
+pub fn resolve_metric(statement_type: StatementType, query: &str) -> MetricLookup {
+    let mut matches = search_metrics(statement_type, query, 5);
+    if normalize_name(query).is_empty() || matches.is_empty() {
+        return MetricLookup::NotFound;
+    }
+    let best = &matches[0];
+    let runner_up = matches.get(1).map_or(0, |m| m.score);
+    if best.score >= LABEL_SCORE && runner_up < best.score {
+        MetricLookup::Exact(matches.swap_remove(0))
+    } else if best.score >= CONFIDENT_SCORE && best.score >= runner_up + CONFIDENT_MARGIN {
+        MetricLookup::Guessed(matches.swap_remove(0))
+    } else {
+        MetricLookup::Ambiguous(matches)
+    }
+}
+
+/// Best score of `query` against the field's name, label and aliases: exact 950–1000,
+/// near-exact typo ~850, name prefix 600–800, word prefixes (`net inc`, `opinc`)
+/// 500–800 by how much of the label is covered, substring 400–600, and in-order
+/// characters 200–400.
+fn score_field(query: &str, field: &str, label: &str) -> u32 {
+    let wanted = normalize_name(query);
+    let name = normalize_name(field);
+    let aliases: Vec<&str> = METRIC_ALIASES
+        .iter()
+        .filter(|(_, target)| *target == field)
+        .map(|(alias, _)| *alias)
+        .collect();
+
+    if wanted == name || aliases.contains(&wanted.as_str()) {
+        return EXACT_SCORE;
+    }
+    if wanted == normalize_name(label) {
+        return LABEL_SCORE;
+    }
+
+    let mut best = 0;
+    for candidate in std::iter::once(name.as_str()).chain(aliases.iter().copied()) {
+        let distance = levenshtein(&wanted, candidate);
+        if distance > 0 && distance <= typo_budget(candidate) {
+            best = best.max(900 - 50 * distance as u32);
+        }
+    }
+    if name.starts_with(&wanted) {
+        best = best.max(600 + ratio(200, wanted.len(), name.len()));
+    }
+    best = best.max(word_score(query, label));
+    best = best.max(abbreviation_score(&wanted, label));
+    if wanted.len() >= 3 && name.contains(&wanted) {
+        best = best.max(400 + ratio(200, wanted.len(), name.len()));
+    }
+    if wanted.len() >= 3 && is_subsequence(&wanted, &name) {
+        best = best.max(200 + ratio(200, wanted.len(), name.len()));
+    }
+    best
+}
+
+/// Every query word must start (or, allowing a typo, nearly equal) a different label
+/// word; the score grows with the share of the label covered.
+fn word_score(query: &str, label: &str) -> u32 {
+    let query_words: Vec<String> = words(query);
+    let mut label_words: Vec<Option<String>> = words(label).into_iter().map(Some).collect();
+    if query_words.is_empty() || label_words.is_empty() {
+        return 0;
+    }
+    let total = label_words.len();
+
+    let mut typos = 0;
+    for word in &query_words {
+        // Some(true) when the word only matches allowing a typo
+        let hit = label_words.iter_mut().find_map(|slot| {
+            let candidate = slot.as_deref()?;
+            let hit = if candidate.starts_with(word.as_str()) {
+                Some(false)
+            } else if word.len() >= 4 && levenshtein(word, candidate) <= typo_budget(candidate) {
+                Some(true)
+            } else {
+                None
+            };
+            if hit.is_some() {
+                *slot = None;
+            }
+            hit
+        });
+        match hit {
+            Some(false) => {}
+            Some(true) => typos += 1,
+            None => return 0,
+        }
+    }
+
+    (500 + ratio(300, query_words.len(), total)).saturating_sub(50 * typos)
+}
+
+/// `opinc` read as prefixes of successive label words (`Op`erating `Inc`ome), each at
+/// least two characters; label words may be skipped.
+fn abbreviation_score(wanted: &str, label: &str) -> u32 {
+    fn split(rest: &str, words: &[String]) -> Option<usize> {
+        if rest.is_empty() {
+            return Some(0);
+        }
+        let (word, tail) = words.split_first()?;
+        let longest = (2..=rest.len().min(word.len()))
+            .rev()
+            .filter(|&n| word.starts_with(&rest[..n]))
+            .find_map(|n| split(&rest[n..], tail).map(|used| used + 1));
+        longest.or_else(|| split(rest, tail))
+    }
+
+    let label_words = words(label);
+    match split(wanted, &label_words) {
+        Some(used) if used > 1 => 500 + ratio(300, used, label_words.len()),
+        _ => 0,
+    }
+}
+
+fn words(text: &str) -> Vec<String> {
+    field_label(text)
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_ascii_lowercase())
+        .collect()
+}
+
+fn typo_budget(word: &str) -> usize {
+    match word.len() {
+        0..=3 => 0,
+        4..=7 => 1,
+        _ => 2,
+    }
+}
+
+fn ratio(scale: u32, part: usize, whole: usize) -> u32 {
+    (scale as usize * part.min(whole) / whole.max(1)) as u32
+}
+
+fn is_subsequence(needle: &str, haystack: &str) -> bool {
+    let mut rest = haystack.chars();
+    needle.chars().all(|c| rest.any(|h| h == c))
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut row = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            row.push((prev[j] + cost).min(prev[j + 1] + 1).min(row[j] + 1));
+        }
+        prev = row;
+    }
+    prev[b.len()]
+}
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 
 use finance_query_core::{FetchClient, YahooAuthManager, YahooError, YahooFinanceClient};
 use serde_json::Value;
@@ -8,11 +8,23 @@ use crate::models::{
     StatementType,
 };
 
+pub mod chart;
+pub mod compare;
+pub mod dcf;
 pub mod earnings;
+pub mod export;
+pub mod fiscal;
 pub mod fundamentals;
 pub mod holders;
+pub mod implied_move;
+pub mod metric_search;
 pub mod news;
 pub mod options;
+pub mod profile;
+pub mod ratios;
+pub mod statements;
+pub mod strategy;
+pub mod volatility;
 
 #[derive(Debug, thiserror::Error)]
 pub enum FinanceServiceError {
@@ -26,6 +38,11 @@ pub enum FinanceServiceError {
 
 pub struct FinanceService {
     client: Arc<YahooFinanceClient>,
+    earnings_sources: earnings::EarningsSourceChain,
+    earnings_calendar: Arc<earnings::YahooCalendarSource>,
+    sectors: profile::SectorCache,
+    fiscal_calendars: fiscal::FiscalCalendarCache,
+    news_summariser: RwLock<Arc<dyn news::NewsSummariser>>,
     #[allow(dead_code)]
     auth: Arc<YahooAuthManager>,
     #[allow(dead_code)]
@@ -39,13 +56,52 @@ impl FinanceService {
         let auth = Arc::new(YahooAuthManager::new(proxy, fetch.cookie_jar().clone()));
         let client = Arc::new(YahooFinanceClient::new(auth.clone(), fetch.clone()));
 
+        // Earnings: configured API first, then Yahoo calendarEvents for the watchlist
+        let earnings_calendar = Arc::new(earnings::YahooCalendarSource::new(
+            client.clone(),
+            env_watchlist(),
+        ));
+        let mut sources: Vec<Arc<dyn earnings::EarningsSource>> = Vec::new();
+        match earnings::ApiEarningsSource::from_env() {
+            Some(api) => sources.push(Arc::new(api)),
+            None => tracing::info!(
+                "EARNINGS_API_URL not set; earnings calendar limited to watchlist symbols"
+            ),
+        }
+        sources.push(earnings_calendar.clone());
+
         Ok(Self {
             client,
+            earnings_sources: earnings::EarningsSourceChain::new(sources),
+            earnings_calendar,
+            sectors: profile::SectorCache::default(),
+            fiscal_calendars: fiscal::FiscalCalendarCache::default(),
+            news_summariser: RwLock::new(Arc::new(news::ExtractiveSummariser::default())),
             auth,
             fetch,
         })
     }
 
+    /// Source of the symbols scanned by the Yahoo earnings calendar fallback, reloaded
+    /// before every fallback fetch. Without one only `WATCHLIST_SYMBOLS` is scanned.
+    pub fn set_earnings_watchlist(&self, provider: Arc<dyn earnings::WatchlistProvider>) {
+        self.earnings_calendar.set_provider(provider);
+    }
+
+    /// Replace the summariser used for post-earnings news digests.
+    pub fn set_news_summariser(&self, summariser: Arc<dyn news::NewsSummariser>) {
+        if let Ok(mut current) = self.news_summariser.write() {
+            *current = summariser;
+        }
+    }
+
+    pub(crate) fn news_summariser(&self) -> Arc<dyn news::NewsSummariser> {
+        match self.news_summariser.read() {
+            Ok(current) => current.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        }
+    }
+
     /// Access the underlying YahooFinanceClient.
     pub fn client(&self) -> &YahooFinanceClient {
         self.client.as_ref()
@@ -69,24 +125,36 @@ impl FinanceService {
         })
     }
 
-    /// Fetch key financial metrics for a symbol.
+    /// Fetch key financial metrics, TTM figures and derived ratios for a symbol.
     pub async fn get_financials(
         &self,
         symbol: &str,
     ) -> Result<FinancialSummary, FinanceServiceError> {
-        let fundamentals = fundamentals::fetch_fundamentals_timeseries(
-            self.client.as_ref(),
-            symbol,
-            StatementType::IncomeStatement,
-            Frequency::Annual,
-            5,
-        )
-        .await
-        .ok();
-
-        let summary = self
-            .client
-            .get_quote_summary(
+        let client = self.client.as_ref();
+        let statement = |statement_type, frequency, years| async move {
+            let raw = fundamentals::fetch_fundamentals_timeseries(
+                client,
+                symbol,
+                statement_type,
+                frequency,
+                years,
+            )
+            .await
+            .ok()?;
+            fundamentals::reshape_timeseries_to_financial_statements(&raw)
+                .into_iter()
+                .find(|s| {
+                    s.statement_type == statement_type.as_str() && s.frequency == frequency.as_str()
+                })
+        };
+
+        // Two years of quarters covers the four in the TTM sums
+        let (annual_income, income_q, balance_q, cash_flow_q, summary) = tokio::join!(
+            statement(StatementType::IncomeStatement, Frequency::Annual, 5),
+            statement(StatementType::IncomeStatement, Frequency::Quarterly, 2),
+            statement(StatementType::BalanceSheet, Frequency::Quarterly, 2),
+            statement(StatementType::CashFlow, Frequency::Quarterly, 2),
+            client.get_quote_summary(
                 symbol,
                 &[
                     "price",
@@ -94,8 +162,9 @@ impl FinanceService {
                     "summaryDetail",
                     "financialData",
                 ],
-            )
-            .await?;
+            ),
+        );
+        let summary = summary?;
 
         let result = summary
             .get("quoteSummary")
@@ -104,9 +173,9 @@ impl FinanceService {
             .and_then(|arr| arr.first())
             .ok_or_else(|| FinanceServiceError::NotFound(symbol.to_string()))?;
 
-        let revenue = fundamentals
+        let revenue = annual_income
             .as_ref()
-            .and_then(|v| extract_timeseries_latest(v, "annualTotalRevenue"))
+            .and_then(|s| ratios::latest_value(s, "TotalRevenue"))
             .or_else(|| extract_financial_data_f64(result, "financialData", "totalRevenue"));
 
         let eps = extract_f64_raw(result, &["defaultKeyStatistics", "trailingEps"])
@@ -118,12 +187,54 @@ impl FinanceService {
         let market_cap = extract_f64_raw(result, &["price", "marketCap"])
             .or_else(|| extract_f64_raw(result, &["summaryDetail", "marketCap"]));
 
+        let price = extract_f64_raw(result, &["price", "regularMarketPrice"])
+            .or_else(|| extract_financial_data_f64(result, "financialData", "currentPrice"));
+
         let currency = result
             .get("price")
             .and_then(|p| p.get("currency"))
             .and_then(|c| c.as_str())
             .map(|s| s.to_string());
 
+        let ttm = ratios::ttm_figures(income_q.as_ref(), cash_flow_q.as_ref());
+        let balance = balance_q
+            .as_ref()
+            .map(ratios::BalanceSnapshot::from_statement)
+            .unwrap_or_default();
+        let eps_growth = annual_income
+            .as_ref()
+            .and_then(|s| ratios::latest_growth(s, "DilutedEPS"));
+
+        let enterprise_value = balance_q
+            .as_ref()
+            .and_then(|_| ratios::enterprise_value(market_cap, &balance))
+            .or_else(|| extract_f64_raw(result, &["defaultKeyStatistics", "enterpriseValue"]));
+
+        let shares_outstanding = balance
+            .shares_outstanding
+            .or_else(|| {
+                income_q
+                    .as_ref()
+                    .and_then(|s| ratios::latest_value(s, "DilutedAverageShares"))
+            })
+            .or_else(|| extract_f64_raw(result, &["defaultKeyStatistics", "sharesOutstanding"]));
+
+        let mut derived = ratios::compute_ratios(
+            &ttm,
+            &balance,
+            market_cap,
+            enterprise_value,
+            pe_ratio,
+            eps_growth,
+        );
+        derived.peg = derived
+            .peg
+            .or_else(|| extract_f64_raw(result, &["defaultKeyStatistics", "pegRatio"]));
+        derived.revenue_growth = annual_income
+            .as_ref()
+            .and_then(|s| ratios::latest_growth(s, "TotalRevenue"));
+        derived.eps_growth = eps_growth;
+
         Ok(FinancialSummary {
             symbol: symbol.to_uppercase(),
             revenue,
@@ -131,6 +242,12 @@ impl FinanceService {
             pe_ratio,
             market_cap,
             currency,
+            price,
+            enterprise_value,
+            shares_outstanding,
+            net_debt: ratios::net_debt(&balance),
+            ttm,
+            ratios: derived,
         })
     }
 
@@ -180,16 +297,26 @@ impl FinanceService {
         Ok(items)
     }
 
-    /// Fetch earnings events for a date range (external API).
+    /// Fetch earnings events for a date range (API, falling back to the Yahoo calendar).
     pub async fn get_earnings_range(
         &self,
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> Result<Vec<EarningsEvent>, FinanceServiceError> {
-        earnings::fetch_earnings_range(from, to).await
+        self.earnings_sources.fetch_range(from, to).await
     }
 }
 
+/// Symbols from `WATCHLIST_SYMBOLS` (comma separated), upper-cased.
+fn env_watchlist() -> Vec<String> {
+    std::env::var("WATCHLIST_SYMBOLS")
+        .unwrap_or_default()
+        .split(',')
+        .map(|s| s.trim().to_ascii_uppercase())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
 /// Extract the first simple quote from the Yahoo response into our bot-facing struct.
 fn extract_simple_quote(data: &Value) -> Option<PriceQuote> {
     let result = data
@@ -221,31 +348,6 @@ fn extract_simple_quote(data: &Value) -> Option<PriceQuote> {
     })
 }
 
-fn extract_timeseries_latest(data: &Value, field: &str) -> Option<f64> {
-    let results = data
-        .get("timeseries")
-        .and_then(|t| t.get("result"))
-        .and_then(|r| r.as_array())?;
-
-    for entry in results {
-        if let Some(values) = entry.get(field).and_then(|v| v.as_array()) {
-            for item in values {
-                if let Some(raw) = item
-                    .get("reportedValue")
-                    .and_then(|rv| rv.get("raw"))
-                    .and_then(|r| r.as_f64())
-                {
-                    return Some(raw);
-                }
-                if let Some(raw) = item.get("raw").and_then(|r| r.as_f64()) {
-                    return Some(raw);
-                }
-            }
-        }
-    }
-    None
-}
-
 fn extract_f64_raw(root: &Value, path: &[&str]) -> Option<f64> {
     let mut current = root;
     for key in path {
@@ -1,10 +1,72 @@
-use chrono::{DateTime, Utc};
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, NaiveTime, TimeZone, Utc};
+use chrono_tz::America::New_York;
 use finance_query_core::{YahooError, YahooFinanceClient};
+use futures_util::future::join_all;
 use serde_json::Value;
+use tracing::warn;
 
 use std::cmp::Ordering;
 
-use crate::models::NewsItem;
+use crate::models::{EarningsEvent, EarningsSession, NewsItem};
+use crate::service::finance::{FinanceService, FinanceServiceError};
+
+/// Search results requested per symbol when building an earnings digest.
+const DIGEST_FETCH_LIMIT: usize = 20;
+
+/// Stories kept per symbol in an earnings digest.
+pub const DIGEST_ITEMS_PER_SYMBOL: usize = 5;
+
+/// Concurrent news searches while building a digest.
+const NEWS_CONCURRENCY: usize = 6;
+
+/// Lower-cased headline words and phrases that mark a story as being about the report.
+const EARNINGS_NEWS_KEYWORDS: &[&str] = &[
+    "earnings",
+    "results",
+    "quarter",
+    "quarterly",
+    "q1",
+    "q2",
+    "q3",
+    "q4",
+    "eps",
+    "revenue",
+    "sales",
+    "profit",
+    "guidance",
+    "outlook",
+    "forecast",
+    "beats",
+    "misses",
+    "tops",
+    "conference call",
+    "earnings call",
+    "transcript",
+];
+
+/// Publishers in digest order; anything unlisted sorts after them. Matched
+/// case-insensitively as a prefix, so `Reuters` also covers `Reuters Breakingviews`.
+const PUBLISHER_RANK: &[&str] = &[
+    "reuters",
+    "bloomberg",
+    "the wall street journal",
+    "dow jones",
+    "financial times",
+    "associated press",
+    "cnbc",
+    "barron's",
+    "marketwatch",
+    "investor's business daily",
+    "yahoo finance",
+    "business insider",
+    "benzinga",
+    "zacks",
+    "the motley fool",
+    "gurufocus",
+];
 
 /// Fetch news items for a symbol via Yahoo search.
 pub async fn fetch_news(
@@ -67,12 +129,19 @@ fn parse_news(data: &Value, limit: usize) -> Vec<NewsItem> {
                     .map(|s| s.to_string())
             });
 
+        let summary = item
+            .get("summary")
+            .and_then(|v| v.as_str())
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty());
+
         items.push(NewsItem {
             title,
             link,
             source,
             published_at,
             thumbnail,
+            summary,
         });
     }
 
@@ -90,3 +159,216 @@ fn parse_news(data: &Value, limit: usize) -> Vec<NewsItem> {
 
     items
 }
+
+/// Turns one company's post-earnings stories into digest text. The default is
+/// [`ExtractiveSummariser`]; a model-backed implementation can be installed with
+/// `FinanceService::set_news_summariser`.
+#[async_trait]
+pub trait NewsSummariser: Send + Sync {
+    /// Short label used in logs.
+    fn name(&self) -> &'static str;
+
+    /// Summary of `items` (already deduplicated and ranked, best first).
+    async fn summarise(
+        &self,
+        symbol: &str,
+        items: &[NewsItem],
+    ) -> Result<String, FinanceServiceError>;
+}
+
+/// Quotes the opening sentences of the top stories' lead paragraphs (the headline when
+/// the provider sends no summary).
+pub struct ExtractiveSummariser {
+    pub sentences: usize,
+    pub stories: usize,
+}
+
+impl Default for ExtractiveSummariser {
+    fn default() -> Self {
+        Self {
+            sentences: 2,
+            stories: 2,
+        }
+    }
+}
+
+#[async_trait]
+impl NewsSummariser for ExtractiveSummariser {
+    fn name(&self) -> &'static str {
+        "extractive"
+    }
+
+    async fn summarise(
+        &self,
+        _symbol: &str,
+        items: &[NewsItem],
+    ) -> Result<String, FinanceServiceError> {
+        let lines: Vec<String> = items
+            .iter()
+            .take(self.stories)
+            .map(|item| {
+                let text = item
+                    .summary
+                    .as_deref()
+                    .map(|s| first_sentences(s, self.sentences))
+                    .filter(|s| !s.is_empty())
+                    .unwrap_or_else(|| item.title.clone());
+                format!("> {}", text)
+            })
+            .collect();
+        Ok(lines.join("\n"))
+    }
+}
+
+/// The first `count` sentences of `text` (split after `.`, `!` or `?` followed by
+/// whitespace, so decimals like `1.46` stay intact).
+pub fn first_sentences(text: &str, count: usize) -> String {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    let mut end = text.len();
+    let mut found = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((idx, c)) = chars.next() {
+        if matches!(c, '.' | '!' | '?') && chars.peek().is_none_or(|(_, next)| *next == ' ') {
+            found += 1;
+            if found == count {
+                end = idx + c.len_utf8();
+                break;
+            }
+        }
+    }
+    text[..end].to_string()
+}
+
+/// One company's section of the post-earnings news digest.
+#[derive(Debug, Clone)]
+pub struct EarningsNewsDigest {
+    pub symbol: String,
+    pub items: Vec<NewsItem>,
+    pub summary: Option<String>,
+}
+
+/// Whether a headline is about an earnings report (keywords matched as whole words).
+pub fn is_earnings_related(item: &NewsItem) -> bool {
+    let title: String = item
+        .title
+        .to_ascii_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { ' ' })
+        .collect();
+    let words = title.split_whitespace().collect::<Vec<_>>().join(" ");
+    let padded = format!(" {} ", words);
+    EARNINGS_NEWS_KEYWORDS
+        .iter()
+        .any(|keyword| padded.contains(&format!(" {} ", keyword)))
+}
+
+/// Position of the publisher in `PUBLISHER_RANK`; unknown publishers rank last.
+pub fn publisher_rank(source: Option<&str>) -> usize {
+    let Some(source) = source.map(|s| s.trim().to_ascii_lowercase()) else {
+        return PUBLISHER_RANK.len() + 1;
+    };
+    PUBLISHER_RANK
+        .iter()
+        .position(|p| source.starts_with(p))
+        .unwrap_or(PUBLISHER_RANK.len())
+}
+
+/// Link without query string, fragment or trailing slash, so tracking variants of the
+/// same article compare equal.
+fn canonical_link(link: &str) -> String {
+    let base = link.split(['?', '#']).next().unwrap_or(link);
+    base.trim_end_matches('/').to_ascii_lowercase()
+}
+
+/// Drop repeated links (first occurrence wins), then order by publisher rank and
+/// newest first within a publisher tier.
+pub fn dedupe_and_rank(items: Vec<NewsItem>) -> Vec<NewsItem> {
+    let mut seen = HashSet::new();
+    let mut items: Vec<NewsItem> = items
+        .into_iter()
+        .filter(|item| seen.insert(canonical_link(&item.link)))
+        .collect();
+    items.sort_by(|a, b| {
+        publisher_rank(a.source.as_deref())
+            .cmp(&publisher_rank(b.source.as_deref()))
+            .then_with(|| b.published_at.cmp(&a.published_at))
+    });
+    items
+}
+
+/// Earliest publish time counted as post-report news: the session open for the report
+/// (midnight ET for BMO/TBA, 09:30 for DMH, 16:00 for AMC).
+pub fn report_cutoff(date: NaiveDate, session: EarningsSession) -> Option<DateTime<Utc>> {
+    let clock = match session {
+        EarningsSession::DuringMarket => NaiveTime::from_hms_opt(9, 30, 0)?,
+        EarningsSession::AfterClose => NaiveTime::from_hms_opt(16, 0, 0)?,
+        EarningsSession::BeforeOpen | EarningsSession::Unknown => NaiveTime::MIN,
+    };
+    New_York
+        .from_local_datetime(&date.and_time(clock))
+        .earliest()
+        .map(|t| t.with_timezone(&Utc))
+}
+
+impl FinanceService {
+    /// Earnings-related stories published since each event's report, deduplicated by
+    /// link, ranked by publisher and summarised by the installed `NewsSummariser`.
+    /// Symbols without matching stories are left out.
+    pub async fn earnings_news_digest(&self, events: &[EarningsEvent]) -> Vec<EarningsNewsDigest> {
+        let summariser = self.news_summariser();
+        let mut digests = Vec::new();
+
+        for chunk in events.chunks(NEWS_CONCURRENCY) {
+            let results = join_all(chunk.iter().map(|ev| async move {
+                let news = fetch_news(self.client.as_ref(), &ev.symbol, DIGEST_FETCH_LIMIT).await;
+                (ev, news)
+            }))
+            .await;
+
+            for (ev, news) in results {
+                let items = match news {
+                    Ok(items) => items,
+                    Err(e) => {
+                        warn!("news search failed for {}: {}", ev.symbol, e);
+                        continue;
+                    }
+                };
+                let cutoff = report_cutoff(ev.date.date_naive(), ev.session);
+                let relevant: Vec<NewsItem> = items
+                    .into_iter()
+                    .filter(|item| match (cutoff, item.published_at) {
+                        (Some(cutoff), Some(published)) => published >= cutoff,
+                        _ => false,
+                    })
+                    .filter(is_earnings_related)
+                    .collect();
+
+                let mut ranked = dedupe_and_rank(relevant);
+                if ranked.is_empty() {
+                    continue;
+                }
+                ranked.truncate(DIGEST_ITEMS_PER_SYMBOL);
+
+                let summary = match summariser.summarise(&ev.symbol, &ranked).await {
+                    Ok(summary) => Some(summary).filter(|s| !s.trim().is_empty()),
+                    Err(e) => {
+                        warn!(
+                            "{} summariser failed for {}: {}",
+                            summariser.name(),
+                            ev.symbol,
+                            e
+                        );
+                        None
+                    }
+                };
+                digests.push(EarningsNewsDigest {
+                    symbol: ev.symbol.to_ascii_uppercase(),
+                    items: ranked,
+                    summary,
+                });
+            }
+        }
+
+        digests
+    }
+}
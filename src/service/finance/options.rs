@@ -78,6 +78,56 @@ impl FinanceService {
         })
     }
 
+    /// Fetch calls and puts at the `strikes_each_side` strikes nearest spot on each side,
+    /// so both legs are available at every strike (straddles, bracketing strikes).
+    pub async fn get_option_chain_near(
+        &self,
+        symbol: &str,
+        expiration: NaiveDate,
+        strikes_each_side: usize,
+    ) -> Result<OptionSlice, FinanceServiceError> {
+        let expiration_str = expiration.format("%Y-%m-%d").to_string();
+        let chain = self
+            .client
+            .get_option_chain(symbol, Some(expiration_str.as_str()))
+            .await?;
+
+        let spot = chain
+            .underlying_price
+            .ok_or_else(|| FinanceServiceError::NotFound("no underlying price".into()))?;
+
+        let mut strikes: Vec<f64> = chain.calls.iter().map(|c| c.strike).collect();
+        strikes.sort_by(|a, b| float_cmp(*a, *b));
+        strikes.dedup();
+        let below = strikes
+            .iter()
+            .rev()
+            .filter(|k| **k <= spot)
+            .take(strikes_each_side);
+        let above = strikes
+            .iter()
+            .filter(|k| **k > spot)
+            .take(strikes_each_side);
+        let window: Vec<f64> = below.chain(above).copied().collect();
+
+        let near = |contracts: Vec<OptionContract>| {
+            let mut kept: Vec<OptionContract> = contracts
+                .into_iter()
+                .filter(|c| window.contains(&c.strike))
+                .collect();
+            kept.sort_by(|a, b| float_cmp(a.strike, b.strike));
+            kept
+        };
+
+        Ok(OptionSlice {
+            symbol: symbol.to_uppercase(),
+            expiration: expiration_str,
+            spot,
+            calls: near(chain.calls),
+            puts: near(chain.puts),
+        })
+    }
+
     /// Fetch today’s expiration option chain and slice around spot.
     pub async fn get_option_slice_today(
         &self,
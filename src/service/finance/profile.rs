@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use futures_util::future::join_all;
+use serde_json::Value;
+use tracing::warn;
+
+use super::FinanceService;
+
+/// Concurrent `assetProfile` lookups per batch.
+const PROFILE_CONCURRENCY: usize = 8;
+
+/// Sector per symbol, kept for the life of the process (sectors rarely change).
+/// `None` records symbols Yahoo has no sector for, so they are not re-fetched.
+#[derive(Default)]
+pub struct SectorCache {
+    sectors: RwLock<HashMap<String, Option<String>>>,
+}
+
+impl SectorCache {
+    fn get(&self, symbol: &str) -> Option<Option<String>> {
+        self.sectors
+            .read()
+            .ok()
+            .and_then(|s| s.get(symbol).cloned())
+    }
+
+    fn insert(&self, symbol: String, sector: Option<String>) {
+        if let Ok(mut sectors) = self.sectors.write() {
+            sectors.insert(symbol, sector);
+        }
+    }
+}
+
+impl FinanceService {
+    /// Sector (Yahoo `assetProfile.sector`) for each symbol that has one.
+    pub async fn get_sectors(&self, symbols: &[String]) -> HashMap<String, String> {
+        let mut out = HashMap::new();
+        let mut missing = Vec::new();
+        for symbol in symbols {
+            let symbol = symbol.to_ascii_uppercase();
+            match self.sectors.get(&symbol) {
+                Some(Some(sector)) => {
+                    out.insert(symbol, sector);
+                }
+                Some(None) => {}
+                None => missing.push(symbol),
+            }
+        }
+        missing.sort();
+        missing.dedup();
+
+        for chunk in missing.chunks(PROFILE_CONCURRENCY) {
+            let results = join_all(chunk.iter().map(|symbol| async move {
+                let summary = self
+                    .client
+                    .get_quote_summary(symbol, &["assetProfile"])
+                    .await;
+                (symbol, summary)
+            }))
+            .await;
+
+            for (symbol, summary) in results {
+                match summary {
+                    Ok(data) => {
+                        let sector = parse_sector(&data);
+                        self.sectors.insert(symbol.clone(), sector.clone());
+                        if let Some(sector) = sector {
+                            out.insert(symbol.clone(), sector);
+                        }
+                    }
+                    // Not cached: transient failures should be retried next time
+                    Err(e) => warn!("assetProfile failed for {}: {}", symbol, e),
+                }
+            }
+        }
+
+        out
+    }
+}
+
+fn parse_sector(data: &Value) -> Option<String> {
+    data.get("quoteSummary")?
+        .get("result")?
+        .as_array()?
+        .first()?
+        .get("assetProfile")?
+        .get("sector")?
+        .as_str()
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string())
+}
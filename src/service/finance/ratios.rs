@@ -0,0 +1,153 @@
+use chrono::NaiveDate;
+
+use crate::models::{FinancialRatios, FinancialStatement, TtmFigures};
+
+use super::statements::{metric_series, pct_change};
+
+/// Longest end-to-end span of the four quarters in a TTM sum; a gap pushes it past a year.
+const TTM_MAX_SPAN_DAYS: i64 = 300;
+
+/// Tax rate for ROIC when the effective rate cannot be derived from the statements.
+const FALLBACK_TAX_RATE: f64 = 0.21;
+
+/// Sum of the latest four quarters of `field`, with the latest quarter's end date.
+/// `None` unless the four quarters are consecutive.
+pub fn ttm_sum(statement: &FinancialStatement, field: &str) -> Option<(NaiveDate, f64)> {
+    let points = metric_series(statement, field);
+    let last4 = points.get(points.len().checked_sub(4)?..)?;
+    if (last4[3].0 - last4[0].0).num_days() > TTM_MAX_SPAN_DAYS {
+        return None;
+    }
+    Some((last4[3].0, last4.iter().map(|(_, v)| v).sum()))
+}
+
+/// Latest reported value of `field`.
+pub fn latest_value(statement: &FinancialStatement, field: &str) -> Option<f64> {
+    metric_series(statement, field).last().map(|(_, v)| *v)
+}
+
+/// Percent change between the last two reported values of `field`.
+pub fn latest_growth(statement: &FinancialStatement, field: &str) -> Option<f64> {
+    let points = metric_series(statement, field);
+    let [.., (_, prior), (_, latest)] = points.as_slice() else {
+        return None;
+    };
+    pct_change(Some(*latest), Some(*prior))
+}
+
+/// TTM figures from quarterly income and cash flow statements.
+pub fn ttm_figures(
+    income: Option<&FinancialStatement>,
+    cash_flow: Option<&FinancialStatement>,
+) -> TtmFigures {
+    let sum = |statement: Option<&FinancialStatement>, field: &str| {
+        statement.and_then(|s| ttm_sum(s, field))
+    };
+    let revenue = sum(income, "TotalRevenue");
+    let net_income = sum(income, "NetIncome");
+
+    TtmFigures {
+        as_of: revenue.or(net_income).map(|(date, _)| date),
+        revenue: revenue.map(|(_, v)| v),
+        gross_profit: sum(income, "GrossProfit").map(|(_, v)| v),
+        operating_income: sum(income, "OperatingIncome").map(|(_, v)| v),
+        pretax_income: sum(income, "PretaxIncome").map(|(_, v)| v),
+        tax_provision: sum(income, "TaxProvision").map(|(_, v)| v),
+        net_income: net_income.map(|(_, v)| v),
+        ebitda: sum(income, "EBITDA").map(|(_, v)| v),
+        diluted_eps: sum(income, "DilutedEPS").map(|(_, v)| v),
+        operating_cash_flow: sum(cash_flow, "OperatingCashFlow").map(|(_, v)| v),
+        capital_expenditure: sum(cash_flow, "CapitalExpenditure").map(|(_, v)| v),
+        free_cash_flow: sum(cash_flow, "FreeCashFlow").map(|(_, v)| v),
+    }
+}
+
+/// Point-in-time balance sheet figures used by the ratios.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BalanceSnapshot {
+    pub total_debt: Option<f64>,
+    /// Cash, equivalents and short-term investments.
+    pub cash: Option<f64>,
+    pub stockholders_equity: Option<f64>,
+    pub invested_capital: Option<f64>,
+    pub current_assets: Option<f64>,
+    pub current_liabilities: Option<f64>,
+    pub shares_outstanding: Option<f64>,
+}
+
+impl BalanceSnapshot {
+    /// Latest values from a (preferably quarterly) balance sheet.
+    pub fn from_statement(balance: &FinancialStatement) -> Self {
+        let latest = |field: &str| latest_value(balance, field);
+        Self {
+            total_debt: latest("TotalDebt"),
+            cash: latest("CashCashEquivalentsAndShortTermInvestments")
+                .or_else(|| latest("CashAndCashEquivalents")),
+            stockholders_equity: latest("StockholdersEquity"),
+            invested_capital: latest("InvestedCapital"),
+            current_assets: latest("CurrentAssets"),
+            current_liabilities: latest("CurrentLiabilities"),
+            shares_outstanding: latest("OrdinarySharesNumber"),
+        }
+    }
+}
+
+/// Debt less cash; `None` only when the balance sheet has neither.
+pub fn net_debt(balance: &BalanceSnapshot) -> Option<f64> {
+    if balance.total_debt.is_none() && balance.cash.is_none() {
+        return None;
+    }
+    Some(balance.total_debt.unwrap_or(0.0) - balance.cash.unwrap_or(0.0))
+}
+
+/// Market cap plus debt less cash; missing debt or cash counts as zero.
+pub fn enterprise_value(market_cap: Option<f64>, balance: &BalanceSnapshot) -> Option<f64> {
+    Some(market_cap? + balance.total_debt.unwrap_or(0.0) - balance.cash.unwrap_or(0.0))
+}
+
+/// Ratios from TTM and balance sheet figures; growth rates are left for the caller.
+pub fn compute_ratios(
+    ttm: &TtmFigures,
+    balance: &BalanceSnapshot,
+    market_cap: Option<f64>,
+    enterprise_value: Option<f64>,
+    pe_ratio: Option<f64>,
+    eps_growth_pct: Option<f64>,
+) -> FinancialRatios {
+    let equity = balance.stockholders_equity.filter(|e| *e > 0.0);
+
+    let tax_rate = ratio(ttm.tax_provision, ttm.pretax_income.filter(|p| *p > 0.0))
+        .filter(|rate| (0.0..=0.5).contains(rate))
+        .unwrap_or(FALLBACK_TAX_RATE);
+    let nopat = ttm.operating_income.map(|oi| oi * (1.0 - tax_rate));
+    let invested_capital = balance
+        .invested_capital
+        .or_else(|| Some(balance.total_debt.unwrap_or(0.0) + equity? - balance.cash.unwrap_or(0.0)))
+        .filter(|ic| *ic > 0.0);
+
+    FinancialRatios {
+        gross_margin: percent(ttm.gross_profit, ttm.revenue),
+        operating_margin: percent(ttm.operating_income, ttm.revenue),
+        net_margin: percent(ttm.net_income, ttm.revenue),
+        roe: percent(ttm.net_income, equity),
+        roic: percent(nopat, invested_capital),
+        debt_to_equity: ratio(balance.total_debt, equity),
+        current_ratio: ratio(balance.current_assets, balance.current_liabilities),
+        fcf_yield: percent(ttm.free_cash_flow, market_cap.filter(|m| *m > 0.0)),
+        ev_to_ebitda: ratio(enterprise_value, ttm.ebitda.filter(|e| *e > 0.0)),
+        peg: ratio(
+            pe_ratio.filter(|pe| *pe > 0.0),
+            eps_growth_pct.filter(|g| *g > 0.0),
+        ),
+        ..FinancialRatios::default()
+    }
+}
+
+fn ratio(numerator: Option<f64>, denominator: Option<f64>) -> Option<f64> {
+    let (n, d) = (numerator?, denominator?);
+    (d != 0.0).then(|| n / d).filter(|r| r.is_finite())
+}
+
+fn percent(numerator: Option<f64>, denominator: Option<f64>) -> Option<f64> {
+    ratio(numerator, denominator).map(|r| r * 100.0)
+}
@@ -0,0 +1,654 @@
+use chrono::{Months, NaiveDate};
+use serde_json::Value;
+
+use crate::models::{FinancialStatement, Frequency, StatementType};
+
+/// Period columns shown side by side in statement tables.
+pub const STATEMENT_PERIODS: usize = 5;
+
+/// How a line item's values are formatted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineKind {
+    /// Currency amount, abbreviated (`391.04B`).
+    Amount,
+    /// Per-share figure, two decimals.
+    PerShare,
+    /// Share count, abbreviated without currency.
+    Shares,
+}
+
+/// One line of a statement layout.
+#[derive(Debug, Clone, Copy)]
+pub struct LineItem {
+    /// Yahoo timeseries field without the frequency prefix, e.g. `TotalRevenue`.
+    pub field: &'static str,
+    pub label: &'static str,
+    /// Indent level under the subtotal it belongs to.
+    pub depth: u8,
+    pub kind: LineKind,
+    /// Subtotals and totals drawn with emphasis.
+    pub total: bool,
+}
+
+const fn line(field: &'static str, label: &'static str, depth: u8, total: bool) -> LineItem {
+    LineItem {
+        field,
+        label,
+        depth,
+        kind: LineKind::Amount,
+        total,
+    }
+}
+
+const fn per_share(field: &'static str, label: &'static str) -> LineItem {
+    LineItem {
+        field,
+        label,
+        depth: 1,
+        kind: LineKind::PerShare,
+        total: false,
+    }
+}
+
+const fn shares(field: &'static str, label: &'static str) -> LineItem {
+    LineItem {
+        field,
+        label,
+        depth: 1,
+        kind: LineKind::Shares,
+        total: false,
+    }
+}
+
+/// Income statement, top line to bottom line, then per-share and supplemental items.
+pub const INCOME_LAYOUT: &[LineItem] = &[
+    line("TotalRevenue", "Total Revenue", 0, true),
+    line("OperatingRevenue", "Operating Revenue", 1, false),
+    line("CostOfRevenue", "Cost of Revenue", 1, false),
+    line("GrossProfit", "Gross Profit", 0, true),
+    line("OperatingExpense", "Operating Expense", 1, false),
+    line("SellingGeneralAndAdministration", "SG&A", 2, false),
+    line("ResearchAndDevelopment", "Research & Development", 2, false),
+    line("OperatingIncome", "Operating Income", 0, true),
+    line(
+        "NetNonOperatingInterestIncomeExpense",
+        "Net Interest Income/Expense",
+        1,
+        false,
+    ),
+    line("InterestIncome", "Interest Income", 2, false),
+    line("InterestExpense", "Interest Expense", 2, false),
+    line("NetInterestIncome", "Net Interest Income", 2, false),
+    line("OtherIncomeExpense", "Other Income/Expense", 1, false),
+    line("PretaxIncome", "Pretax Income", 0, true),
+    line("TaxProvision", "Tax Provision", 1, false),
+    line("NetIncome", "Net Income", 0, true),
+    line(
+        "NetIncomeCommonStockholders",
+        "Net Income to Common",
+        1,
+        false,
+    ),
+    line(
+        "NetIncomeFromContinuingOperationNetMinorityInterest",
+        "Net Income (Continuing Ops)",
+        1,
+        false,
+    ),
+    per_share("BasicEPS", "Basic EPS"),
+    per_share("DilutedEPS", "Diluted EPS"),
+    shares("BasicAverageShares", "Basic Avg Shares"),
+    shares("DilutedAverageShares", "Diluted Avg Shares"),
+    line("TotalExpenses", "Total Expenses", 0, false),
+    line(
+        "TotalOperatingIncomeAsReported",
+        "Operating Income (Reported)",
+        0,
+        false,
+    ),
+    line("EBIT", "EBIT", 0, false),
+    line("EBITDA", "EBITDA", 0, false),
+    line("NormalizedEBITDA", "Normalized EBITDA", 0, false),
+    line(
+        "ReconciledCostOfRevenue",
+        "Reconciled Cost of Revenue",
+        0,
+        false,
+    ),
+    line(
+        "ReconciledDepreciation",
+        "Reconciled Depreciation",
+        0,
+        false,
+    ),
+];
+
+/// Balance sheet: assets, liabilities, equity, then supplemental items.
+pub const BALANCE_LAYOUT: &[LineItem] = &[
+    line("TotalAssets", "Total Assets", 0, true),
+    line("CurrentAssets", "Current Assets", 1, true),
+    line(
+        "CashCashEquivalentsAndShortTermInvestments",
+        "Cash & Short-Term Investments",
+        2,
+        false,
+    ),
+    line("CashAndCashEquivalents", "Cash & Equivalents", 3, false),
+    line("CashFinancial", "Cash", 3, false),
+    line("Receivables", "Receivables", 2, false),
+    line("AccountsReceivable", "Accounts Receivable", 3, false),
+    line("Inventory", "Inventory", 2, false),
+    line("PrepaidAssets", "Prepaid Assets", 2, false),
+    line("OtherCurrentAssets", "Other Current Assets", 2, false),
+    line("TotalNonCurrentAssets", "Non-Current Assets", 1, true),
+    line("NetPPE", "Net PP&E", 2, false),
+    line("GrossPPE", "Gross PP&E", 3, false),
+    line(
+        "AccumulatedDepreciation",
+        "Accumulated Depreciation",
+        3,
+        false,
+    ),
+    line(
+        "GoodwillAndOtherIntangibleAssets",
+        "Goodwill & Intangibles",
+        2,
+        false,
+    ),
+    line("Goodwill", "Goodwill", 3, false),
+    line("OtherIntangibleAssets", "Other Intangibles", 3, false),
+    line("InvestmentsAndAdvances", "Investments & Advances", 2, false),
+    line(
+        "LongTermEquityInvestment",
+        "Long-Term Equity Investment",
+        3,
+        false,
+    ),
+    line(
+        "OtherNonCurrentAssets",
+        "Other Non-Current Assets",
+        2,
+        false,
+    ),
+    line(
+        "TotalLiabilitiesNetMinorityInterest",
+        "Total Liabilities",
+        0,
+        true,
+    ),
+    line("CurrentLiabilities", "Current Liabilities", 1, true),
+    line("PayablesAndAccruedExpenses", "Payables & Accrued", 2, false),
+    line("AccountsPayable", "Accounts Payable", 3, false),
+    line("CurrentDebt", "Current Debt", 2, false),
+    line("CurrentDeferredRevenue", "Deferred Revenue", 2, false),
+    line(
+        "OtherCurrentLiabilities",
+        "Other Current Liabilities",
+        2,
+        false,
+    ),
+    line(
+        "TotalNonCurrentLiabilitiesNetMinorityInterest",
+        "Non-Current Liabilities",
+        1,
+        true,
+    ),
+    line(
+        "LongTermDebtAndCapitalLeaseObligation",
+        "Long-Term Debt & Leases",
+        2,
+        false,
+    ),
+    line("LongTermDebt", "Long-Term Debt", 3, false),
+    line(
+        "NonCurrentDeferredRevenue",
+        "Non-Current Deferred Revenue",
+        2,
+        false,
+    ),
+    line(
+        "NonCurrentDeferredTaxesLiabilities",
+        "Deferred Tax Liabilities",
+        2,
+        false,
+    ),
+    line(
+        "OtherNonCurrentLiabilities",
+        "Other Non-Current Liabilities",
+        2,
+        false,
+    ),
+    line("TotalEquityGrossMinorityInterest", "Total Equity", 0, true),
+    line("StockholdersEquity", "Stockholders' Equity", 1, true),
+    line("CommonStockEquity", "Common Stock Equity", 2, false),
+    line("CommonStock", "Common Stock", 2, false),
+    line(
+        "AdditionalPaidInCapital",
+        "Additional Paid-In Capital",
+        2,
+        false,
+    ),
+    line("RetainedEarnings", "Retained Earnings", 2, false),
+    line("TreasuryStock", "Treasury Stock", 2, false),
+    line("TotalDebt", "Total Debt", 0, false),
+    line("NetDebt", "Net Debt", 0, false),
+    line("WorkingCapital", "Working Capital", 0, false),
+    line("InvestedCapital", "Invested Capital", 0, false),
+    line("TangibleBookValue", "Tangible Book Value", 0, false),
+    shares("ShareIssued", "Shares Issued"),
+    shares("OrdinarySharesNumber", "Ordinary Shares"),
+];
+
+/// Cash flow: operating, investing, financing, then the cash bridge and free cash flow.
+pub const CASH_FLOW_LAYOUT: &[LineItem] = &[
+    line("OperatingCashFlow", "Operating Cash Flow", 0, true),
+    line(
+        "CashFlowFromContinuingOperatingActivities",
+        "Continuing Operations",
+        1,
+        false,
+    ),
+    line("NetIncomeFromContinuingOperations", "Net Income", 1, false),
+    line(
+        "DepreciationAndAmortization",
+        "Depreciation & Amortization",
+        1,
+        false,
+    ),
+    line("DeferredIncomeTax", "Deferred Income Tax", 1, false),
+    line(
+        "StockBasedCompensation",
+        "Stock-Based Compensation",
+        1,
+        false,
+    ),
+    line("OtherNonCashItems", "Other Non-Cash Items", 1, false),
+    line(
+        "ChangeInWorkingCapital",
+        "Change in Working Capital",
+        1,
+        false,
+    ),
+    line("ChangeInReceivables", "Receivables", 2, false),
+    line(
+        "ChangesInAccountReceivables",
+        "Accounts Receivable",
+        2,
+        false,
+    ),
+    line("ChangeInInventory", "Inventory", 2, false),
+    line("ChangeInAccountPayable", "Accounts Payable", 2, false),
+    line(
+        "ChangeInOtherWorkingCapital",
+        "Other Working Capital",
+        2,
+        false,
+    ),
+    line("InvestingCashFlow", "Investing Cash Flow", 0, true),
+    line(
+        "CashFlowFromContinuingInvestingActivities",
+        "Continuing Operations",
+        1,
+        false,
+    ),
+    line("CapitalExpenditure", "Capital Expenditure", 1, false),
+    line("NetPPEPurchaseAndSale", "Net PP&E Purchase/Sale", 1, false),
+    line("PurchaseOfPPE", "Purchase of PP&E", 2, false),
+    line("SaleOfPPE", "Sale of PP&E", 2, false),
+    line(
+        "NetBusinessPurchaseAndSale",
+        "Net Business Purchase/Sale",
+        1,
+        false,
+    ),
+    line("PurchaseOfBusiness", "Purchase of Business", 2, false),
+    line("SaleOfBusiness", "Sale of Business", 2, false),
+    line(
+        "NetInvestmentPurchaseAndSale",
+        "Net Investment Purchase/Sale",
+        1,
+        false,
+    ),
+    line("PurchaseOfInvestment", "Purchase of Investments", 2, false),
+    line("SaleOfInvestment", "Sale of Investments", 2, false),
+    line("NetOtherInvestingChanges", "Other Investing", 1, false),
+    line("FinancingCashFlow", "Financing Cash Flow", 0, true),
+    line(
+        "CashFlowFromContinuingFinancingActivities",
+        "Continuing Operations",
+        1,
+        false,
+    ),
+    line("NetIssuancePaymentsOfDebt", "Net Debt Issuance", 1, false),
+    line("NetLongTermDebtIssuance", "Long-Term Debt, Net", 2, false),
+    line("LongTermDebtIssuance", "Long-Term Debt Issued", 3, false),
+    line("LongTermDebtPayments", "Long-Term Debt Repaid", 3, false),
+    line("NetShortTermDebtIssuance", "Short-Term Debt, Net", 2, false),
+    line("NetCommonStockIssuance", "Net Stock Issuance", 1, false),
+    line("CommonStockIssuance", "Stock Issued", 2, false),
+    line("CommonStockPayments", "Stock Repurchased", 2, false),
+    line("RepurchaseOfCapitalStock", "Buybacks", 1, false),
+    line("CashDividendsPaid", "Dividends Paid", 1, false),
+    line("CommonStockDividendPaid", "Common Dividends", 2, false),
+    line("NetOtherFinancingCharges", "Other Financing", 1, false),
+    line("BeginningCashPosition", "Beginning Cash", 0, false),
+    line("ChangesinCash", "Change in Cash", 0, false),
+    line("EffectOfExchangeRateChanges", "FX Effect", 1, false),
+    line("EndCashPosition", "Ending Cash", 0, true),
+    line("CapitalExpenditureReported", "CapEx (Reported)", 0, false),
+    line("FreeCashFlow", "Free Cash Flow", 0, true),
+];
+
+pub fn layout(statement_type: StatementType) -> &'static [LineItem] {
+    match statement_type {
+        StatementType::IncomeStatement => INCOME_LAYOUT,
+        StatementType::BalanceSheet => BALANCE_LAYOUT,
+        StatementType::CashFlow => CASH_FLOW_LAYOUT,
+    }
+}
+
+/// Shorthands accepted wherever a line item is looked up by name.
+pub const METRIC_ALIASES: &[(&str, &str)] = &[
+    ("revenue", "TotalRevenue"),
+    ("sales", "TotalRevenue"),
+    ("cogs", "CostOfRevenue"),
+    ("opex", "OperatingExpense"),
+    ("rd", "ResearchAndDevelopment"),
+    ("eps", "DilutedEPS"),
+    ("shares", "DilutedAverageShares"),
+    ("cash", "CashAndCashEquivalents"),
+    ("debt", "TotalDebt"),
+    ("equity", "StockholdersEquity"),
+    ("ocf", "OperatingCashFlow"),
+    ("cfo", "OperatingCashFlow"),
+    ("capex", "CapitalExpenditure"),
+    ("fcf", "FreeCashFlow"),
+    ("sbc", "StockBasedCompensation"),
+    ("da", "DepreciationAndAmortization"),
+    ("sga", "SellingGeneralAndAdministration"),
+    ("ppe", "NetPPE"),
+    ("buybacks", "RepurchaseOfCapitalStock"),
+    ("dividends", "CashDividendsPaid"),
+];
+
+/// Look up a line item by alias, field name or label (case, spacing and punctuation
+/// ignored), falling back to the first item whose name starts with `query`. Statements
+/// are searched income → balance → cash flow.
+pub fn find_line_item(query: &str) -> Option<(StatementType, LineItem)> {
+    let wanted = normalize_name(query);
+    if wanted.is_empty() {
+        return None;
+    }
+    let field = METRIC_ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == wanted)
+        .map(|(_, field)| normalize_name(field));
+    let wanted = field.as_deref().unwrap_or(&wanted);
+
+    let all = [
+        StatementType::IncomeStatement,
+        StatementType::BalanceSheet,
+        StatementType::CashFlow,
+    ]
+    .into_iter()
+    .flat_map(|st| layout(st).iter().map(move |item| (st, *item)));
+    let names = |item: &LineItem| [normalize_name(item.field), normalize_name(item.label)];
+
+    all.clone()
+        .find(|(_, item)| names(item).iter().any(|n| n == wanted))
+        .or_else(|| {
+            all.clone()
+                .find(|(_, item)| names(item).iter().any(|n| n.starts_with(wanted)))
+        })
+}
+
+/// Lower-cased alphanumerics only: `R&D`, `r_d` and `RD` all become `rd`.
+pub fn normalize_name(name: &str) -> String {
+    name.chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Every reported value of `field`, oldest first.
+pub fn metric_series(statement: &FinancialStatement, field: &str) -> Vec<(NaiveDate, f64)> {
+    let mut points: Vec<(NaiveDate, f64)> = statement
+        .statement
+        .get(field)
+        .into_iter()
+        .flatten()
+        .filter_map(|(date, entry)| {
+            let date = NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?;
+            Some((date, reported_value(entry)?))
+        })
+        .collect();
+    points.sort_by_key(|(date, _)| *date);
+    points
+}
+
+/// Period ends this close to the expected date count as that period, so 52/53-week
+/// fiscal years still line up.
+const PERIOD_TOLERANCE_DAYS: i64 = 10;
+
+/// Index in `dates` of the period ending `months` months before `date`, or `None` when
+/// that period is absent. Matching is by date, never by position, so a missing period
+/// does not shift the comparison.
+pub fn prior_period_index(dates: &[NaiveDate], date: NaiveDate, months: u32) -> Option<usize> {
+    let target = date.checked_sub_months(Months::new(months))?;
+    dates
+        .iter()
+        .enumerate()
+        .filter(|(_, d)| (**d - target).num_days().abs() <= PERIOD_TOLERANCE_DAYS)
+        .min_by_key(|(_, d)| (**d - target).num_days().abs())
+        .map(|(i, _)| i)
+}
+
+/// Change of each value against the period ending `months` months earlier (`None` when
+/// that period is not in `points`).
+pub fn growth_rates(points: &[(NaiveDate, f64)], months: u32) -> Vec<Option<f64>> {
+    let dates: Vec<NaiveDate> = points.iter().map(|(d, _)| *d).collect();
+    points
+        .iter()
+        .map(|&(date, value)| {
+            let prior = prior_period_index(&dates, date, months).map(|j| points[j].1);
+            pct_change(Some(value), prior)
+        })
+        .collect()
+}
+
+/// Compound annual growth rate in percent; undefined unless both ends are positive.
+pub fn cagr(first: f64, last: f64, years: f64) -> Option<f64> {
+    (first > 0.0 && last > 0.0 && years > 0.0)
+        .then(|| ((last / first).powf(1.0 / years) - 1.0) * 100.0)
+}
+
+/// One line item across the table's periods.
+#[derive(Debug, Clone)]
+pub struct StatementRow {
+    pub field: String,
+    pub label: String,
+    pub depth: u8,
+    pub kind: LineKind,
+    pub total: bool,
+    /// Values aligned with `StatementTable::periods` (oldest first).
+    pub values: Vec<Option<f64>>,
+    /// Latest period vs the one before (QoQ for quarterly, YoY for annual).
+    pub change_pct: Option<f64>,
+    /// Latest quarter vs the same quarter a year earlier (quarterly tables only).
+    pub yoy_pct: Option<f64>,
+}
+
+/// A statement laid out as line items × periods.
+#[derive(Debug, Clone)]
+pub struct StatementTable {
+    pub statement_type: StatementType,
+    pub frequency: Frequency,
+    /// Period end dates, oldest first.
+    pub periods: Vec<NaiveDate>,
+    pub rows: Vec<StatementRow>,
+    pub currency: Option<String>,
+}
+
+/// Build a table of up to `max_periods` periods ending at `latest` (the newest period on
+/// or before it; the newest overall when `None`). Line items follow the statement
+/// layout; fields outside the layout follow in alphabetical order, and lines with no
+/// values are dropped.
+pub fn build_statement_table(
+    statement: &FinancialStatement,
+    statement_type: StatementType,
+    frequency: Frequency,
+    latest: Option<NaiveDate>,
+    max_periods: usize,
+) -> Option<StatementTable> {
+    let mut dates: Vec<NaiveDate> = statement
+        .statement
+        .values()
+        .flat_map(|series| series.keys())
+        .filter_map(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+        .filter(|d| latest.is_none_or(|latest| *d <= latest))
+        .collect();
+    dates.sort();
+    dates.dedup();
+    let periods: Vec<NaiveDate> = dates.split_off(dates.len().saturating_sub(max_periods.max(1)));
+    if periods.is_empty() {
+        return None;
+    }
+
+    let layout = layout(statement_type);
+    let mut extra: Vec<(String, String)> = statement
+        .statement
+        .keys()
+        .filter(|field| !layout.iter().any(|item| item.field == field.as_str()))
+        .map(|field| (field.clone(), field_label(field)))
+        .collect();
+    extra.sort();
+
+    let lines = layout
+        .iter()
+        .map(|item| {
+            (
+                item.field.to_string(),
+                item.label.to_string(),
+                item.depth,
+                item.kind,
+                item.total,
+            )
+        })
+        .chain(
+            extra
+                .into_iter()
+                .map(|(field, label)| (field, label, 0, LineKind::Amount, false)),
+        );
+
+    let mut currency = None;
+    let mut rows = Vec::new();
+    for (field, label, depth, kind, total) in lines {
+        let Some(series) = statement.statement.get(&field) else {
+            continue;
+        };
+        let values: Vec<Option<f64>> = periods
+            .iter()
+            .map(|date| {
+                let entry = series.get(&date.format("%Y-%m-%d").to_string())?;
+                if currency.is_none() {
+                    currency = entry
+                        .get("currencyCode")
+                        .and_then(Value::as_str)
+                        .map(str::to_string);
+                }
+                reported_value(entry)
+            })
+            .collect();
+        if values.iter().all(Option::is_none) {
+            continue;
+        }
+
+        let latest_value = values.last().copied().flatten();
+        let latest_period = periods[periods.len() - 1];
+        let value_before = |months: u32| {
+            prior_period_index(&periods, latest_period, months).and_then(|j| values[j])
+        };
+        let change_pct = match frequency {
+            Frequency::Quarterly => pct_change(latest_value, value_before(3)),
+            Frequency::Annual => pct_change(latest_value, value_before(12)),
+        };
+        let yoy_pct = match frequency {
+            Frequency::Quarterly => pct_change(latest_value, value_before(12)),
+            Frequency::Annual => None,
+        };
+        rows.push(StatementRow {
+            field,
+            label,
+            depth,
+            kind,
+            total,
+            values,
+            change_pct,
+            yoy_pct,
+        });
+    }
+
+    (!rows.is_empty()).then_some(StatementTable {
+        statement_type,
+        frequency,
+        periods,
+        rows,
+        currency,
+    })
+}
+
+fn reported_value(entry: &Value) -> Option<f64> {
+    entry
+        .get("reportedValue")
+        .and_then(|rv| rv.get("raw"))
+        .and_then(Value::as_f64)
+        .or_else(|| entry.get("raw").and_then(Value::as_f64))
+}
+
+/// Percent change from `prior` to `current`, relative to |prior|.
+pub fn pct_change(current: Option<f64>, prior: Option<f64>) -> Option<f64> {
+    let (current, prior) = (current?, prior?);
+    (prior != 0.0).then(|| (current - prior) / prior.abs() * 100.0)
+}
+
+/// `391.04B`, `1.20T`, `-850.3M`, `12.5K`; small values keep two decimals.
+pub fn abbreviate(value: f64) -> String {
+    let abs = value.abs();
+    let (scaled, suffix) = if abs >= 1e12 {
+        (value / 1e12, "T")
+    } else if abs >= 1e9 {
+        (value / 1e9, "B")
+    } else if abs >= 1e6 {
+        (value / 1e6, "M")
+    } else if abs >= 1e3 {
+        (value / 1e3, "K")
+    } else {
+        return format!("{:.2}", value);
+    };
+    format!("{:.2}{}", scaled, suffix)
+}
+
+/// Display text for a value of the given kind.
+pub fn format_value(value: f64, kind: LineKind) -> String {
+    match kind {
+        LineKind::PerShare => format!("{:.2}", value),
+        LineKind::Amount | LineKind::Shares => abbreviate(value),
+    }
+}
+
+/// `TotalRevenue` → `Total Revenue`.
+pub fn field_label(field: &str) -> String {
+    let mut out = String::with_capacity(field.len() + 8);
+    let chars: Vec<char> = field.chars().collect();
+    for (i, &c) in chars.iter().enumerate() {
+        let boundary = i > 0
+            && c.is_uppercase()
+            && (chars[i - 1].is_lowercase()
+                || chars.get(i + 1).is_some_and(|next| next.is_lowercase()));
+        if boundary {
+            out.push(' ');
+        }
+        out.push(c);
+    }
+    out
+}
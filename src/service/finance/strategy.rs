@@ -0,0 +1,429 @@
+use std::f64::consts::{PI, SQRT_2};
+
+use chrono::NaiveDate;
+use finance_query_core::OptionContract;
+
+use super::implied_move::contract_mid;
+use super::{FinanceService, FinanceServiceError};
+
+/// Shares per contract.
+pub const CONTRACT_MULTIPLIER: f64 = 100.0;
+
+/// Annual risk-free rate used for Black–Scholes pricing and put–call parity.
+pub const RISK_FREE_RATE: f64 = 0.04;
+
+/// Strikes requested on each side of spot so that far legs are still in the slice.
+const LEG_STRIKES_EACH_SIDE: usize = 60;
+
+/// Number of points sampled along the payoff curve.
+const CURVE_POINTS: usize = 241;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptionKind {
+    Call,
+    Put,
+}
+
+impl OptionKind {
+    pub fn as_char(&self) -> char {
+        match self {
+            OptionKind::Call => 'C',
+            OptionKind::Put => 'P',
+        }
+    }
+}
+
+/// A requested leg, e.g. `600C -1`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LegSpec {
+    pub strike: f64,
+    pub kind: OptionKind,
+    /// Contracts; negative for short legs.
+    pub quantity: i32,
+}
+
+/// A leg priced from the live chain.
+#[derive(Debug, Clone)]
+pub struct StrategyLeg {
+    pub strike: f64,
+    pub kind: OptionKind,
+    pub quantity: i32,
+    /// Per-share mid (or last) price paid/received at entry.
+    pub premium: f64,
+    pub iv: f64,
+    /// Priced from the opposite contract at the same strike via put–call parity.
+    pub via_parity: bool,
+}
+
+/// Position greeks in dollars: delta/gamma per $1 move, theta per day, vega per vol point.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Greeks {
+    pub delta: f64,
+    pub gamma: f64,
+    pub theta: f64,
+    pub vega: f64,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PayoffPoint {
+    pub spot: f64,
+    pub at_expiry: f64,
+    pub at_horizon: f64,
+}
+
+/// Full P/L profile of a multi-leg position.
+#[derive(Debug, Clone)]
+pub struct StrategyAnalysis {
+    pub spot: f64,
+    pub days_to_expiry: f64,
+    pub horizon_days: i64,
+    pub legs: Vec<StrategyLeg>,
+    /// Cash paid to open (negative for a net credit).
+    pub net_debit: f64,
+    pub curve: Vec<PayoffPoint>,
+    pub breakevens: Vec<f64>,
+    /// `None` when profit is unbounded.
+    pub max_profit: Option<f64>,
+    /// Most negative P/L at expiry; `None` when the loss is unbounded.
+    pub max_loss: Option<f64>,
+    /// Probability that the position finishes above zero at expiry (0–1).
+    pub pop: f64,
+    pub greeks: Greeks,
+    /// Quantity-weighted IV used for the probability model.
+    pub iv: f64,
+}
+
+impl FinanceService {
+    /// Price each leg from the live chain (bid/ask mid). Legs missing from the slice
+    /// (in-the-money side) are priced from the other contract at the same strike.
+    pub async fn get_strategy_legs(
+        &self,
+        symbol: &str,
+        expiration: NaiveDate,
+        legs: &[LegSpec],
+        days_to_expiry: f64,
+    ) -> Result<(f64, Vec<StrategyLeg>), FinanceServiceError> {
+        let slice = self
+            .get_option_slice(symbol, expiration, LEG_STRIKES_EACH_SIDE)
+            .await?;
+        let spot = slice.spot;
+        let t = days_to_expiry.max(0.0) / 365.0;
+        let discount = (-RISK_FREE_RATE * t).exp();
+
+        let find = |contracts: &[OptionContract], strike: f64| {
+            contracts
+                .iter()
+                .find(|c| (c.strike - strike).abs() < 1e-6)
+                .cloned()
+        };
+
+        let mut priced = Vec::with_capacity(legs.len());
+        for leg in legs {
+            let (same, other) = match leg.kind {
+                OptionKind::Call => (&slice.calls, &slice.puts),
+                OptionKind::Put => (&slice.puts, &slice.calls),
+            };
+
+            let (premium, iv, via_parity) = if let Some(c) = find(same, leg.strike) {
+                (contract_mid(&c).0, c.implied_volatility, false)
+            } else if let Some(c) = find(other, leg.strike) {
+                // C − P = S − K·e^(−rT)
+                let forward_gap = spot - leg.strike * discount;
+                let other_mid = contract_mid(&c).0;
+                let premium = match leg.kind {
+                    OptionKind::Call => other_mid + forward_gap,
+                    OptionKind::Put => other_mid - forward_gap,
+                };
+                (premium.max(0.0), c.implied_volatility, true)
+            } else {
+                return Err(FinanceServiceError::NotFound(format!(
+                    "no {} strike {} for {symbol} {expiration}",
+                    leg.kind.as_char(),
+                    leg.strike
+                )));
+            };
+
+            priced.push(StrategyLeg {
+                strike: leg.strike,
+                kind: leg.kind,
+                quantity: leg.quantity,
+                premium,
+                iv,
+                via_parity,
+            });
+        }
+
+        Ok((spot, priced))
+    }
+}
+
+/// Build the payoff curve at expiry and at `horizon_days` from now, breakevens, max
+/// profit/loss, probability of profit and position greeks.
+pub fn analyze_strategy(
+    spot: f64,
+    legs: Vec<StrategyLeg>,
+    days_to_expiry: f64,
+    horizon_days: i64,
+) -> StrategyAnalysis {
+    let t = days_to_expiry.max(0.0) / 365.0;
+    let horizon_t = (days_to_expiry - horizon_days as f64).max(0.0) / 365.0;
+
+    let total_qty: f64 = legs.iter().map(|l| l.quantity.unsigned_abs() as f64).sum();
+    let iv = if total_qty > 0.0 {
+        legs.iter()
+            .map(|l| l.iv * l.quantity.unsigned_abs() as f64)
+            .sum::<f64>()
+            / total_qty
+    } else {
+        0.0
+    };
+
+    let net_debit: f64 = legs
+        .iter()
+        .map(|l| l.premium * l.quantity as f64 * CONTRACT_MULTIPLIER)
+        .sum();
+
+    // Sample wide enough to show every strike and ±3σ around spot
+    let spread = (3.0 * iv.max(0.05) * t.max(1.0 / 365.0).sqrt()).min(2.0);
+    let mut lo = spot * (-spread).exp();
+    let mut hi = spot * spread.exp();
+    for leg in &legs {
+        lo = lo.min(leg.strike * 0.95);
+        hi = hi.max(leg.strike * 1.05);
+    }
+    let step = (hi - lo) / (CURVE_POINTS - 1) as f64;
+    let curve = (0..CURVE_POINTS)
+        .map(|i| {
+            let s = lo + step * i as f64;
+            PayoffPoint {
+                spot: s,
+                at_expiry: expiry_pl(&legs, s),
+                at_horizon: value_pl(&legs, s, horizon_t),
+            }
+        })
+        .collect();
+
+    // Expiry P/L is piecewise linear with kinks at the strikes
+    let mut kinks: Vec<f64> = legs.iter().map(|l| l.strike).collect();
+    kinks.push(0.0);
+    kinks.sort_by(|a, b| a.total_cmp(b));
+    kinks.dedup();
+    let tail_slope: f64 = legs
+        .iter()
+        .filter(|l| l.kind == OptionKind::Call)
+        .map(|l| l.quantity as f64 * CONTRACT_MULTIPLIER)
+        .sum();
+
+    let kink_pl: Vec<f64> = kinks.iter().map(|k| expiry_pl(&legs, *k)).collect();
+    let breakevens = find_breakevens(&kinks, &kink_pl, tail_slope);
+
+    let bounded_max = kink_pl.iter().copied().fold(f64::MIN, f64::max);
+    let bounded_min = kink_pl.iter().copied().fold(f64::MAX, f64::min);
+    let max_profit = (tail_slope <= 0.0).then_some(bounded_max);
+    let max_loss = (tail_slope >= 0.0).then_some(bounded_min);
+
+    let pop = probability_of_profit(&legs, &breakevens, spot, iv, t);
+    let greeks = position_greeks(&legs, spot, t);
+
+    StrategyAnalysis {
+        spot,
+        days_to_expiry,
+        horizon_days,
+        legs,
+        net_debit,
+        curve,
+        breakevens,
+        max_profit,
+        max_loss,
+        pop,
+        greeks,
+        iv,
+    }
+}
+
+/// P/L at expiry for an underlying price `s`.
+pub fn expiry_pl(legs: &[StrategyLeg], s: f64) -> f64 {
+    legs.iter()
+        .map(|l| {
+            let intrinsic = match l.kind {
+                OptionKind::Call => (s - l.strike).max(0.0),
+                OptionKind::Put => (l.strike - s).max(0.0),
+            };
+            (intrinsic - l.premium) * l.quantity as f64 * CONTRACT_MULTIPLIER
+        })
+        .sum()
+}
+
+/// Mark-to-model P/L with `t` years left, holding each leg's IV constant.
+pub fn value_pl(legs: &[StrategyLeg], s: f64, t: f64) -> f64 {
+    legs.iter()
+        .map(|l| {
+            let value = black_scholes(l.kind, s, l.strike, t, l.iv, RISK_FREE_RATE);
+            (value - l.premium) * l.quantity as f64 * CONTRACT_MULTIPLIER
+        })
+        .sum()
+}
+
+fn find_breakevens(kinks: &[f64], pl: &[f64], tail_slope: f64) -> Vec<f64> {
+    let mut out: Vec<f64> = Vec::new();
+    let mut push = |x: f64| {
+        if x > 0.0 && !out.iter().any(|b| (b - x).abs() < 1e-6) {
+            out.push(x);
+        }
+    };
+
+    for i in 0..kinks.len().saturating_sub(1) {
+        let (a, b) = (kinks[i], kinks[i + 1]);
+        let (pa, pb) = (pl[i], pl[i + 1]);
+        if pa == 0.0 && pb != 0.0 {
+            push(a);
+        } else if pa * pb < 0.0 {
+            push(a + (b - a) * (-pa) / (pb - pa));
+        }
+    }
+
+    if let (Some(last), Some(last_pl)) = (kinks.last(), pl.last()) {
+        if *last_pl == 0.0 && tail_slope != 0.0 {
+            push(*last);
+        } else if tail_slope != 0.0 {
+            let root = last - last_pl / tail_slope;
+            if root > *last {
+                push(root);
+            }
+        }
+    }
+
+    out.sort_by(|a, b| a.total_cmp(b));
+    out
+}
+
+/// Probability mass of the profitable intervals under a lognormal terminal price.
+fn probability_of_profit(
+    legs: &[StrategyLeg],
+    breakevens: &[f64],
+    spot: f64,
+    iv: f64,
+    t: f64,
+) -> f64 {
+    let mut bounds = vec![0.0];
+    bounds.extend_from_slice(breakevens);
+
+    let mut pop = 0.0;
+    for (i, lower) in bounds.iter().enumerate() {
+        let upper = bounds.get(i + 1).copied();
+        let probe = match upper {
+            Some(u) => (lower + u) / 2.0,
+            None => lower * 1.1 + 1.0,
+        };
+        if expiry_pl(legs, probe) > 0.0 {
+            let p_upper = upper.map_or(1.0, |u| lognormal_cdf(u, spot, iv, t));
+            pop += p_upper - lognormal_cdf(*lower, spot, iv, t);
+        }
+    }
+    pop.clamp(0.0, 1.0)
+}
+
+/// Risk-neutral P(S_T < x).
+fn lognormal_cdf(x: f64, spot: f64, iv: f64, t: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    let sd = iv * t.sqrt();
+    if sd <= 0.0 {
+        return if spot < x { 1.0 } else { 0.0 };
+    }
+    let mu = (RISK_FREE_RATE - 0.5 * iv * iv) * t;
+    norm_cdf(((x / spot).ln() - mu) / sd)
+}
+
+fn position_greeks(legs: &[StrategyLeg], spot: f64, t: f64) -> Greeks {
+    let mut total = Greeks::default();
+    for leg in legs {
+        let g = leg_greeks(leg.kind, spot, leg.strike, t, leg.iv, RISK_FREE_RATE);
+        let size = leg.quantity as f64 * CONTRACT_MULTIPLIER;
+        total.delta += g.delta * size;
+        total.gamma += g.gamma * size;
+        total.theta += g.theta * size;
+        total.vega += g.vega * size;
+    }
+    total
+}
+
+/// Black–Scholes price; collapses to intrinsic value at or past expiry.
+pub fn black_scholes(kind: OptionKind, s: f64, k: f64, t: f64, sigma: f64, r: f64) -> f64 {
+    if t <= 0.0 || sigma <= 0.0 {
+        return match kind {
+            OptionKind::Call => (s - k).max(0.0),
+            OptionKind::Put => (k - s).max(0.0),
+        };
+    }
+    let (d1, d2) = d1_d2(s, k, t, sigma, r);
+    let discount = (-r * t).exp();
+    match kind {
+        OptionKind::Call => s * norm_cdf(d1) - k * discount * norm_cdf(d2),
+        OptionKind::Put => k * discount * norm_cdf(-d2) - s * norm_cdf(-d1),
+    }
+}
+
+/// Per-share greeks: theta per calendar day, vega per vol point.
+fn leg_greeks(kind: OptionKind, s: f64, k: f64, t: f64, sigma: f64, r: f64) -> Greeks {
+    if t <= 0.0 || sigma <= 0.0 || s <= 0.0 {
+        let itm = match kind {
+            OptionKind::Call => s > k,
+            OptionKind::Put => s < k,
+        };
+        let delta = match (kind, itm) {
+            (OptionKind::Call, true) => 1.0,
+            (OptionKind::Put, true) => -1.0,
+            _ => 0.0,
+        };
+        return Greeks {
+            delta,
+            ..Greeks::default()
+        };
+    }
+
+    let (d1, d2) = d1_d2(s, k, t, sigma, r);
+    let pdf = norm_pdf(d1);
+    let sqrt_t = t.sqrt();
+    let discount = (-r * t).exp();
+    let decay = -s * pdf * sigma / (2.0 * sqrt_t);
+
+    let (delta, theta) = match kind {
+        OptionKind::Call => (norm_cdf(d1), decay - r * k * discount * norm_cdf(d2)),
+        OptionKind::Put => (norm_cdf(d1) - 1.0, decay + r * k * discount * norm_cdf(-d2)),
+    };
+
+    Greeks {
+        delta,
+        gamma: pdf / (s * sigma * sqrt_t),
+        theta: theta / 365.0,
+        vega: s * pdf * sqrt_t / 100.0,
+    }
+}
+
+fn d1_d2(s: f64, k: f64, t: f64, sigma: f64, r: f64) -> (f64, f64) {
+    let sd = sigma * t.sqrt();
+    let d1 = ((s / k).ln() + (r + 0.5 * sigma * sigma) * t) / sd;
+    (d1, d1 - sd)
+}
+
+fn norm_pdf(x: f64) -> f64 {
+    (-0.5 * x * x).exp() / (2.0 * PI).sqrt()
+}
+
+pub fn norm_cdf(x: f64) -> f64 {
+    0.5 * (1.0 + erf(x / SQRT_2))
+}
+
+/// Abramowitz & Stegun 7.1.26 (max error ~1.5e-7).
+fn erf(x: f64) -> f64 {
+    let sign = if x < 0.0 { -1.0 } else { 1.0 };
+    let x = x.abs();
+    let t = 1.0 / (1.0 + 0.327_591_1 * x);
+    let poly = t
+        * (0.254_829_592
+            + t * (-0.284_496_736
+                + t * (1.421_413_741 + t * (-1.453_152_027 + t * 1.061_405_429))));
+    sign * (1.0 - poly * (-x * x).exp())
+}
@@ -0,0 +1,173 @@
+use chrono::{NaiveDate, Utc};
+use chrono_tz::America::New_York;
+
+use super::{FinanceService, FinanceServiceError};
+
+/// Days to expiry used for the constant-maturity IV reading.
+pub const IV_TARGET_DAYS: i64 = 30;
+
+/// ATM implied volatility for a single expiration.
+#[derive(Debug, Clone)]
+pub struct AtmIvPoint {
+    pub expiration: NaiveDate,
+    pub days: i64,
+    pub iv: f64,
+}
+
+/// 52-week context for the current IV reading.
+#[derive(Debug, Clone)]
+pub struct IvStats {
+    pub current: f64,
+    pub low: f64,
+    pub high: f64,
+    /// Where `current` sits between the low and high (0–100).
+    pub rank: f64,
+    /// Share of past readings below `current` (0–100).
+    pub percentile: f64,
+    pub samples: usize,
+}
+
+impl FinanceService {
+    /// ATM IV for one expiration: average of the nearest call and put IVs around spot.
+    pub async fn get_atm_iv(
+        &self,
+        symbol: &str,
+        expiration: NaiveDate,
+    ) -> Result<AtmIvPoint, FinanceServiceError> {
+        let slice = self.get_option_slice(symbol, expiration, 1).await?;
+
+        let ivs: Vec<f64> = slice
+            .calls
+            .iter()
+            .chain(slice.puts.iter())
+            .map(|c| c.implied_volatility)
+            .filter(|iv| *iv > 0.01)
+            .collect();
+
+        if ivs.is_empty() {
+            return Err(FinanceServiceError::NotFound(format!(
+                "no ATM implied volatility for {symbol} {expiration}"
+            )));
+        }
+
+        Ok(AtmIvPoint {
+            expiration,
+            days: days_until(expiration),
+            iv: ivs.iter().sum::<f64>() / ivs.len() as f64,
+        })
+    }
+
+    /// ATM IV per expiration out to `max_days`, capped at `max_points` expirations.
+    pub async fn get_iv_term_structure(
+        &self,
+        symbol: &str,
+        max_days: i64,
+        max_points: usize,
+    ) -> Result<Vec<AtmIvPoint>, FinanceServiceError> {
+        let expirations = self.get_option_expirations(symbol).await?;
+
+        let mut points = Vec::new();
+        for expiration in expirations
+            .into_iter()
+            .filter(|e| (1..=max_days).contains(&days_until(*e)))
+            .take(max_points)
+        {
+            match self.get_atm_iv(symbol, expiration).await {
+                Ok(point) => points.push(point),
+                Err(e) => tracing::warn!("ATM IV failed for {} {}: {}", symbol, expiration, e),
+            }
+        }
+
+        if points.is_empty() {
+            return Err(FinanceServiceError::NotFound(format!(
+                "no IV term structure for symbol {symbol}"
+            )));
+        }
+
+        Ok(points)
+    }
+
+    /// Constant-maturity ATM IV (30 days), interpolated between the bracketing expirations.
+    pub async fn get_iv30(&self, symbol: &str) -> Result<f64, FinanceServiceError> {
+        let expirations = self.get_option_expirations(symbol).await?;
+        let dated: Vec<(NaiveDate, i64)> = expirations
+            .into_iter()
+            .map(|e| (e, days_until(e)))
+            .filter(|(_, d)| *d >= 1)
+            .collect();
+
+        let below = dated.iter().rev().find(|(_, d)| *d <= IV_TARGET_DAYS);
+        let above = dated.iter().find(|(_, d)| *d >= IV_TARGET_DAYS);
+
+        let mut points = Vec::new();
+        for (expiration, _) in [below, above].into_iter().flatten() {
+            if points
+                .iter()
+                .any(|p: &AtmIvPoint| p.expiration == *expiration)
+            {
+                continue;
+            }
+            points.push(self.get_atm_iv(symbol, *expiration).await?);
+        }
+
+        interpolate_iv(&points, IV_TARGET_DAYS).ok_or_else(|| {
+            FinanceServiceError::NotFound(format!("no 30-day IV for symbol {symbol}"))
+        })
+    }
+}
+
+/// Interpolate IV at `target_days` in total variance (IV² × t), which keeps the
+/// term structure arbitrage-free between the two bracketing expirations.
+pub fn interpolate_iv(points: &[AtmIvPoint], target_days: i64) -> Option<f64> {
+    let below = points
+        .iter()
+        .filter(|p| p.days <= target_days)
+        .max_by_key(|p| p.days);
+    let above = points
+        .iter()
+        .filter(|p| p.days >= target_days)
+        .min_by_key(|p| p.days);
+
+    match (below, above) {
+        (Some(b), Some(a)) if a.days != b.days => {
+            let (tb, ta, t) = (b.days as f64, a.days as f64, target_days as f64);
+            let var_b = b.iv * b.iv * tb;
+            let var_a = a.iv * a.iv * ta;
+            let var = var_b + (var_a - var_b) * (t - tb) / (ta - tb);
+            (var > 0.0).then(|| (var / t).sqrt())
+        }
+        (Some(p), _) | (_, Some(p)) => Some(p.iv),
+        (None, None) => None,
+    }
+}
+
+/// IV rank/percentile of `current` against past readings.
+pub fn iv_stats(current: f64, history: &[f64]) -> Option<IvStats> {
+    if history.is_empty() {
+        return None;
+    }
+
+    let low = history.iter().copied().fold(current, f64::min);
+    let high = history.iter().copied().fold(current, f64::max);
+    let rank = if high > low {
+        (current - low) / (high - low) * 100.0
+    } else {
+        50.0
+    };
+    let below = history.iter().filter(|v| **v < current).count();
+    let percentile = below as f64 / history.len() as f64 * 100.0;
+
+    Some(IvStats {
+        current,
+        low,
+        high,
+        rank,
+        percentile,
+        samples: history.len(),
+    })
+}
+
+fn days_until(expiration: NaiveDate) -> i64 {
+    let today = Utc::now().with_timezone(&New_York).date_naive();
+    (expiration - today).num_days()
+}
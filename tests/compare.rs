@@ -0,0 +1,64 @@
+use chrono::NaiveDate;
+
+use stacks_bot::models::PricePerformance;
+use stacks_bot::service::command::compare::{best_column, parse_tickers, Better};
+use stacks_bot::service::finance::compare::price_performance;
+
+fn day(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+#[test]
+fn parses_two_to_five_distinct_tickers() {
+    assert_eq!(
+        parse_tickers("nvda amd, AVGO").unwrap(),
+        ["NVDA", "AMD", "AVGO"]
+    );
+    assert_eq!(parse_tickers("BRK.B,brk.b, KO").unwrap(), ["BRK.B", "KO"]);
+
+    assert!(parse_tickers("NVDA").is_err());
+    assert!(parse_tickers("NVDA nvda").is_err());
+    assert!(parse_tickers("A B C D E F").is_err());
+    assert!(parse_tickers("NVDA AM$D").is_err());
+}
+
+#[test]
+fn picks_best_column_by_direction() {
+    let values = [Some(25.0), None, Some(-4.0), Some(18.0)];
+    assert_eq!(best_column(&values, Better::Higher), Some(0));
+    // Negative multiples are not ranked as cheapest
+    assert_eq!(best_column(&values, Better::Lower), Some(3));
+    assert_eq!(best_column(&values, Better::Neither), None);
+    assert_eq!(best_column(&[Some(1.0), None], Better::Higher), None);
+    assert_eq!(best_column(&[Some(0.0), Some(0.4)], Better::Lower), Some(0));
+    assert_eq!(best_column(&[Some(0.3), Some(0.3)], Better::Lower), None);
+}
+
+#[test]
+fn trailing_returns_from_daily_closes() {
+    let closes = vec![
+        (day(2025, 1, 2), 80.0),
+        (day(2025, 12, 31), 100.0),
+        (day(2026, 4, 30), 110.0),
+        (day(2026, 6, 26), 120.0),
+        (day(2026, 7, 31), 132.0),
+    ];
+    let perf = price_performance(&closes);
+    let close_to = |v: Option<f64>, want: f64| (v.unwrap() - want).abs() < 1e-9;
+
+    // 2026-07-01 falls back to the 2026-06-26 close
+    assert!(close_to(perf.one_month, 10.0));
+    // 2026-05-01 falls back to the 2026-04-30 close
+    assert!(close_to(perf.three_months, 20.0));
+    assert!(close_to(perf.year_to_date, 32.0));
+    // No close on or before 2025-07-31 other than 2025-01-02
+    assert!(close_to(perf.one_year, 65.0));
+
+    // History shorter than the lookback leaves the return empty
+    let short = price_performance(&closes[3..]);
+    assert!(short.one_month.is_some());
+    assert_eq!(short.three_months, None);
+    assert_eq!(short.one_year, None);
+
+    assert_eq!(price_performance(&[]), PricePerformance::default());
+}
@@ -0,0 +1,195 @@
+use stacks_bot::models::{FinancialRatios, FinancialSummary, TtmFigures};
+use stacks_bot::service::command::dcf::{
+    check_rate, parse_rate, DISCOUNT_RANGE, GROWTH_RANGE, TERMINAL_RANGE,
+};
+use stacks_bot::service::finance::dcf::{
+    implied_growth, sensitivity_grid, value, DcfAssumptions, DcfError, DcfInputs,
+};
+
+fn inputs() -> DcfInputs {
+    DcfInputs {
+        free_cash_flow: 100.0,
+        shares: 8.0,
+        net_debt: 200.0,
+    }
+}
+
+fn assumptions(growth_pct: f64, discount_pct: f64, terminal_pct: f64) -> DcfAssumptions {
+    DcfAssumptions {
+        growth_pct,
+        discount_pct,
+        terminal_pct,
+    }
+}
+
+fn summary(free_cash_flow: Option<f64>, shares: Option<f64>) -> FinancialSummary {
+    FinancialSummary {
+        symbol: "TEST".to_string(),
+        shares_outstanding: shares,
+        ttm: TtmFigures {
+            free_cash_flow,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+fn close(actual: f64, expected: f64) {
+    assert!((actual - expected).abs() < 1e-6, "{actual} != {expected}");
+}
+
+#[test]
+fn flat_cash_flows_value_as_a_perpetuity() {
+    let valuation = value(&inputs(), &assumptions(0.0, 10.0, 0.0)).expect("valuation");
+
+    assert_eq!(valuation.cash_flows.len(), 10);
+    close(valuation.enterprise_value, 1000.0);
+    close(valuation.equity_value, 800.0);
+    close(valuation.per_share, 100.0);
+}
+
+#[test]
+fn growth_fades_linearly_to_terminal_after_stage_one() {
+    let a = assumptions(12.0, 9.0, 2.0);
+
+    close(a.growth_in_year(1), 12.0);
+    close(a.growth_in_year(5), 12.0);
+    close(a.growth_in_year(6), 10.0);
+    close(a.growth_in_year(8), 6.0);
+    close(a.growth_in_year(10), 2.0);
+
+    let valuation = value(&inputs(), &a).expect("valuation");
+    close(valuation.cash_flows[0], 112.0);
+    close(valuation.cash_flows[5], 100.0 * 1.12f64.powi(5) * 1.10);
+}
+
+#[test]
+fn discount_must_exceed_terminal_growth() {
+    assert_eq!(
+        value(&inputs(), &assumptions(5.0, 3.0, 3.0)),
+        Err(DcfError::DiscountNotAboveTerminal {
+            discount: 3.0,
+            terminal: 3.0
+        })
+    );
+}
+
+#[test]
+fn reverse_dcf_recovers_the_growth_behind_a_price() {
+    let base = assumptions(5.0, 9.0, 2.5);
+    let price = value(&inputs(), &assumptions(14.0, 9.0, 2.5))
+        .expect("valuation")
+        .per_share;
+
+    let implied = implied_growth(&inputs(), &base, price).expect("implied growth");
+    assert!((implied - 14.0).abs() < 1e-4, "{implied}");
+}
+
+#[test]
+fn reverse_dcf_gives_up_outside_the_search_range() {
+    let base = assumptions(5.0, 9.0, 2.5);
+    assert_eq!(implied_growth(&inputs(), &base, 1e9), None);
+    assert_eq!(implied_growth(&inputs(), &base, -1e9), None);
+}
+
+#[test]
+fn sensitivity_grid_is_centred_on_the_base_case() {
+    let base = assumptions(6.0, 9.0, 2.5);
+    let grid = sensitivity_grid(&inputs(), &base, 1.0, 2.0, 2);
+
+    assert_eq!(grid.discount_pcts, vec![7.0, 8.0, 9.0, 10.0, 11.0]);
+    assert_eq!(grid.growth_pcts, vec![2.0, 4.0, 6.0, 8.0, 10.0]);
+    let centre = grid.values[2][2].expect("base value");
+    close(
+        centre,
+        value(&inputs(), &base).expect("valuation").per_share,
+    );
+
+    // Higher growth is worth more, a higher discount rate less
+    assert!(grid.values[2][3].unwrap() > centre);
+    assert!(grid.values[3][2].unwrap() < centre);
+}
+
+#[test]
+fn sensitivity_grid_leaves_invalid_discount_rates_empty() {
+    let base = assumptions(6.0, 3.0, 2.5);
+    let grid = sensitivity_grid(&inputs(), &base, 1.0, 2.0, 1);
+
+    assert_eq!(grid.discount_pcts, vec![2.0, 3.0, 4.0]);
+    assert!(grid.values[0].iter().all(Option::is_none));
+    assert!(grid.values[1].iter().all(Option::is_some));
+}
+
+#[test]
+fn inputs_come_from_the_summary() {
+    let mut s = summary(Some(50.0), Some(10.0));
+    assert_eq!(
+        DcfInputs::from_summary(&s),
+        Ok(DcfInputs {
+            free_cash_flow: 50.0,
+            shares: 10.0,
+            net_debt: 0.0
+        })
+    );
+
+    s.net_debt = Some(-30.0);
+    assert_eq!(DcfInputs::from_summary(&s).unwrap().net_debt, -30.0);
+}
+
+#[test]
+fn inputs_need_positive_free_cash_flow_and_shares() {
+    assert_eq!(
+        DcfInputs::from_summary(&summary(None, Some(10.0))),
+        Err(DcfError::MissingFreeCashFlow("TEST".into()))
+    );
+    assert!(matches!(
+        DcfInputs::from_summary(&summary(Some(-5.0e9), Some(10.0))),
+        Err(DcfError::NonPositiveFreeCashFlow(_, _))
+    ));
+    assert_eq!(
+        DcfInputs::from_summary(&summary(Some(50.0), None)),
+        Err(DcfError::MissingShares("TEST".into()))
+    );
+}
+
+#[test]
+fn default_growth_is_clamped_revenue_growth() {
+    let mut s = summary(Some(50.0), Some(10.0));
+    assert_eq!(DcfAssumptions::defaults(&s), assumptions(5.0, 9.0, 2.5));
+
+    s.ratios = FinancialRatios {
+        revenue_growth: Some(35.0),
+        ..Default::default()
+    };
+    assert_eq!(DcfAssumptions::defaults(&s).growth_pct, 20.0);
+
+    s.ratios.revenue_growth = Some(-8.0);
+    assert_eq!(DcfAssumptions::defaults(&s).growth_pct, 0.0);
+
+    s.ratios.revenue_growth = Some(7.5);
+    assert_eq!(DcfAssumptions::defaults(&s).growth_pct, 7.5);
+}
+
+#[test]
+fn parses_mention_rates() {
+    assert_eq!(parse_rate("8"), Ok(8.0));
+    assert_eq!(parse_rate("8.5%"), Ok(8.5));
+    assert_eq!(parse_rate("-3"), Ok(-3.0));
+    assert!(parse_rate("fast").is_err());
+    assert!(parse_rate("nan").is_err());
+}
+
+#[test]
+fn rates_outside_the_slash_option_ranges_are_rejected() {
+    assert_eq!(check_rate("growth", -50.0, GROWTH_RANGE), Ok(-50.0));
+    assert_eq!(check_rate("discount", 9.0, DISCOUNT_RANGE), Ok(9.0));
+    assert_eq!(check_rate("terminal", 10.0, TERMINAL_RANGE), Ok(10.0));
+
+    // `dcf AAPL 5 -100 -150` would otherwise discount by zero
+    assert_eq!(
+        check_rate("discount", -100.0, DISCOUNT_RANGE),
+        Err("discount rate must be between 1% and 30% (got -100%)".to_string())
+    );
+    assert!(check_rate("terminal", -150.0, TERMINAL_RANGE).is_err());
+    assert!(check_rate("growth", 100.5, GROWTH_RANGE).is_err());
+}
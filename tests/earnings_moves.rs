@@ -0,0 +1,101 @@
+use chrono::{DateTime, NaiveDate, Utc};
+
+use stacks_bot::models::EarningsSession;
+use stacks_bot::service::automation::earnings::{last_closed_session, realized_move_pct};
+
+fn d(s: &str) -> NaiveDate {
+    s.parse().unwrap()
+}
+
+fn at(s: &str) -> DateTime<Utc> {
+    s.parse().unwrap()
+}
+
+/// Wed Oct 14 → Thu Oct 15, with Thursday's bar still forming during the session.
+fn closes() -> Vec<(NaiveDate, f64)> {
+    vec![
+        (d("2026-10-13"), 100.0),
+        (d("2026-10-14"), 100.0),
+        (d("2026-10-15"), 110.0),
+    ]
+}
+
+#[test]
+fn last_closed_session_rolls_over_at_the_close() {
+    // 11:00 and 15:59 ET (EDT, UTC-4)
+    assert_eq!(
+        last_closed_session(at("2026-10-15T15:00:00Z")),
+        d("2026-10-14")
+    );
+    assert_eq!(
+        last_closed_session(at("2026-10-15T19:59:00Z")),
+        d("2026-10-14")
+    );
+    // 16:00 ET
+    assert_eq!(
+        last_closed_session(at("2026-10-15T20:00:00Z")),
+        d("2026-10-15")
+    );
+    // 00:30 UTC on the 16th is still the evening of the 15th in New York
+    assert_eq!(
+        last_closed_session(at("2026-10-16T00:30:00Z")),
+        d("2026-10-15")
+    );
+}
+
+#[test]
+fn intraday_bar_does_not_settle_amc_move() {
+    let intraday = last_closed_session(at("2026-10-15T15:00:00Z"));
+    assert_eq!(
+        realized_move_pct(
+            &closes(),
+            d("2026-10-14"),
+            EarningsSession::AfterClose,
+            intraday
+        ),
+        None
+    );
+    assert_eq!(
+        realized_move_pct(
+            &closes(),
+            d("2026-10-14"),
+            EarningsSession::Unknown,
+            intraday
+        ),
+        None
+    );
+
+    let after_close = last_closed_session(at("2026-10-15T20:30:00Z"));
+    let settled = realized_move_pct(
+        &closes(),
+        d("2026-10-14"),
+        EarningsSession::AfterClose,
+        after_close,
+    )
+    .unwrap();
+    assert!((settled - 10.0).abs() < 1e-9);
+}
+
+#[test]
+fn intraday_bar_does_not_settle_bmo_move() {
+    let intraday = last_closed_session(at("2026-10-15T15:00:00Z"));
+    assert_eq!(
+        realized_move_pct(
+            &closes(),
+            d("2026-10-15"),
+            EarningsSession::BeforeOpen,
+            intraday
+        ),
+        None
+    );
+
+    let after_close = last_closed_session(at("2026-10-15T20:30:00Z"));
+    let settled = realized_move_pct(
+        &closes(),
+        d("2026-10-15"),
+        EarningsSession::BeforeOpen,
+        after_close,
+    )
+    .unwrap();
+    assert!((settled - 10.0).abs() < 1e-9);
+}
@@ -0,0 +1,241 @@
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use redis::AsyncCommands;
+use serde_json::json;
+
+use stacks_bot::models::EarningsEvent;
+use stacks_bot::service::automation::earnings::{due_stages, is_reported_quarter, report_time};
+use stacks_bot::service::caching::collections::earnings_subscriptions::{
+    self, Delivery, EarningsSubscription, ReminderStage,
+};
+use stacks_bot::service::caching::RedisCache;
+
+use ReminderStage::{Eve, Report, Results};
+
+fn d(s: &str) -> NaiveDate {
+    s.parse().unwrap()
+}
+
+fn at(s: &str) -> NaiveDateTime {
+    s.parse().unwrap()
+}
+
+fn hm(h: u32, m: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(h, m, 0).unwrap()
+}
+
+fn event(time: Option<&str>) -> EarningsEvent {
+    let mut ev: EarningsEvent =
+        serde_json::from_value(json!({ "symbol": "AAPL", "date": "2026-10-29T00:00:00Z" }))
+            .unwrap();
+    ev.time_of_day = time.map(str::to_string);
+    ev.session = stacks_bot::models::EarningsSession::classify(time);
+    ev
+}
+
+#[test]
+fn stages_follow_the_report_time() {
+    // Thursday after-close report at 16:05 ET
+    let date = d("2026-10-29");
+    let time = hm(16, 5);
+
+    assert_eq!(due_stages(at("2026-10-28T17:59:00"), date, time), []);
+    assert_eq!(due_stages(at("2026-10-28T18:00:00"), date, time), [Eve]);
+    assert_eq!(due_stages(at("2026-10-28T23:30:00"), date, time), [Eve]);
+    assert_eq!(due_stages(at("2026-10-29T09:00:00"), date, time), []);
+    assert_eq!(
+        due_stages(at("2026-10-29T16:05:00"), date, time),
+        [Report, Results]
+    );
+    assert_eq!(
+        due_stages(at("2026-10-29T18:04:00"), date, time),
+        [Report, Results]
+    );
+    // Report-time window is two hours; after that only results remain
+    assert_eq!(due_stages(at("2026-10-29T18:05:00"), date, time), [Results]);
+    assert_eq!(due_stages(at("2026-10-30T09:00:00"), date, time), [Results]);
+}
+
+#[test]
+fn monday_report_gets_its_eve_on_friday() {
+    let date = d("2026-10-26");
+    let time = hm(7, 0);
+
+    assert_eq!(due_stages(at("2026-10-23T18:30:00"), date, time), [Eve]);
+    assert_eq!(due_stages(at("2026-10-25T18:30:00"), date, time), []);
+    assert_eq!(
+        due_stages(at("2026-10-26T07:00:00"), date, time),
+        [Report, Results]
+    );
+}
+
+#[test]
+fn report_time_prefers_the_calendar_clock() {
+    assert_eq!(report_time(&event(Some("16:30:00"))), hm(16, 30));
+    assert_eq!(report_time(&event(Some("4:30 PM"))), hm(16, 30));
+    assert_eq!(report_time(&event(Some("bmo"))), hm(7, 0));
+    assert_eq!(report_time(&event(Some("amc"))), hm(16, 5));
+    assert_eq!(report_time(&event(Some("dmh"))), hm(9, 30));
+    assert_eq!(report_time(&event(None)), hm(9, 30));
+}
+
+#[test]
+fn results_must_be_for_the_reported_quarter() {
+    let report = d("2026-10-29");
+
+    // The quarter that just ended
+    assert!(is_reported_quarter(d("2026-09-27"), report, None));
+    assert!(is_reported_quarter(
+        d("2026-09-27"),
+        report,
+        Some(d("2026-06-28"))
+    ));
+
+    // Yahoo still shows last quarter: too old for this report
+    assert!(!is_reported_quarter(d("2026-06-28"), report, None));
+    // Or it is the quarter a results reminder already went out for
+    assert!(!is_reported_quarter(
+        d("2026-09-27"),
+        report,
+        Some(d("2026-09-27"))
+    ));
+    // A quarter ending after the report date is not its result
+    assert!(!is_reported_quarter(d("2026-12-27"), report, None));
+}
+
+fn subscription(user_id: u64, symbol: &str, delivery: Delivery) -> EarningsSubscription {
+    EarningsSubscription {
+        user_id,
+        symbol: symbol.to_string(),
+        delivery,
+        created_at: Utc::now(),
+    }
+}
+
+#[tokio::test]
+#[ignore = "requires REDIS_URL"]
+async fn subscribe_and_unsubscribe() {
+    let cache = RedisCache::from_env().await.expect("REDIS_URL");
+    let symbol = "ZZSUBTEST";
+    let _: () = cache
+        .connection()
+        .del(format!("earnings:subs:{symbol}"))
+        .await
+        .unwrap();
+
+    let first = subscription(1, symbol, Delivery::Dm);
+    assert!(earnings_subscriptions::subscribe(&cache, &first)
+        .await
+        .unwrap());
+    // Re-subscribing replaces the delivery instead of adding a second entry
+    let moved = subscription(1, symbol, Delivery::Channel { channel_id: 42 });
+    assert!(!earnings_subscriptions::subscribe(&cache, &moved)
+        .await
+        .unwrap());
+    assert!(
+        earnings_subscriptions::subscribe(&cache, &subscription(2, symbol, Delivery::Dm))
+            .await
+            .unwrap()
+    );
+
+    let all = earnings_subscriptions::load_all(&cache).await.unwrap();
+    assert_eq!(all[symbol].len(), 2);
+    let mine = earnings_subscriptions::list_for_user(&cache, 1)
+        .await
+        .unwrap();
+    assert!(mine
+        .iter()
+        .any(|s| s.symbol == symbol && s.delivery == Delivery::Channel { channel_id: 42 }));
+    assert!(earnings_subscriptions::symbols(&cache)
+        .await
+        .unwrap()
+        .contains(&symbol.to_string()));
+
+    assert!(earnings_subscriptions::unsubscribe(&cache, 1, "zzsubtest")
+        .await
+        .unwrap());
+    assert!(!earnings_subscriptions::unsubscribe(&cache, 1, symbol)
+        .await
+        .unwrap());
+    assert!(earnings_subscriptions::symbols(&cache)
+        .await
+        .unwrap()
+        .contains(&symbol.to_string()));
+
+    // Last subscriber gone: the symbol leaves the set
+    assert!(earnings_subscriptions::unsubscribe(&cache, 2, symbol)
+        .await
+        .unwrap());
+    assert!(!earnings_subscriptions::symbols(&cache)
+        .await
+        .unwrap()
+        .contains(&symbol.to_string()));
+    assert!(!earnings_subscriptions::load_all(&cache)
+        .await
+        .unwrap()
+        .contains_key(symbol));
+}
+
+#[tokio::test]
+#[ignore = "requires REDIS_URL"]
+async fn each_stage_is_notified_once() {
+    let cache = RedisCache::from_env().await.expect("REDIS_URL");
+    let symbol = "ZZNOTIFYTEST";
+    let date = Utc::now().date_naive();
+
+    let key = format!(
+        "earnings:subs:notified:{}:{}",
+        symbol,
+        date.format("%Y-%m-%d")
+    );
+    let _: () = cache.connection().del(&key).await.unwrap();
+
+    for stage in [Eve, Report, Results] {
+        assert!(
+            earnings_subscriptions::mark_notified(&cache, symbol, date, stage)
+                .await
+                .unwrap()
+        );
+        assert!(
+            !earnings_subscriptions::mark_notified(&cache, symbol, date, stage)
+                .await
+                .unwrap()
+        );
+        assert!(
+            earnings_subscriptions::was_notified(&cache, symbol, date, stage)
+                .await
+                .unwrap()
+        );
+    }
+    assert!(
+        !earnings_subscriptions::was_notified(&cache, symbol, date.succ_opt().unwrap(), Eve)
+            .await
+            .unwrap()
+    );
+}
+
+#[tokio::test]
+#[ignore = "requires REDIS_URL"]
+async fn records_the_last_results_quarter() {
+    let cache = RedisCache::from_env().await.expect("REDIS_URL");
+    let _: () = cache
+        .connection()
+        .del("earnings:subs:results_quarter:ZZRESULTS")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        earnings_subscriptions::last_results_quarter(&cache, "zzresults")
+            .await
+            .unwrap(),
+        None
+    );
+    earnings_subscriptions::record_results_quarter(&cache, "zzresults", d("2026-09-27"))
+        .await
+        .unwrap();
+    assert_eq!(
+        earnings_subscriptions::last_results_quarter(&cache, "ZZRESULTS")
+            .await
+            .unwrap(),
+        Some(d("2026-09-27"))
+    );
+}
@@ -0,0 +1,105 @@
+use chrono::NaiveDate;
+use serde_json::{json, Value};
+
+use stacks_bot::service::finance::earnings::{reported_revenue_estimate, select_reported_quarter};
+
+fn d(s: &str) -> NaiveDate {
+    s.parse().unwrap()
+}
+
+fn trend(entries: &[(&str, &str, f64)]) -> Value {
+    let trend: Vec<Value> = entries
+        .iter()
+        .map(|(period, end, revenue)| {
+            json!({
+                "period": period,
+                "endDate": end,
+                "revenueEstimate": { "avg": { "raw": revenue, "fmt": "" } },
+            })
+        })
+        .collect();
+    json!({ "earningsTrend": { "trend": trend } })
+}
+
+#[test]
+fn takes_revenue_estimate_of_the_reported_quarter() {
+    let result = trend(&[
+        ("0q", "2026-09-30", 100.0e9),
+        ("+1q", "2026-12-31", 120.0e9),
+        ("0y", "2026-09-30", 400.0e9),
+    ]);
+
+    // 52/53-week fiscal quarter ending a few days before the calendar quarter
+    assert_eq!(
+        reported_revenue_estimate(&result, d("2026-09-27")),
+        Some(100.0e9)
+    );
+    assert_eq!(
+        reported_revenue_estimate(&result, d("2026-12-31")),
+        Some(120.0e9)
+    );
+}
+
+#[test]
+fn ignores_next_quarter_once_the_trend_has_rolled() {
+    // After the report Yahoo may already show the next quarter as 0q
+    let result = trend(&[
+        ("0q", "2026-12-31", 120.0e9),
+        ("+1q", "2027-03-31", 125.0e9),
+    ]);
+
+    assert_eq!(reported_revenue_estimate(&result, d("2026-09-27")), None);
+}
+
+#[test]
+fn ignores_annual_periods_and_missing_trend() {
+    let result = trend(&[("0y", "2026-09-30", 400.0e9)]);
+
+    assert_eq!(reported_revenue_estimate(&result, d("2026-09-30")), None);
+    assert_eq!(reported_revenue_estimate(&json!({}), d("2026-09-30")), None);
+}
+
+fn history(entries: &[(&str, Option<f64>, f64)]) -> Value {
+    let history: Vec<Value> = entries
+        .iter()
+        .map(|(quarter, actual, estimate)| {
+            let ts = d(quarter)
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+                .and_utc()
+                .timestamp();
+            json!({
+                "quarter": { "raw": ts, "fmt": quarter },
+                "epsActual": actual.map(|a| json!({ "raw": a, "fmt": "" })).unwrap_or(json!({})),
+                "epsEstimate": { "raw": estimate, "fmt": "" },
+            })
+        })
+        .collect();
+    json!({ "earningsHistory": { "history": history } })
+}
+
+#[test]
+fn selects_the_latest_quarter_before_the_report() {
+    let result = history(&[
+        ("2026-06-30", Some(1.40), 1.35),
+        ("2026-09-30", Some(1.60), 1.50),
+    ]);
+
+    assert_eq!(
+        select_reported_quarter(&result, d("2026-10-14")),
+        Some((d("2026-09-30"), 1.60, Some(1.50)))
+    );
+    // Quarters ending after the report date are not its results
+    assert_eq!(
+        select_reported_quarter(&result, d("2026-09-29")).map(|q| q.0),
+        Some(d("2026-06-30"))
+    );
+}
+
+#[test]
+fn waits_for_actuals_instead_of_using_the_prior_quarter() {
+    // Yahoo lists the new quarter before its actual EPS is posted
+    let result = history(&[("2026-06-30", Some(1.40), 1.35), ("2026-09-30", None, 1.50)]);
+
+    assert_eq!(select_reported_quarter(&result, d("2026-10-14")), None);
+}
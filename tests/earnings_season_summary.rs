@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+
+use chrono::{NaiveDate, Utc};
+use redis::AsyncCommands;
+use serde_json::json;
+
+use stacks_bot::models::{EarningsEvent, EarningsSeason, EarningsSession};
+use stacks_bot::service::caching::collections::earnings_season::{self, SeasonData, SeasonReport};
+use stacks_bot::service::caching::RedisCache;
+use stacks_bot::service::command::earnings_season::summarize;
+
+fn day(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+fn event(symbol: &str, date: &str) -> EarningsEvent {
+    serde_json::from_value(json!({ "symbol": symbol, "date": date })).unwrap()
+}
+
+fn report(
+    symbol: &str,
+    eps: (f64, f64),
+    revenue: (f64, f64),
+    reaction: Option<f64>,
+) -> SeasonReport {
+    SeasonReport {
+        symbol: symbol.to_string(),
+        date: day(2026, 10, 14),
+        session: EarningsSession::AfterClose,
+        eps_actual: Some(eps.0),
+        eps_estimate: Some(eps.1),
+        revenue_actual: Some(revenue.0),
+        revenue_estimate: Some(revenue.1),
+        reaction_pct: reaction,
+        recorded_at: Utc::now(),
+    }
+}
+
+#[test]
+fn seasons_follow_the_reported_quarter() {
+    let q3 = EarningsSeason::for_report_date(day(2026, 10, 19));
+    assert_eq!(
+        q3,
+        EarningsSeason {
+            year: 2026,
+            quarter: 3
+        }
+    );
+    assert_eq!(q3.start(), day(2026, 10, 1));
+    assert_eq!(q3.end(), day(2026, 12, 31));
+    assert_eq!(q3.key(), "2026Q3");
+    assert_eq!(q3.to_string(), "Q3 2026");
+
+    // January–March reports cover the prior year's fourth quarter
+    let q4 = EarningsSeason::for_report_date(day(2027, 2, 3));
+    assert_eq!(
+        q4,
+        EarningsSeason {
+            year: 2026,
+            quarter: 4
+        }
+    );
+    assert_eq!(q4.start(), day(2027, 1, 1));
+    assert_eq!(q4.end(), day(2027, 3, 31));
+    assert_eq!(q3.next(), q4);
+    assert_eq!(EarningsSeason::for_report_date(day(2027, 4, 1)), q4.next());
+}
+
+#[test]
+fn summarizes_beats_reactions_and_movers() {
+    let data = SeasonData {
+        scheduled: vec![
+            event("AAA", "2026-10-14T00:00:00Z"),
+            event("BBB", "2026-10-15T00:00:00Z"),
+            event("CCC", "2026-10-28T00:00:00Z"),
+            event("DDD", "2026-11-04T00:00:00Z"),
+        ],
+        reported: vec![
+            // EPS beat, revenue inline
+            report("AAA", (1.10, 1.00), (100.0, 100.0), Some(8.0)),
+            // EPS miss, revenue beat
+            report("BBB", (0.90, 1.00), (110.0, 100.0), Some(-4.0)),
+            // Not on the stored calendar; tiny estimate is capped when averaging
+            report("EEE", (0.50, 0.01), (95.0, 100.0), None),
+        ],
+        ..Default::default()
+    };
+
+    let summary = summarize(&data, day(2026, 10, 19));
+    assert_eq!(summary.companies, 5);
+    assert_eq!(summary.reported, 3);
+
+    assert_eq!(
+        (summary.eps.beat, summary.eps.inline, summary.eps.miss),
+        (2, 0, 1)
+    );
+    let eps_avg = summary.eps.avg_surprise_pct.unwrap();
+    assert!(
+        (eps_avg - (10.0 - 10.0 + 100.0) / 3.0).abs() < 1e-9,
+        "{eps_avg}"
+    );
+    assert_eq!(
+        (
+            summary.revenue.beat,
+            summary.revenue.inline,
+            summary.revenue.miss
+        ),
+        (1, 1, 1)
+    );
+
+    assert_eq!(summary.reactions, 2);
+    assert_eq!(summary.avg_reaction_pct, Some(2.0));
+    assert_eq!(summary.avg_abs_reaction_pct, Some(6.0));
+    let gainers: Vec<_> = summary.gainers.iter().map(|r| r.symbol.as_str()).collect();
+    let losers: Vec<_> = summary.losers.iter().map(|r| r.symbol.as_str()).collect();
+    assert_eq!(gainers, ["AAA"]);
+    assert_eq!(losers, ["BBB"]);
+}
+
+#[test]
+fn empty_season_has_no_averages() {
+    let summary = summarize(&SeasonData::default(), day(2026, 10, 19));
+    assert_eq!(summary.companies, 0);
+    assert_eq!(summary.eps.count(), 0);
+    assert_eq!(summary.eps.avg_surprise_pct, None);
+    assert_eq!(summary.avg_reaction_pct, None);
+    assert!(summary.gainers.is_empty() && summary.losers.is_empty());
+}
+
+#[tokio::test]
+#[ignore = "requires REDIS_URL"]
+async fn sectors_are_saved_with_the_season_calendar() {
+    let cache = RedisCache::from_env().await.expect("REDIS_URL");
+    let season = EarningsSeason::for_report_date(day(2099, 10, 14));
+    let mut conn = cache.connection();
+    let _: () = conn
+        .del(&[
+            format!("earnings:season:{}:scheduled", season.key()),
+            format!("earnings:season:{}:sectors", season.key()),
+        ])
+        .await
+        .unwrap();
+
+    let events = [
+        event("aaa", "2099-10-14T00:00:00Z"),
+        event("BBB", "2099-10-15T00:00:00Z"),
+    ];
+    // BBB has no known sector, so it is only on the calendar
+    let sectors = HashMap::from([("AAA".to_string(), "Technology".to_string())]);
+    earnings_season::record_scheduled(&cache, &events, &sectors)
+        .await
+        .unwrap();
+
+    let backfill = HashMap::from([("CCC".to_string(), "Energy".to_string())]);
+    earnings_season::record_sectors(&cache, season, &backfill)
+        .await
+        .unwrap();
+
+    let data = earnings_season::load(&cache, season).await.unwrap();
+    assert_eq!(data.scheduled.len(), 2);
+    assert_eq!(
+        data.sectors,
+        HashMap::from([
+            ("AAA".to_string(), "Technology".to_string()),
+            ("CCC".to_string(), "Energy".to_string()),
+        ])
+    );
+}
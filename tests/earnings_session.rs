@@ -0,0 +1,146 @@
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use chrono::NaiveTime;
+
+use stacks_bot::models::earnings::report_clock;
+use stacks_bot::models::{EarningsEvent, EarningsSession};
+
+const FIXTURE: &str = "build-docs/stacks-bot-docs/json_output/earnings_output.json";
+
+fn hm(h: u32, m: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(h, m, 0).unwrap()
+}
+
+/// Every session hint the classifier is expected to handle, including the time strings
+/// the earnings API actually returns (see `FIXTURE`).
+#[test]
+fn classifies_time_hints() {
+    use EarningsSession::*;
+
+    let cases: &[(Option<&str>, EarningsSession)] = &[
+        // Clock times from the earnings API fixture
+        (Some("03:39:17"), BeforeOpen),
+        (Some("06:45:00"), BeforeOpen),
+        (Some("08:00:00"), BeforeOpen),
+        (Some("09:00:00"), BeforeOpen),
+        (Some("10:00:00"), DuringMarket),
+        (Some("11:00:00"), DuringMarket),
+        (Some("12:00:00"), DuringMarket),
+        (Some("15:56:16"), DuringMarket),
+        (Some("16:00:00"), AfterClose),
+        (Some("16:05:00"), AfterClose),
+        (Some("17:00:00"), AfterClose),
+        (Some("18:07:55"), AfterClose),
+        (Some("00:00:00"), Unknown),
+        // Market-open / close boundaries
+        (Some("09:29"), BeforeOpen),
+        (Some("09:30"), DuringMarket),
+        (Some("15:59:59"), DuringMarket),
+        (Some("16:00"), AfterClose),
+        (Some("16"), AfterClose),
+        (Some("9"), BeforeOpen),
+        // 12-hour clocks
+        (Some("8am"), BeforeOpen),
+        (Some("9:15 AM"), BeforeOpen),
+        (Some("12:30 pm"), DuringMarket),
+        (Some("4:30 PM"), AfterClose),
+        (Some("12 am"), Unknown),
+        // Keywords
+        (Some("bmo"), BeforeOpen),
+        (Some("BMO"), BeforeOpen),
+        (Some("amc"), AfterClose),
+        (Some("AMC"), AfterClose),
+        (Some("dmh"), DuringMarket),
+        (Some("Pre-market"), BeforeOpen),
+        (Some("premarket"), BeforeOpen),
+        (Some("pre"), BeforeOpen),
+        (Some("Before Market Open"), BeforeOpen),
+        (Some("before the open"), BeforeOpen),
+        (Some("after-hours"), AfterClose),
+        (Some("After Market Close"), AfterClose),
+        (Some("post_market"), AfterClose),
+        (Some("during market hours"), DuringMarket),
+        (Some("intraday"), DuringMarket),
+        // Words that merely contain a keyword are not hints
+        (Some("previous"), Unknown),
+        (Some("preliminary"), Unknown),
+        (Some("postponed"), Unknown),
+        (Some("time not supplied"), Unknown),
+        (Some("TBA"), Unknown),
+        (Some(""), Unknown),
+        (Some("  "), Unknown),
+        (Some("25:00"), Unknown),
+        (Some("9:75"), Unknown),
+        (None, Unknown),
+    ];
+
+    for (input, expected) in cases {
+        assert_eq!(
+            EarningsSession::classify(*input),
+            *expected,
+            "classify({:?})",
+            input
+        );
+    }
+}
+
+#[test]
+fn parses_report_clock() {
+    assert_eq!(report_clock(Some("16:05:00")), Some(hm(16, 5)));
+    assert_eq!(report_clock(Some("06:45")), Some(hm(6, 45)));
+    assert_eq!(report_clock(Some("7")), Some(hm(7, 0)));
+    assert_eq!(report_clock(Some("4:30 PM")), Some(hm(16, 30)));
+    assert_eq!(report_clock(Some("12 pm")), Some(hm(12, 0)));
+    assert_eq!(report_clock(Some("00:00:00")), None);
+    assert_eq!(report_clock(Some("amc")), None);
+    assert_eq!(report_clock(None), None);
+}
+
+#[test]
+fn labels_round_trip() {
+    for session in [
+        EarningsSession::BeforeOpen,
+        EarningsSession::DuringMarket,
+        EarningsSession::AfterClose,
+        EarningsSession::Unknown,
+    ] {
+        assert_eq!(EarningsSession::from_label(session.as_str()), Some(session));
+        let json = serde_json::to_string(&session).unwrap();
+        assert_eq!(json, format!("\"{}\"", session.as_str()));
+        assert_eq!(
+            serde_json::from_str::<EarningsSession>(&json).unwrap(),
+            session
+        );
+    }
+    assert_eq!(
+        EarningsSession::from_label("amc"),
+        Some(EarningsSession::AfterClose)
+    );
+    assert_eq!(EarningsSession::from_label("later"), None);
+}
+
+/// Every time string in the captured API response classifies by its clock alone.
+#[test]
+fn fixture_times_match_clock_rule() -> Result<(), Box<dyn std::error::Error>> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(FIXTURE);
+    let events: Vec<EarningsEvent> = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+    assert!(!events.is_empty(), "fixture has no events");
+
+    let mut seen = BTreeSet::new();
+    for ev in &events {
+        let session = EarningsSession::classify(ev.time_of_day.as_deref());
+        let expected = match report_clock(ev.time_of_day.as_deref()) {
+            Some(t) if t < hm(9, 30) => EarningsSession::BeforeOpen,
+            Some(t) if t >= hm(16, 0) => EarningsSession::AfterClose,
+            Some(_) => EarningsSession::DuringMarket,
+            None => EarningsSession::Unknown,
+        };
+        assert_eq!(session, expected, "{} at {:?}", ev.symbol, ev.time_of_day);
+        seen.insert(session.as_str());
+    }
+
+    // The fixture covers every session, so a regression in any branch shows up here
+    assert_eq!(seen.len(), 4, "sessions seen: {:?}", seen);
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use serde_json::json;
+
+use stacks_bot::models::EarningsEvent;
+use stacks_bot::service::finance::earnings::{EarningsSource, EarningsSourceChain};
+use stacks_bot::service::finance::FinanceServiceError;
+
+/// Stands in for the watchlist-driven Yahoo source: `refresh` reloads the symbol list
+/// from a shared "Redis" list and `fetch_range` returns one event per symbol.
+struct WatchlistSource {
+    stored: Arc<Mutex<Vec<String>>>,
+    symbols: Mutex<Vec<String>>,
+    refreshes: AtomicUsize,
+}
+
+impl WatchlistSource {
+    fn new(stored: Arc<Mutex<Vec<String>>>) -> Self {
+        Self {
+            stored,
+            symbols: Mutex::new(Vec::new()),
+            refreshes: AtomicUsize::new(0),
+        }
+    }
+}
+
+#[async_trait]
+impl EarningsSource for WatchlistSource {
+    fn name(&self) -> &'static str {
+        "watchlist"
+    }
+
+    async fn refresh(&self) {
+        self.refreshes.fetch_add(1, Ordering::SeqCst);
+        *self.symbols.lock().unwrap() = self.stored.lock().unwrap().clone();
+    }
+
+    async fn fetch_range(
+        &self,
+        from: NaiveDate,
+        _to: NaiveDate,
+    ) -> Result<Vec<EarningsEvent>, FinanceServiceError> {
+        Ok(self
+            .symbols
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|symbol| {
+                serde_json::from_value(
+                    json!({ "symbol": symbol, "date": format!("{from}T20:00:00Z") }),
+                )
+                .unwrap()
+            })
+            .collect())
+    }
+}
+
+/// Primary source that always fails, forcing the fallback.
+struct FailingSource;
+
+#[async_trait]
+impl EarningsSource for FailingSource {
+    fn name(&self) -> &'static str {
+        "failing"
+    }
+
+    async fn fetch_range(
+        &self,
+        _from: NaiveDate,
+        _to: NaiveDate,
+    ) -> Result<Vec<EarningsEvent>, FinanceServiceError> {
+        Err(FinanceServiceError::Http("down".into()))
+    }
+}
+
+fn symbols(events: &[EarningsEvent]) -> Vec<&str> {
+    events.iter().map(|e| e.symbol.as_str()).collect()
+}
+
+#[tokio::test]
+async fn fallback_reloads_watchlist_before_each_fetch() {
+    let stored = Arc::new(Mutex::new(vec!["AAPL".to_string()]));
+    let fallback = Arc::new(WatchlistSource::new(stored.clone()));
+    let chain = EarningsSourceChain::new(vec![Arc::new(FailingSource), fallback.clone()]);
+    let day = NaiveDate::from_ymd_opt(2026, 10, 19).unwrap();
+
+    let events = chain.fetch_range(day, day).await.unwrap();
+    assert_eq!(symbols(&events), ["AAPL"]);
+
+    // A symbol added after startup is picked up by the next fetch
+    stored.lock().unwrap().push("MSFT".to_string());
+    let events = chain.fetch_range(day, day).await.unwrap();
+    assert_eq!(symbols(&events), ["AAPL", "MSFT"]);
+    assert_eq!(fallback.refreshes.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn empty_watchlist_yields_no_events() {
+    let fallback = Arc::new(WatchlistSource::new(Arc::new(Mutex::new(Vec::new()))));
+    let chain = EarningsSourceChain::new(vec![Arc::new(FailingSource), fallback.clone()]);
+    let day = NaiveDate::from_ymd_opt(2026, 10, 19).unwrap();
+
+    assert!(chain.fetch_range(day, day).await.unwrap().is_empty());
+    assert_eq!(fallback.refreshes.load(Ordering::SeqCst), 1);
+}
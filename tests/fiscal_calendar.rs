@@ -0,0 +1,78 @@
+use chrono::NaiveDate;
+
+use stacks_bot::models::Frequency;
+use stacks_bot::service::finance::fiscal::FiscalCalendar;
+
+fn day(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+fn fiscal(calendar: FiscalCalendar, end: NaiveDate) -> (i32, u32) {
+    let period = calendar.period(end);
+    (period.year, period.quarter)
+}
+
+#[test]
+fn maps_shifted_fiscal_years() {
+    // NVDA: fiscal year ends late January, named after the year it ends
+    let nvda = FiscalCalendar::ending(day(2025, 1, 26));
+    assert_eq!(nvda.year_end_month, 1);
+    assert_eq!(fiscal(nvda, day(2025, 4, 27)), (2026, 1));
+    assert_eq!(fiscal(nvda, day(2025, 7, 27)), (2026, 2));
+    assert_eq!(fiscal(nvda, day(2025, 10, 26)), (2026, 3));
+    assert_eq!(fiscal(nvda, day(2026, 1, 25)), (2026, 4));
+
+    // AAPL: late September; Q1 spans the calendar year end
+    let aapl = FiscalCalendar::ending(day(2024, 9, 28));
+    assert_eq!(fiscal(aapl, day(2024, 12, 28)), (2025, 1));
+    assert_eq!(fiscal(aapl, day(2025, 6, 28)), (2025, 3));
+    assert_eq!(fiscal(aapl, day(2025, 9, 27)), (2025, 4));
+
+    // MSFT: June
+    let msft = FiscalCalendar::ending(day(2025, 6, 30));
+    assert_eq!(fiscal(msft, day(2025, 9, 30)), (2026, 1));
+    assert_eq!(fiscal(msft, day(2025, 3, 31)), (2025, 3));
+
+    let calendar = FiscalCalendar::CALENDAR_YEAR;
+    assert_eq!(fiscal(calendar, day(2025, 3, 31)), (2025, 1));
+    assert_eq!(fiscal(calendar, day(2025, 12, 31)), (2025, 4));
+}
+
+#[test]
+fn period_ends_early_in_a_month_belong_to_the_previous_one() {
+    // 53-week years can end a few days into the next month
+    let nvda = FiscalCalendar::ending(day(2024, 1, 28));
+    assert_eq!(FiscalCalendar::ending(day(2023, 1, 29)), nvda);
+    assert_eq!(fiscal(nvda, day(2020, 2, 2)), (2020, 4));
+
+    let aapl = FiscalCalendar::ending(day(2022, 9, 24));
+    assert_eq!(FiscalCalendar::ending(day(2023, 9, 30)), aapl);
+    assert_eq!(fiscal(aapl, day(2021, 1, 2)), (2021, 1));
+    assert_eq!(fiscal(aapl, day(2017, 12, 30)), (2018, 1));
+}
+
+#[test]
+fn filters_and_labels_by_fiscal_period() {
+    let nvda = FiscalCalendar::ending(day(2025, 1, 26));
+    let q2 = day(2025, 7, 27);
+    assert!(nvda.matches(q2, Some(2026), Some(2)));
+    assert!(nvda.matches(q2, None, Some(2)));
+    // The calendar quarter and year no longer match
+    assert!(!nvda.matches(q2, Some(2025), None));
+    assert!(!nvda.matches(q2, None, Some(3)));
+
+    assert_eq!(
+        nvda.period(q2).label(Frequency::Quarterly),
+        "FY2026 Q2 (ended Jul 27, 2025)"
+    );
+    let aapl = FiscalCalendar::ending(day(2024, 9, 28));
+    assert_eq!(
+        aapl.period(day(2025, 9, 27)).label(Frequency::Annual),
+        "FY2025 (ended Sep 27, 2025)"
+    );
+    assert_eq!(
+        aapl.period(day(2025, 3, 29))
+            .short_label(Frequency::Quarterly),
+        "FY2025 Q2"
+    );
+}
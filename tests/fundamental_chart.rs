@@ -0,0 +1,152 @@
+use chrono::NaiveDate;
+
+use stacks_bot::models::{Frequency, StatementType};
+use stacks_bot::service::command::fundamental_chart::{
+    format_series_line, parse_chart_request, period_bucket, ChartSeries,
+};
+use stacks_bot::service::finance::statements::{cagr, find_line_item, growth_rates, LineKind};
+
+fn day(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+#[test]
+fn finds_line_items_by_alias_field_and_label() {
+    let field = |q: &str| find_line_item(q).map(|(st, item)| (st, item.field));
+
+    assert_eq!(
+        field("revenue"),
+        Some((StatementType::IncomeStatement, "TotalRevenue"))
+    );
+    assert_eq!(
+        field("FCF"),
+        Some((StatementType::CashFlow, "FreeCashFlow"))
+    );
+    assert_eq!(
+        field("free cash flow"),
+        Some((StatementType::CashFlow, "FreeCashFlow"))
+    );
+    assert_eq!(
+        field("total_assets"),
+        Some((StatementType::BalanceSheet, "TotalAssets"))
+    );
+    assert_eq!(
+        field("R&D"),
+        Some((StatementType::IncomeStatement, "ResearchAndDevelopment"))
+    );
+    // Labels shared across statements resolve to the income statement first
+    assert_eq!(
+        field("Net Income"),
+        Some((StatementType::IncomeStatement, "NetIncome"))
+    );
+    assert_eq!(
+        field("gross"),
+        Some((StatementType::IncomeStatement, "GrossProfit"))
+    );
+    assert_eq!(field("not a metric"), None);
+    assert_eq!(field(""), None);
+}
+
+#[test]
+fn parses_tickers_and_metrics() {
+    let req = parse_chart_request("aapl", "revenue, free cash flow").unwrap();
+    assert_eq!(req.tickers, ["AAPL"]);
+    let fields: Vec<_> = req.metrics.iter().map(|(_, item)| item.field).collect();
+    assert_eq!(fields, ["TotalRevenue", "FreeCashFlow"]);
+
+    let req = parse_chart_request("AAPL, msft", "revenue").unwrap();
+    assert_eq!(req.tickers, ["AAPL", "MSFT"]);
+
+    // Duplicates collapse
+    let req = parse_chart_request("AAPL,AAPL", "revenue, sales").unwrap();
+    assert_eq!((req.tickers.len(), req.metrics.len()), (1, 1));
+
+    assert!(parse_chart_request("AAPL,MSFT", "revenue, fcf").is_err());
+    assert!(parse_chart_request("AAPL,MSFT,GOOG", "revenue").is_err());
+    assert!(parse_chart_request("AAPL", "revenue, fcf, capex, ocf").is_err());
+    assert!(parse_chart_request("AAPL", "revenue, eps").is_err());
+    assert!(parse_chart_request("AAPL", "bogus").is_err());
+    assert!(parse_chart_request("", "revenue").is_err());
+}
+
+#[test]
+fn growth_and_cagr() {
+    let annual = [
+        (day(2021, 9, 25), 100.0),
+        (day(2022, 9, 24), 110.0),
+        (day(2023, 9, 30), 99.0),
+        (day(2024, 9, 28), 0.0),
+        (day(2025, 9, 27), 50.0),
+    ];
+    let growth = growth_rates(&annual, 12);
+    assert_eq!(growth[0], None);
+    // 52/53-week fiscal years still line up
+    assert!((growth[1].unwrap() - 10.0).abs() < 1e-9);
+    assert!((growth[2].unwrap() + 10.0).abs() < 1e-9);
+    assert_eq!(growth[4], None);
+
+    assert!((cagr(100.0, 121.0, 2.0).unwrap() - 10.0).abs() < 1e-9);
+    assert_eq!(cagr(-5.0, 10.0, 3.0), None);
+}
+
+#[test]
+fn quarterly_growth_matches_the_same_quarter_by_date() {
+    let quarters = [
+        (day(2024, 3, 31), 10.0),
+        (day(2024, 6, 30), 11.0),
+        (day(2024, 9, 30), 12.0),
+        (day(2024, 12, 31), 13.0),
+        (day(2025, 3, 31), 15.0),
+    ];
+    let yoy = growth_rates(&quarters, 12);
+    assert_eq!(&yoy[..4], [None, None, None, None]);
+    assert!((yoy[4].unwrap() - 50.0).abs() < 1e-9);
+
+    // Q2 2024 is missing, so positions no longer line up with quarters
+    let gappy = [
+        (day(2024, 3, 31), 10.0),
+        (day(2024, 9, 30), 12.0),
+        (day(2024, 12, 31), 13.0),
+        (day(2025, 3, 31), 15.0),
+        (day(2025, 6, 30), 16.0),
+        (day(2025, 9, 30), 18.0),
+    ];
+    let yoy = growth_rates(&gappy, 12);
+    // Q2 2025 has no year-ago quarter; Q3 2025 compares with Q3 2024
+    assert_eq!(yoy[4], None);
+    assert!((yoy[5].unwrap() - 50.0).abs() < 1e-9);
+}
+
+#[test]
+fn buckets_line_up_calendar_periods() {
+    // Apple's fiscal Q3 and Microsoft's fiscal Q4 both end in calendar Q2
+    assert_eq!(
+        period_bucket(day(2025, 6, 28), Frequency::Quarterly),
+        (2025, 2)
+    );
+    assert_eq!(
+        period_bucket(day(2025, 6, 30), Frequency::Quarterly),
+        (2025, 2)
+    );
+    assert_eq!(
+        period_bucket(day(2025, 9, 27), Frequency::Annual),
+        (2025, 0)
+    );
+}
+
+#[test]
+fn summarizes_latest_value_growth_and_cagr() {
+    let series = ChartSeries {
+        label: "Total Revenue".to_string(),
+        kind: LineKind::Amount,
+        points: vec![
+            (day(2021, 9, 30), 100e9),
+            (day(2022, 9, 30), 110e9),
+            (day(2023, 9, 30), 121e9),
+        ],
+    };
+    assert_eq!(
+        format_series_line(&series),
+        "**Total Revenue**: 121.00B (2023-09-30) · +10.0% YoY · CAGR +10.0% over 2y"
+    );
+}
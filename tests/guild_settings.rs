@@ -0,0 +1,75 @@
+use redis::AsyncCommands;
+
+use stacks_bot::service::caching::collections::guild_settings::{
+    load_calendar_theme, save_calendar_theme, CalendarTheme,
+};
+use stacks_bot::service::caching::RedisCache;
+
+#[test]
+fn parses_theme_names() {
+    assert_eq!(CalendarTheme::parse("light"), Some(CalendarTheme::Light));
+    assert_eq!(CalendarTheme::parse(" Dark "), Some(CalendarTheme::Dark));
+    assert_eq!(CalendarTheme::parse("DARK"), Some(CalendarTheme::Dark));
+    assert_eq!(CalendarTheme::parse("midnight"), None);
+    assert_eq!(CalendarTheme::parse(""), None);
+
+    for theme in [CalendarTheme::Light, CalendarTheme::Dark] {
+        assert_eq!(CalendarTheme::parse(theme.as_str()), Some(theme));
+    }
+}
+
+#[tokio::test]
+async fn defaults_to_light_without_redis_or_guild() {
+    assert_eq!(CalendarTheme::default(), CalendarTheme::Light);
+    assert_eq!(
+        load_calendar_theme(None, Some(1)).await,
+        CalendarTheme::Light
+    );
+    assert_eq!(load_calendar_theme(None, None).await, CalendarTheme::Light);
+}
+
+#[tokio::test]
+#[ignore = "requires REDIS_URL"]
+async fn saved_theme_applies_to_its_guild_only() {
+    let cache = RedisCache::from_env().await.expect("REDIS_URL");
+    let (guild, other) = (9_000_000_000_000_001, 9_000_000_000_000_002);
+    let mut conn = cache.connection();
+    let _: () = conn
+        .del(&[
+            format!("guild:{guild}:calendar_theme"),
+            format!("guild:{other}:calendar_theme"),
+        ])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        load_calendar_theme(Some(&cache), Some(guild)).await,
+        CalendarTheme::Light
+    );
+    save_calendar_theme(&cache, guild, CalendarTheme::Dark)
+        .await
+        .unwrap();
+    assert_eq!(
+        load_calendar_theme(Some(&cache), Some(guild)).await,
+        CalendarTheme::Dark
+    );
+    assert_eq!(
+        load_calendar_theme(Some(&cache), Some(other)).await,
+        CalendarTheme::Light
+    );
+    // Outside a guild (DMs) there is no setting to read
+    assert_eq!(
+        load_calendar_theme(Some(&cache), None).await,
+        CalendarTheme::Light
+    );
+
+    // An unknown stored value falls back to the default
+    let _: () = conn
+        .set(format!("guild:{guild}:calendar_theme"), "sepia")
+        .await
+        .unwrap();
+    assert_eq!(
+        load_calendar_theme(Some(&cache), Some(guild)).await,
+        CalendarTheme::Light
+    );
+}
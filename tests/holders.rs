@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+
+use chrono::Utc;
+use serde_json::json;
+
+use stacks_bot::models::{HolderType, InsiderTransaction, MajorHoldersBreakdown};
+use stacks_bot::service::command::holders::{
+    major_slices, paginate, parse_holder_type, HoldersPage,
+};
+use stacks_bot::service::finance::holders::TradeSide;
+
+fn transaction(text: &str) -> InsiderTransaction {
+    InsiderTransaction {
+        start_date: Utc::now(),
+        insider: "DOE JANE".to_string(),
+        position: "Director".to_string(),
+        transaction: text.to_string(),
+        shares: Some(1000),
+        value: Some(50_000),
+        ownership: None,
+    }
+}
+
+fn breakdown(values: &[(&str, f64)]) -> MajorHoldersBreakdown {
+    MajorHoldersBreakdown {
+        breakdown_data: values
+            .iter()
+            .map(|(k, v)| (k.to_string(), json!({ "raw": v, "fmt": "" })))
+            .collect::<HashMap<_, _>>(),
+    }
+}
+
+fn close(actual: f64, expected: f64) {
+    assert!((actual - expected).abs() < 1e-9, "{actual} != {expected}");
+}
+
+#[test]
+fn page_ids_round_trip() {
+    let page = HoldersPage {
+        symbol: "BRK-B".to_string(),
+        holder_type: HolderType::InsiderTransactions,
+        page: 3,
+        page_size: 10,
+    };
+    let id = page.to_custom_id();
+    assert_eq!(id, "holders:insider_transactions:3:10:BRK-B");
+    assert_eq!(HoldersPage::parse_custom_id(&id), Some(page));
+}
+
+#[test]
+fn rejects_foreign_or_broken_page_ids() {
+    assert_eq!(
+        HoldersPage::parse_custom_id("weekly:1:20260105:20260109:-:-:0:"),
+        None
+    );
+    assert_eq!(
+        HoldersPage::parse_custom_id("holders:bogus:0:10:AAPL"),
+        None
+    );
+    assert_eq!(
+        HoldersPage::parse_custom_id("holders:major:x:10:AAPL"),
+        None
+    );
+    assert_eq!(HoldersPage::parse_custom_id("holders:major:0:10:"), None);
+}
+
+#[test]
+fn paginates_and_clamps_to_the_last_page() {
+    assert_eq!(paginate(25, 0, 10), (0..10, 0, 3));
+    assert_eq!(paginate(25, 2, 10), (20..25, 2, 3));
+    assert_eq!(paginate(25, 9, 10), (20..25, 2, 3));
+    assert_eq!(paginate(10, 0, 10), (0..10, 0, 1));
+    assert_eq!(paginate(0, 0, 10), (0..0, 0, 1));
+}
+
+#[test]
+fn splits_shares_into_insiders_institutions_and_the_rest() {
+    let slices = major_slices(&breakdown(&[
+        ("insidersPercentHeld", 0.02),
+        ("institutionsPercentHeld", 0.64),
+        ("institutionsCount", 6512.0),
+    ]));
+
+    assert_eq!(
+        slices.iter().map(|(label, _)| *label).collect::<Vec<_>>(),
+        vec!["Insiders", "Institutions", "Public & other"]
+    );
+    close(slices[0].1, 0.02);
+    close(slices[1].1, 0.64);
+    close(slices[2].1, 0.34);
+}
+
+#[test]
+fn caps_institutions_when_reported_holdings_exceed_the_float() {
+    let slices = major_slices(&breakdown(&[
+        ("insidersPercentHeld", 0.10),
+        ("institutionsPercentHeld", 1.05),
+    ]));
+
+    close(slices[1].1, 0.90);
+    close(slices[2].1, 0.0);
+}
+
+#[test]
+fn classifies_insider_transactions() {
+    assert_eq!(
+        TradeSide::of(&transaction("Purchase at price 20.10 per share.")),
+        TradeSide::Buy
+    );
+    assert_eq!(
+        TradeSide::of(&transaction("Sale at price 225.00 - 230.00 per share.")),
+        TradeSide::Sell
+    );
+    assert_eq!(TradeSide::of(&transaction("Stock Gift")), TradeSide::Other);
+    assert_eq!(TradeSide::of(&transaction("")), TradeSide::Other);
+}
+
+#[test]
+fn parses_holder_types() {
+    assert_eq!(
+        parse_holder_type("Institutional"),
+        Some(HolderType::Institutional)
+    );
+    assert_eq!(
+        parse_holder_type("insider_roster"),
+        Some(HolderType::InsiderRoster)
+    );
+    assert_eq!(parse_holder_type("whales"), None);
+}
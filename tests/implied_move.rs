@@ -0,0 +1,232 @@
+use chrono::{NaiveDate, NaiveDateTime, Utc};
+use finance_query_core::OptionContract;
+
+use stacks_bot::service::finance::implied_move::{
+    atm_straddle, event_expirations, split_against_baseline, split_event_variance, QuoteFlag,
+};
+use stacks_bot::service::finance::options::OptionSlice;
+
+fn contract(strike: f64, bid: f64, ask: f64, last: f64, iv: f64) -> OptionContract {
+    OptionContract {
+        contract_symbol: format!("TEST{strike}"),
+        last_trade_date: Utc::now(),
+        strike,
+        last_price: last,
+        bid,
+        ask,
+        change: 0.0,
+        percent_change: 0.0,
+        volume: None,
+        open_interest: None,
+        implied_volatility: iv,
+        in_the_money: false,
+        contract_size: "REGULAR".to_string(),
+        currency: "USD".to_string(),
+    }
+}
+
+/// `(strike, call (bid, ask), put (bid, ask), iv)`.
+type Leg = (f64, (f64, f64), (f64, f64), f64);
+
+/// Calls and puts share strikes; each straddle mid is `call_mid + put_mid`.
+fn slice(spot: f64, legs: &[Leg]) -> OptionSlice {
+    OptionSlice {
+        symbol: "TEST".to_string(),
+        expiration: "2026-10-16".to_string(),
+        spot,
+        calls: legs
+            .iter()
+            .map(|(k, (b, a), _, iv)| contract(*k, *b, *a, 0.0, *iv))
+            .collect(),
+        puts: legs
+            .iter()
+            .map(|(k, _, (b, a), iv)| contract(*k, *b, *a, 0.0, *iv))
+            .collect(),
+    }
+}
+
+fn d(s: &str) -> NaiveDate {
+    s.parse().unwrap()
+}
+
+fn at(s: &str) -> NaiveDateTime {
+    s.parse().unwrap()
+}
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+#[test]
+fn straddle_interpolates_between_bracketing_strikes() {
+    let s = slice(
+        101.0,
+        &[
+            (95.0, (6.9, 7.1), (1.9, 2.1), 0.50),
+            (100.0, (3.9, 4.1), (2.9, 3.1), 0.40),
+            (105.0, (1.9, 2.1), (5.9, 6.1), 0.30),
+        ],
+    );
+    let straddle = atm_straddle(&s).unwrap();
+
+    assert_eq!(
+        (straddle.lower_strike, straddle.upper_strike),
+        (100.0, 105.0)
+    );
+    // 7.0 at 100 and 8.0 at 105, 20% of the way to 105
+    assert!(close(straddle.price, 7.2));
+    assert!(close(straddle.iv, 0.38));
+    assert!(close(straddle.spread_ratio.unwrap(), 0.4 / 7.2));
+    assert!(straddle.flags.is_empty());
+}
+
+#[test]
+fn straddle_uses_exact_strike_at_spot() {
+    let s = slice(
+        100.0,
+        &[
+            (95.0, (6.9, 7.1), (1.9, 2.1), 0.50),
+            (100.0, (3.9, 4.1), (2.9, 3.1), 0.40),
+            (105.0, (1.9, 2.1), (5.9, 6.1), 0.30),
+        ],
+    );
+    let straddle = atm_straddle(&s).unwrap();
+
+    assert_eq!(
+        (straddle.lower_strike, straddle.upper_strike),
+        (100.0, 100.0)
+    );
+    assert!(close(straddle.price, 7.0));
+    assert!(straddle.flags.is_empty());
+}
+
+#[test]
+fn straddle_falls_back_to_nearest_strike_when_spot_is_outside_the_chain() {
+    let s = slice(
+        112.0,
+        &[
+            (100.0, (3.9, 4.1), (2.9, 3.1), 0.40),
+            (105.0, (1.9, 2.1), (5.9, 6.1), 0.30),
+        ],
+    );
+    let straddle = atm_straddle(&s).unwrap();
+
+    assert_eq!(
+        (straddle.lower_strike, straddle.upper_strike),
+        (105.0, 105.0)
+    );
+    assert!(close(straddle.price, 8.0));
+    assert_eq!(straddle.flags, vec![QuoteFlag::SingleStrike]);
+}
+
+#[test]
+fn straddle_falls_back_to_last_price_for_one_sided_wings() {
+    let mut s = slice(
+        100.0,
+        &[
+            (95.0, (6.9, 7.1), (1.9, 2.1), 0.50),
+            (100.0, (3.9, 4.1), (2.9, 3.1), 0.40),
+        ],
+    );
+    // No bid on the ATM put: its last price stands in for the mid
+    s.puts[1].bid = 0.0;
+    s.puts[1].last_price = 3.5;
+    let straddle = atm_straddle(&s).unwrap();
+
+    assert!(close(straddle.price, 7.5));
+    assert_eq!(straddle.spread_ratio, None);
+    assert_eq!(straddle.flags, vec![QuoteFlag::LastPriceFallback]);
+}
+
+#[test]
+fn straddle_flags_wide_spreads() {
+    let s = slice(100.0, &[(100.0, (3.0, 5.0), (2.0, 4.0), 0.40)]);
+    let straddle = atm_straddle(&s).unwrap();
+
+    assert!(close(straddle.spread_ratio.unwrap(), 4.0 / 7.0));
+    assert!(matches!(
+        straddle.flags.as_slice(),
+        [QuoteFlag::WideSpread { ratio }] if close(*ratio, 4.0 / 7.0)
+    ));
+}
+
+#[test]
+fn straddle_needs_a_priced_strike_and_positive_spot() {
+    assert!(atm_straddle(&slice(100.0, &[])).is_none());
+    assert!(atm_straddle(&slice(0.0, &[(100.0, (3.9, 4.1), (2.9, 3.1), 0.40)])).is_none());
+    assert!(atm_straddle(&slice(100.0, &[(100.0, (0.0, 0.0), (0.0, 0.0), 0.40)])).is_none());
+}
+
+#[test]
+fn event_variance_is_split_from_bracketing_expiries() {
+    let (base_iv, event_move) = split_event_variance(0.60, 7, 0.40, 35).unwrap();
+
+    // Base variance: (0.40² · 35 − 0.60² · 7) / 28 = 0.11
+    assert!(close(base_iv, 0.11_f64.sqrt()));
+    // Event variance: (0.60² − 0.11) · 7/365, scaled by √(2/π)
+    assert!((event_move - 0.055_247_5).abs() < 1e-6);
+}
+
+#[test]
+fn event_variance_rejects_negative_variance() {
+    // Front IV so high the later expiry implies negative base variance
+    assert_eq!(split_event_variance(0.80, 7, 0.30, 35), None);
+    // Later expiry richer per day than the event expiry: no event variance left
+    assert_eq!(split_event_variance(0.30, 7, 0.50, 35), None);
+    // Baseline must expire after the event expiry
+    assert_eq!(split_event_variance(0.60, 7, 0.40, 7), None);
+}
+
+#[test]
+fn baseline_split_flags_missing_and_inverted_baselines() {
+    let mut flags = Vec::new();
+    let (base_iv, move_pct) = split_against_baseline(0.60, 7, Some((35, 0.40)), &mut flags);
+    assert!(close(base_iv.unwrap(), 0.11_f64.sqrt()));
+    assert!((move_pct.unwrap() - 5.524_75).abs() < 1e-4);
+    assert!(flags.is_empty());
+
+    let mut flags = Vec::new();
+    assert_eq!(
+        split_against_baseline(0.60, 7, None, &mut flags),
+        (None, None)
+    );
+    assert_eq!(flags, vec![QuoteFlag::NoBaselineExpiry]);
+
+    let mut flags = Vec::new();
+    assert_eq!(
+        split_against_baseline(0.30, 7, Some((35, 0.50)), &mut flags),
+        (None, None)
+    );
+    assert_eq!(flags, vec![QuoteFlag::InvertedTermStructure]);
+}
+
+#[test]
+fn event_expiry_is_first_settling_after_the_report() {
+    let expirations = [
+        d("2026-10-09"),
+        d("2026-10-15"),
+        d("2026-10-16"),
+        d("2026-10-23"),
+    ];
+
+    // After the close on the 15th: that day's expiry settled before the report
+    assert_eq!(
+        event_expirations(&expirations, at("2026-10-15T16:05:00")),
+        Some((d("2026-10-16"), Some(d("2026-10-23"))))
+    );
+    // Before the open on the 16th: same-day expiry still captures the move
+    assert_eq!(
+        event_expirations(&expirations, at("2026-10-16T08:00:00")),
+        Some((d("2026-10-16"), Some(d("2026-10-23"))))
+    );
+    // Only one expiry after the event: no baseline
+    assert_eq!(
+        event_expirations(&expirations, at("2026-10-20T16:05:00")),
+        Some((d("2026-10-23"), None))
+    );
+    // Nothing after the event
+    assert_eq!(
+        event_expirations(&expirations, at("2026-10-23T16:05:00")),
+        None
+    );
+}
@@ -0,0 +1,217 @@
+use chrono::{NaiveDate, TimeZone, Utc};
+
+use redis::AsyncCommands;
+use stacks_bot::models::InsiderTransaction;
+
+use stacks_bot::service::automation::insiders::{
+    cluster_alert, cluster_buy, format_cluster, format_transaction, is_reportable,
+    InsiderFeedConfig,
+};
+use stacks_bot::service::caching::collections::insider_feed::{
+    self, has_history, mark_seen, transaction_key,
+};
+use stacks_bot::service::caching::RedisCache;
+
+fn tx(insider: &str, day: u32, text: &str, value: Option<i64>) -> InsiderTransaction {
+    InsiderTransaction {
+        start_date: Utc.with_ymd_and_hms(2026, 10, day, 0, 0, 0).unwrap(),
+        insider: insider.to_string(),
+        position: "Director".to_string(),
+        transaction: text.to_string(),
+        shares: Some(10_000),
+        value,
+        ownership: None,
+    }
+}
+
+const BUY: &str = "Purchase at price 125.00 per share.";
+const SALE: &str = "Sale at price 230.00 per share.";
+
+fn date(day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2026, 10, day).unwrap()
+}
+
+#[test]
+fn transaction_keys_are_stable_and_normalized() {
+    let a = tx("Doe Jane ", 14, BUY, Some(1_250_000));
+    assert_eq!(transaction_key(&a), "DOE JANE|2026-10-14|10000|1250000");
+
+    let mut b = tx("DOE JANE", 14, "", Some(1_250_000));
+    assert_eq!(transaction_key(&a), transaction_key(&b));
+
+    b.value = None;
+    b.shares = None;
+    assert_eq!(transaction_key(&b), "DOE JANE|2026-10-14|-|-");
+}
+
+#[test]
+fn only_open_market_trades_above_the_threshold_are_reportable() {
+    assert!(is_reportable(&tx("A", 1, BUY, Some(100_000)), 100_000));
+    assert!(is_reportable(&tx("A", 1, SALE, Some(2_000_000)), 100_000));
+    assert!(!is_reportable(&tx("A", 1, BUY, Some(99_999)), 100_000));
+    assert!(!is_reportable(&tx("A", 1, BUY, None), 100_000));
+    assert!(!is_reportable(
+        &tx(
+            "A",
+            1,
+            "Stock Award(Grant) at price 0.00 per share.",
+            Some(5_000_000)
+        ),
+        100_000
+    ));
+    assert!(!is_reportable(
+        &tx("A", 1, "Stock Gift", Some(5_000_000)),
+        100_000
+    ));
+}
+
+#[test]
+fn detects_cluster_buys_by_distinct_insiders_in_the_window() {
+    let config = InsiderFeedConfig::default();
+    let txs = vec![
+        tx("SMITH JOHN", 12, BUY, Some(400_000)),
+        tx("DOE JANE", 3, BUY, Some(250_000)),
+        tx("DOE JANE", 5, BUY, Some(50_000)),
+        tx("ROE RICHARD", 1, SALE, Some(9_000_000)),
+        tx("LEE ANN", 9, BUY, None),
+    ];
+
+    let cluster = cluster_buy(&txs, date(12), &config).expect("cluster");
+    assert_eq!(cluster.insiders, vec!["DOE JANE", "LEE ANN", "SMITH JOHN"]);
+    assert_eq!((cluster.first, cluster.last), (date(3), date(12)));
+    assert_eq!(cluster.total_value, 700_000);
+}
+
+#[test]
+fn no_cluster_with_too_few_insiders_or_outside_the_window() {
+    let config = InsiderFeedConfig {
+        cluster_window_days: 5,
+        ..Default::default()
+    };
+    let txs = vec![
+        tx("SMITH JOHN", 12, BUY, Some(400_000)),
+        tx("LEE ANN", 9, BUY, Some(100_000)),
+        tx("DOE JANE", 3, BUY, Some(250_000)),
+        tx("ROE RICHARD", 10, SALE, Some(9_000_000)),
+    ];
+
+    // DOE JANE bought 9 days before the anchor, outside a 5-day window
+    assert_eq!(cluster_buy(&txs, date(12), &config), None);
+
+    let wider = InsiderFeedConfig {
+        cluster_window_days: 10,
+        ..config
+    };
+    assert!(cluster_buy(&txs, date(12), &wider).is_some());
+}
+
+#[test]
+fn formats_feed_lines() {
+    let line = format_transaction("AAPL", &tx("DOE JANE", 14, BUY, Some(1_250_000)));
+    assert_eq!(
+        line,
+        "🟢 **AAPL** insider buy — DOE JANE (Director) bought 10.00K sh for $1.25M on Oct 14 · Purchase at price 125.00 per share"
+    );
+
+    let sale = format_transaction("AAPL", &tx("COOK TIMOTHY D", 2, SALE, Some(27_000_000)));
+    assert!(sale.starts_with("🔴 **AAPL** insider sale — COOK TIMOTHY D (Director) sold"));
+
+    let config = InsiderFeedConfig::default();
+    let cluster = cluster_buy(
+        &[
+            tx("A", 1, BUY, Some(1_000_000)),
+            tx("B", 4, BUY, Some(600_000)),
+            tx("C", 10, BUY, Some(500_000)),
+        ],
+        date(10),
+        &config,
+    )
+    .expect("cluster");
+    assert_eq!(
+        format_cluster("AAPL", &cluster, &config),
+        "🚨 **AAPL** cluster buy — 3 insiders bought within 14 days (Oct 1 – Oct 10), $2.10M total: A, B, C"
+    );
+}
+
+#[test]
+fn cluster_alert_needs_a_new_insider_in_the_cluster() {
+    let config = InsiderFeedConfig::default();
+    let mut txs = vec![
+        tx("DOE JANE", 3, BUY, Some(250_000)),
+        tx("LEE ANN", 5, BUY, Some(100_000)),
+    ];
+
+    // A third insider completes the cluster
+    txs.push(tx("SMITH JOHN", 9, BUY, Some(400_000)));
+    let fresh = [&txs[2]];
+    let cluster = cluster_alert(&txs, &fresh, &config).expect("cluster");
+    assert_eq!(cluster.insiders, vec!["DOE JANE", "LEE ANN", "SMITH JOHN"]);
+
+    // A repeat buy by an insider already in the cluster is not announced again
+    txs.push(tx("Doe Jane", 10, BUY, Some(300_000)));
+    let fresh = [&txs[3]];
+    assert_eq!(cluster_alert(&txs, &fresh, &config), None);
+
+    // A fourth insider grows the cluster
+    txs.push(tx("ROE RICHARD", 11, BUY, Some(50_000)));
+    let fresh = [&txs[4]];
+    assert_eq!(
+        cluster_alert(&txs, &fresh, &config).map(|c| c.insiders.len()),
+        Some(4)
+    );
+
+    // Sales never anchor a cluster
+    txs.push(tx("POE EDGAR", 12, SALE, Some(900_000)));
+    let fresh = [&txs[5]];
+    assert_eq!(cluster_alert(&txs, &fresh, &config), None);
+}
+
+#[test]
+fn cluster_alert_for_purchases_arriving_together() {
+    let config = InsiderFeedConfig::default();
+    let txs = vec![
+        tx("A", 3, BUY, Some(100_000)),
+        tx("B", 4, BUY, Some(100_000)),
+        tx("C", 5, BUY, Some(100_000)),
+    ];
+    let fresh: Vec<_> = txs.iter().collect();
+    assert!(cluster_alert(&txs, &fresh, &config).is_some());
+    assert_eq!(cluster_alert(&txs, &fresh[..0], &config), None);
+}
+
+#[tokio::test]
+#[ignore = "requires REDIS_URL"]
+async fn transactions_stay_unseen_until_marked() {
+    let cache = RedisCache::from_env().await.expect("REDIS_URL");
+    let mut conn = cache.connection();
+    let _: () = conn.del("insider:seen:ZZINSIDER").await.unwrap();
+
+    let keys: Vec<String> = ["A|2026-10-01|1|1", "B|2026-10-02|2|2"]
+        .map(String::from)
+        .to_vec();
+    assert!(!has_history(&cache, "zzinsider").await.unwrap());
+    assert_eq!(
+        insider_feed::unseen(&cache, "ZZINSIDER", &keys)
+            .await
+            .unwrap(),
+        keys
+    );
+    // Checking does not record anything
+    assert!(!has_history(&cache, "ZZINSIDER").await.unwrap());
+
+    assert_eq!(
+        mark_seen(&cache, "ZZINSIDER", &keys[..1]).await.unwrap(),
+        keys[..1]
+    );
+    assert!(has_history(&cache, "ZZINSIDER").await.unwrap());
+    assert_eq!(
+        insider_feed::unseen(&cache, "ZZINSIDER", &keys)
+            .await
+            .unwrap(),
+        keys[1..]
+    );
+    assert_eq!(
+        mark_seen(&cache, "ZZINSIDER", &keys).await.unwrap(),
+        keys[1..]
+    );
+}
@@ -0,0 +1,82 @@
+use redis::AsyncCommands;
+
+use stacks_bot::service::caching::collections::logo_cache::{
+    content_hash, load_blobs, load_entries, store_logo, store_missing,
+};
+use stacks_bot::service::caching::RedisCache;
+
+const DAY_SECS: i64 = 24 * 60 * 60;
+
+#[test]
+fn content_hash_is_hex_sha256_of_bytes() {
+    let hash = content_hash(b"logo");
+    assert_eq!(hash.len(), 64);
+    assert!(hash
+        .chars()
+        .all(|c| c.is_ascii_hexdigit() && !c.is_ascii_uppercase()));
+    assert_eq!(hash, content_hash(b"logo"));
+    assert_ne!(hash, content_hash(b"other logo"));
+    assert_eq!(
+        content_hash(b""),
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    );
+}
+
+#[tokio::test]
+#[ignore = "requires REDIS_URL"]
+async fn stored_logo_is_keyed_by_upper_case_symbol_and_content_hash() {
+    let cache = RedisCache::from_env().await.expect("REDIS_URL");
+    let mut conn = cache.connection();
+    let png = b"\x89PNG test".to_vec();
+    let hash = content_hash(&png);
+    let _: () = conn
+        .del(&[
+            "logos:entry:ZZLOGO".to_string(),
+            format!("logos:blob:{hash}"),
+        ])
+        .await
+        .unwrap();
+
+    store_logo(&cache, "zzlogo", "https://example.com/zz.png", &hash, &png)
+        .await
+        .unwrap();
+
+    let entries = load_entries(&cache, &["ZZLOGO".to_string(), "ZZNONE".to_string()])
+        .await
+        .unwrap();
+    assert_eq!(entries.len(), 1);
+    let entry = &entries["ZZLOGO"];
+    assert_eq!(entry.url, "https://example.com/zz.png");
+    assert_eq!(entry.hash.as_deref(), Some(hash.as_str()));
+
+    let blobs = load_blobs(&cache, std::slice::from_ref(&hash)).await.unwrap();
+    assert_eq!(blobs.get(&hash), Some(&png));
+
+    // Logos are refreshed after 30 days
+    for key in [
+        "logos:entry:ZZLOGO".to_string(),
+        format!("logos:blob:{hash}"),
+    ] {
+        let ttl: i64 = conn.ttl(&key).await.unwrap();
+        assert!(ttl > 29 * DAY_SECS && ttl <= 30 * DAY_SECS, "{key}: {ttl}");
+    }
+}
+
+#[tokio::test]
+#[ignore = "requires REDIS_URL"]
+async fn missing_logo_is_retried_after_a_week() {
+    let cache = RedisCache::from_env().await.expect("REDIS_URL");
+    let mut conn = cache.connection();
+    let _: () = conn.del("logos:entry:ZZMISS").await.unwrap();
+
+    store_missing(&cache, "ZZMISS", "https://example.com/missing.png")
+        .await
+        .unwrap();
+
+    let entries = load_entries(&cache, &["ZZMISS".to_string()]).await.unwrap();
+    assert_eq!(entries["ZZMISS"].url, "https://example.com/missing.png");
+    assert_eq!(entries["ZZMISS"].hash, None);
+
+    let ttl: i64 = conn.ttl("logos:entry:ZZMISS").await.unwrap();
+    assert!(ttl > 6 * DAY_SECS && ttl <= 7 * DAY_SECS, "{ttl}");
+}
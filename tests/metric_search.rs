@@ -0,0 +1,101 @@
+use stacks_bot::models::StatementType::{self, BalanceSheet, CashFlow, IncomeStatement};
+use stacks_bot::service::finance::metric_search::{
+    resolve_metric, search_metrics, slash_value, MetricLookup,
+};
+
+fn lookup(statement_type: StatementType, query: &str) -> (&'static str, Option<&'static str>) {
+    match resolve_metric(statement_type, query) {
+        MetricLookup::Exact(m) => ("exact", Some(m.field)),
+        MetricLookup::Guessed(m) => ("guessed", Some(m.field)),
+        MetricLookup::Ambiguous(_) => ("ambiguous", None),
+        MetricLookup::NotFound => ("not found", None),
+    }
+}
+
+#[test]
+fn exact_names_labels_and_aliases() {
+    assert_eq!(
+        lookup(IncomeStatement, "net_income"),
+        ("exact", Some("NetIncome"))
+    );
+    assert_eq!(
+        lookup(IncomeStatement, "Operating Income"),
+        ("exact", Some("OperatingIncome"))
+    );
+    assert_eq!(lookup(IncomeStatement, "EBITDA"), ("exact", Some("EBITDA")));
+    // Old slash values split acronyms letter by letter
+    assert_eq!(
+        lookup(IncomeStatement, "e_b_i_t_d_a"),
+        ("exact", Some("EBITDA"))
+    );
+    assert_eq!(lookup(CashFlow, "FCF"), ("exact", Some("FreeCashFlow")));
+    assert_eq!(
+        lookup(CashFlow, "capex"),
+        ("exact", Some("CapitalExpenditure"))
+    );
+    assert_eq!(
+        lookup(CashFlow, "sbc"),
+        ("exact", Some("StockBasedCompensation"))
+    );
+    // An alias beats another field's label
+    assert_eq!(
+        lookup(BalanceSheet, "cash"),
+        ("exact", Some("CashAndCashEquivalents"))
+    );
+}
+
+#[test]
+fn clear_fuzzy_matches_are_guessed() {
+    assert_eq!(
+        lookup(IncomeStatement, "revnue"),
+        ("guessed", Some("TotalRevenue"))
+    );
+    assert_eq!(
+        lookup(IncomeStatement, "net inc"),
+        ("guessed", Some("NetIncome"))
+    );
+    assert_eq!(
+        lookup(IncomeStatement, "opinc"),
+        ("guessed", Some("OperatingIncome"))
+    );
+    assert_eq!(
+        lookup(CashFlow, "stock comp"),
+        ("guessed", Some("StockBasedCompensation"))
+    );
+    assert_eq!(
+        lookup(BalanceSheet, "inventry"),
+        ("guessed", Some("Inventory"))
+    );
+}
+
+#[test]
+fn ambiguous_queries_list_suggestions() {
+    assert_eq!(lookup(IncomeStatement, "income"), ("ambiguous", None));
+    let MetricLookup::Ambiguous(suggestions) = resolve_metric(IncomeStatement, "income") else {
+        unreachable!();
+    };
+    let fields: Vec<_> = suggestions.iter().map(|m| m.field).collect();
+    assert!(fields.contains(&"NetIncome"));
+    assert!(fields.contains(&"OperatingIncome"));
+
+    assert_eq!(lookup(IncomeStatement, "interest"), ("ambiguous", None));
+    assert_eq!(lookup(IncomeStatement, "xyzzy"), ("not found", None));
+    assert_eq!(lookup(IncomeStatement, " "), ("not found", None));
+}
+
+#[test]
+fn search_ranks_and_lists_layout_for_empty_query() {
+    let top: Vec<_> = search_metrics(IncomeStatement, "ebit", 3)
+        .into_iter()
+        .map(|m| m.field)
+        .collect();
+    assert_eq!(top, ["EBIT", "EBITDA", "NormalizedEBITDA"]);
+
+    let first = search_metrics(IncomeStatement, "", 25);
+    assert!((10..=25).contains(&first.len()));
+    assert_eq!(first[0].field, "TotalRevenue");
+
+    assert_eq!(slash_value("NetIncome"), "net_income");
+    assert_eq!(slash_value("EBITDA"), "ebitda");
+    assert_eq!(slash_value("DilutedEPS"), "diluted_eps");
+}
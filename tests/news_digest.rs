@@ -0,0 +1,151 @@
+use chrono::{DateTime, NaiveDate, Utc};
+
+use stacks_bot::models::{EarningsSession, NewsItem};
+use stacks_bot::service::finance::news::{
+    dedupe_and_rank, first_sentences, is_earnings_related, publisher_rank, report_cutoff,
+    ExtractiveSummariser, NewsSummariser,
+};
+
+fn item(title: &str, link: &str, source: Option<&str>, published: &str) -> NewsItem {
+    NewsItem {
+        title: title.to_string(),
+        link: link.to_string(),
+        source: source.map(str::to_string),
+        published_at: Some(published.parse::<DateTime<Utc>>().unwrap()),
+        thumbnail: None,
+        summary: None,
+    }
+}
+
+#[test]
+fn matches_earnings_headlines_by_whole_word() {
+    let yes = [
+        "Apple tops Q4 estimates as iPhone sales climb",
+        "Nvidia earnings: what to watch on the call",
+        "Tesla misses on revenue, cuts outlook",
+        "Microsoft (MSFT) Q1 2026 Earnings Call Transcript",
+    ];
+    let no = [
+        "Apple reaches all-time high this week",
+        "Stocktops and desktops: a buyer's guide",
+        "Analyst upgrades Intel to buy",
+    ];
+    for title in yes {
+        assert!(
+            is_earnings_related(&item(title, "https://a", None, "2026-10-20T21:00:00Z")),
+            "{title}"
+        );
+    }
+    for title in no {
+        assert!(
+            !is_earnings_related(&item(title, "https://a", None, "2026-10-20T21:00:00Z")),
+            "{title}"
+        );
+    }
+}
+
+#[test]
+fn ranks_publishers_then_recency_and_drops_duplicate_links() {
+    let items = vec![
+        item(
+            "a",
+            "https://x.com/a?utm=1",
+            Some("GuruFocus.com"),
+            "2026-10-20T22:00:00Z",
+        ),
+        item(
+            "b",
+            "https://x.com/b",
+            Some("Reuters"),
+            "2026-10-20T21:00:00Z",
+        ),
+        item(
+            "c",
+            "https://x.com/a/",
+            Some("Reuters"),
+            "2026-10-20T23:00:00Z",
+        ),
+        item("d", "https://x.com/d", None, "2026-10-20T23:30:00Z"),
+        item(
+            "e",
+            "https://x.com/e",
+            Some("Reuters"),
+            "2026-10-20T21:30:00Z",
+        ),
+        item(
+            "f",
+            "https://x.com/f",
+            Some("Some Blog"),
+            "2026-10-20T21:30:00Z",
+        ),
+    ];
+    let ranked: Vec<_> = dedupe_and_rank(items)
+        .into_iter()
+        .map(|i| i.title)
+        .collect();
+    // `c` repeats `a`'s link; the first occurrence is kept
+    assert_eq!(ranked, ["e", "b", "a", "f", "d"]);
+
+    assert!(publisher_rank(Some("Reuters Breakingviews")) < publisher_rank(Some("Zacks")));
+    assert!(publisher_rank(Some("Some Blog")) < publisher_rank(None));
+}
+
+#[test]
+fn extracts_leading_sentences() {
+    let text = "Apple earned $1.46 a share.  Revenue rose 6%!\nShares fell after hours? Yes.";
+    assert_eq!(first_sentences(text, 1), "Apple earned $1.46 a share.");
+    assert_eq!(
+        first_sentences(text, 2),
+        "Apple earned $1.46 a share. Revenue rose 6%!"
+    );
+    assert_eq!(
+        first_sentences("No terminal punctuation", 2),
+        "No terminal punctuation"
+    );
+}
+
+#[test]
+fn cutoff_follows_session() {
+    let date = NaiveDate::from_ymd_opt(2026, 10, 20).unwrap();
+    let at = |s: &str| s.parse::<DateTime<Utc>>().unwrap();
+    // EDT is UTC−4 in October
+    assert_eq!(
+        report_cutoff(date, EarningsSession::AfterClose),
+        Some(at("2026-10-20T20:00:00Z"))
+    );
+    assert_eq!(
+        report_cutoff(date, EarningsSession::DuringMarket),
+        Some(at("2026-10-20T13:30:00Z"))
+    );
+    assert_eq!(
+        report_cutoff(date, EarningsSession::BeforeOpen),
+        Some(at("2026-10-20T04:00:00Z"))
+    );
+}
+
+#[tokio::test]
+async fn extractive_summary_prefers_lead_paragraph() {
+    let mut with_summary = item(
+        "Apple beats",
+        "https://a",
+        Some("Reuters"),
+        "2026-10-20T21:00:00Z",
+    );
+    with_summary.summary = Some("Apple beat estimates. Guidance was light. More text.".to_string());
+    let headline_only = item(
+        "Apple guidance disappoints",
+        "https://b",
+        None,
+        "2026-10-20T21:00:00Z",
+    );
+    let ignored = item("Third story", "https://c", None, "2026-10-20T21:00:00Z");
+
+    let summary = ExtractiveSummariser::default()
+        .summarise("AAPL", &[with_summary, headline_only, ignored])
+        .await
+        .unwrap();
+    assert_eq!(
+        summary,
+        "> Apple beat estimates. Guidance was light.\n> Apple guidance disappoints"
+    );
+}
@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use serde_json::json;
+
+use stacks_bot::models::{FinancialStatement, FinancialSummary, TtmFigures};
+use stacks_bot::service::command::ratios::card_sections;
+use stacks_bot::service::finance::ratios::{
+    compute_ratios, enterprise_value, latest_growth, ttm_figures, ttm_sum, BalanceSnapshot,
+};
+
+fn statement(frequency: &str, fields: &[(&str, &[(&str, f64)])]) -> FinancialStatement {
+    FinancialStatement {
+        symbol: "TEST".to_string(),
+        statement_type: "income".to_string(),
+        frequency: frequency.to_string(),
+        statement: fields
+            .iter()
+            .map(|(field, values)| {
+                let series = values
+                    .iter()
+                    .map(|(date, raw)| {
+                        (date.to_string(), json!({ "reportedValue": { "raw": raw } }))
+                    })
+                    .collect();
+                (field.to_string(), series)
+            })
+            .collect::<HashMap<_, _>>(),
+    }
+}
+
+fn close(actual: Option<f64>, expected: f64) {
+    let actual = actual.expect("value");
+    assert!((actual - expected).abs() < 1e-6, "{actual} != {expected}");
+}
+
+#[test]
+fn sums_the_latest_four_consecutive_quarters() {
+    let income = statement(
+        "quarterly",
+        &[
+            (
+                "TotalRevenue",
+                &[
+                    ("2024-09-30", 50.0),
+                    ("2024-12-31", 10.0),
+                    ("2025-03-31", 20.0),
+                    ("2025-06-30", 30.0),
+                    ("2025-09-30", 40.0),
+                ],
+            ),
+            // 2025-03-31 missing: the latest four span more than a year
+            (
+                "NetIncome",
+                &[
+                    ("2024-09-30", 5.0),
+                    ("2024-12-31", 1.0),
+                    ("2025-06-30", 3.0),
+                    ("2025-09-30", 4.0),
+                ],
+            ),
+            ("EBITDA", &[("2025-06-30", 3.0), ("2025-09-30", 4.0)]),
+        ],
+    );
+
+    assert_eq!(
+        ttm_sum(&income, "TotalRevenue"),
+        Some((NaiveDate::from_ymd_opt(2025, 9, 30).unwrap(), 100.0))
+    );
+    assert_eq!(ttm_sum(&income, "NetIncome"), None);
+    assert_eq!(ttm_sum(&income, "EBITDA"), None);
+
+    let ttm = ttm_figures(Some(&income), None);
+    assert_eq!(ttm.revenue, Some(100.0));
+    assert_eq!(ttm.as_of, NaiveDate::from_ymd_opt(2025, 9, 30));
+    assert_eq!(ttm.net_income, None);
+    assert_eq!(ttm.free_cash_flow, None);
+}
+
+#[test]
+fn computes_ratios_from_ttm_and_balance_sheet() {
+    let ttm = TtmFigures {
+        revenue: Some(400.0),
+        gross_profit: Some(180.0),
+        operating_income: Some(120.0),
+        pretax_income: Some(120.0),
+        tax_provision: Some(18.0),
+        net_income: Some(100.0),
+        ebitda: Some(130.0),
+        free_cash_flow: Some(90.0),
+        ..TtmFigures::default()
+    };
+    let balance = BalanceSnapshot::from_statement(&statement(
+        "quarterly",
+        &[
+            ("TotalDebt", &[("2025-06-30", 90.0), ("2025-09-30", 100.0)]),
+            ("CashAndCashEquivalents", &[("2025-09-30", 50.0)]),
+            ("StockholdersEquity", &[("2025-09-30", 80.0)]),
+            ("CurrentAssets", &[("2025-09-30", 150.0)]),
+            ("CurrentLiabilities", &[("2025-09-30", 160.0)]),
+        ],
+    ));
+    assert_eq!(balance.total_debt, Some(100.0));
+    assert_eq!(balance.invested_capital, None);
+
+    let market_cap = Some(3000.0);
+    let ev = enterprise_value(market_cap, &balance);
+    assert_eq!(ev, Some(3050.0));
+
+    let r = compute_ratios(&ttm, &balance, market_cap, ev, Some(30.0), Some(12.0));
+    close(r.gross_margin, 45.0);
+    close(r.operating_margin, 30.0);
+    close(r.net_margin, 25.0);
+    close(r.roe, 125.0);
+    // NOPAT 120 × (1 − 15%) over debt + equity − cash = 130
+    close(r.roic, 102.0 / 130.0 * 100.0);
+    close(r.debt_to_equity, 1.25);
+    close(r.current_ratio, 150.0 / 160.0);
+    close(r.fcf_yield, 3.0);
+    close(r.ev_to_ebitda, 3050.0 / 130.0);
+    close(r.peg, 2.5);
+}
+
+#[test]
+fn undefined_ratios_stay_empty() {
+    let ttm = TtmFigures {
+        revenue: Some(0.0),
+        net_income: Some(-10.0),
+        operating_income: Some(-5.0),
+        ebitda: Some(-1.0),
+        ..TtmFigures::default()
+    };
+    let balance = BalanceSnapshot {
+        stockholders_equity: Some(-20.0),
+        total_debt: Some(50.0),
+        ..BalanceSnapshot::default()
+    };
+    let r = compute_ratios(&ttm, &balance, None, None, Some(-4.0), Some(10.0));
+    assert_eq!(r.net_margin, None);
+    assert_eq!(r.roe, None);
+    assert_eq!(r.debt_to_equity, None);
+    assert_eq!(r.ev_to_ebitda, None);
+    assert_eq!(r.fcf_yield, None);
+    assert_eq!(r.peg, None);
+}
+
+#[test]
+fn eps_growth_uses_the_last_two_years() {
+    let annual = statement(
+        "annual",
+        &[(
+            "DilutedEPS",
+            &[
+                ("2023-09-30", 6.13),
+                ("2024-09-30", 6.08),
+                ("2025-09-30", 7.46),
+            ],
+        )],
+    );
+    close(
+        latest_growth(&annual, "DilutedEPS"),
+        (7.46 / 6.08 - 1.0) * 100.0,
+    );
+    assert_eq!(latest_growth(&annual, "NetIncome"), None);
+}
+
+#[test]
+fn card_shows_dashes_for_missing_values() {
+    let summary = FinancialSummary {
+        symbol: "TEST".to_string(),
+        pe_ratio: Some(31.456),
+        market_cap: Some(3.9e12),
+        ..FinancialSummary::default()
+    };
+    let sections = card_sections(&summary);
+    let valuation = sections.iter().find(|s| s.title == "Valuation").unwrap();
+    assert_eq!(
+        valuation.tiles,
+        [
+            ("P/E", "31.46x".to_string()),
+            ("PEG", "—".to_string()),
+            ("EV / EBITDA", "—".to_string()),
+            ("FCF yield", "—".to_string()),
+            ("Market cap", "3.90T".to_string()),
+        ]
+    );
+}
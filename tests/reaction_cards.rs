@@ -0,0 +1,155 @@
+use chrono::NaiveDate;
+
+use stacks_bot::models::{
+    EarningsResult, EarningsSession, EstimateOutlook, PriceQuote, SurpriseVerdict,
+};
+use stacks_bot::service::automation::earnings::reaction_cards::{
+    compare_line, rank_by_reaction, ReactionCard,
+};
+use stacks_bot::service::automation::earnings::{extended_hours_move_pct, format_card_line};
+
+fn d(s: &str) -> NaiveDate {
+    s.parse().unwrap()
+}
+
+fn card(symbol: &str, reaction_pct: Option<f64>) -> ReactionCard {
+    ReactionCard {
+        symbol: symbol.to_string(),
+        name: None,
+        session: EarningsSession::AfterClose,
+        date: d("2026-10-29"),
+        result: None,
+        eps_estimate: None,
+        revenue_estimate: None,
+        reaction_pct,
+        implied_move_pct: None,
+    }
+}
+
+fn quote(percent_change: f64, pre: Option<f64>, after: Option<f64>) -> PriceQuote {
+    PriceQuote {
+        symbol: "AAPL".to_string(),
+        name: "Apple Inc.".to_string(),
+        price: Some(100.0),
+        currency: Some("USD".to_string()),
+        change: None,
+        percent_change: Some(percent_change),
+        pre_market_price: pre,
+        after_hours_price: after,
+    }
+}
+
+fn plain(v: Option<f64>) -> String {
+    v.map(|v| format!("{v:.2}"))
+        .unwrap_or_else(|| "N/A".to_string())
+}
+
+#[test]
+fn ranks_biggest_moves_first_and_unquoted_last() {
+    let mut cards = vec![
+        card("NOQ", None),
+        card("SMALL", Some(1.2)),
+        card("DOWN", Some(-8.5)),
+        card("UP", Some(4.0)),
+        card("TIE", Some(-4.0)),
+        card("ANOQ", None),
+    ];
+    rank_by_reaction(&mut cards);
+
+    let order: Vec<&str> = cards.iter().map(|c| c.symbol.as_str()).collect();
+    assert_eq!(order, ["DOWN", "TIE", "UP", "SMALL", "ANOQ", "NOQ"]);
+}
+
+#[test]
+fn reaction_uses_the_session_price() {
+    let q = quote(1.5, Some(97.0), Some(103.0));
+
+    let close = |a: Option<f64>, b: f64| (a.unwrap() - b).abs() < 1e-9;
+    assert!(close(
+        extended_hours_move_pct(&q, EarningsSession::BeforeOpen),
+        -3.0
+    ));
+    assert!(close(
+        extended_hours_move_pct(&q, EarningsSession::AfterClose),
+        3.0
+    ));
+    assert!(close(
+        extended_hours_move_pct(&q, EarningsSession::DuringMarket),
+        1.5
+    ));
+    assert_eq!(extended_hours_move_pct(&q, EarningsSession::Unknown), None);
+
+    let no_after_hours = quote(1.5, None, None);
+    assert_eq!(
+        extended_hours_move_pct(&no_after_hours, EarningsSession::AfterClose),
+        None
+    );
+}
+
+#[test]
+fn compare_lines_carry_surprise_and_verdict() {
+    let beat = compare_line("EPS", "1.46".into(), Some(1.39), plain, Some(5.04));
+    assert_eq!(beat.text, "EPS 1.46 vs 1.39 (+5.0%)");
+    assert_eq!(beat.verdict, Some(SurpriseVerdict::Beat));
+
+    let inline = compare_line("EPS", "1.40".into(), Some(1.39), plain, Some(0.7));
+    assert_eq!(inline.verdict, Some(SurpriseVerdict::Inline));
+
+    let miss = compare_line("EPS", "1.20".into(), Some(1.39), plain, Some(-13.7));
+    assert_eq!(miss.verdict, Some(SurpriseVerdict::Miss));
+
+    let estimate_only = compare_line("Revenue", "N/A".into(), Some(89.5), plain, None);
+    assert_eq!(estimate_only.text, "Revenue N/A vs est 89.50");
+    assert_eq!(estimate_only.verdict, None);
+
+    let actual_only = compare_line("Revenue", "90.70".into(), None, plain, None);
+    assert_eq!(actual_only.text, "Revenue 90.70");
+}
+
+#[test]
+fn card_line_with_results_and_implied_move() {
+    let mut c = card("AAPL", Some(3.1));
+    c.implied_move_pct = Some(4.2);
+    c.result = Some(EarningsResult {
+        symbol: "AAPL".to_string(),
+        fiscal_quarter_end: d("2026-09-27"),
+        eps_actual: Some(1.46),
+        eps_estimate: Some(1.39),
+        revenue_actual: Some(90.7e9),
+        revenue_estimate: Some(89.5e9),
+        outlook: Some(EstimateOutlook {
+            period_end: d("2026-12-27"),
+            eps_estimate: Some(1.60),
+            eps_estimate_7d_ago: Some(1.5686),
+            revenue_estimate: Some(95.0e9),
+        }),
+    });
+
+    assert_eq!(
+        format_card_line(&c),
+        "AAPL [AMC 2026-10-29] — FQ ending Sep 27, 2026 — EPS 1.46 vs 1.39 (+5.0%) ✅ Beat \
+         | Revenue 90.7B vs 89.5B (+1.3%) ✅ Beat | Next Q EPS est 1.60 (7d +2.0%), \
+         Rev est 95.0B | Implied ±4.2% vs actual +3.1%"
+    );
+}
+
+#[test]
+fn card_line_before_results_shows_estimates() {
+    let mut c = card("MSFT", None);
+    c.session = EarningsSession::BeforeOpen;
+    c.eps_estimate = Some(3.1);
+    c.revenue_estimate = Some(64.5e9);
+    c.implied_move_pct = Some(5.0);
+
+    assert_eq!(
+        format_card_line(&c),
+        "MSFT [BMO 2026-10-29] — EPS N/A | Revenue N/A (est. EPS 3.10 | Revenue 64.5B) \
+         | Implied ±5.0%"
+    );
+
+    let bare = card("NVDA", Some(-2.3));
+    assert_eq!(
+        format_card_line(&bare),
+        "NVDA [AMC 2026-10-29] — EPS N/A | Revenue N/A | Move -2.3%"
+    );
+}
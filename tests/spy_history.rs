@@ -0,0 +1,40 @@
+use stacks_bot::service::caching::collections::spy_data::{parse_entry, HistorySeries};
+
+#[test]
+fn legacy_entry_leaves_new_fields_empty() {
+    let point = parse_entry(r#"{"t":"2026-10-16T14:30:00+00:00","p":1.25}"#).unwrap();
+
+    assert_eq!(point.time.to_rfc3339(), "2026-10-16T14:30:00+00:00");
+    assert_eq!(point.last, 1.25);
+    assert_eq!(point.bid, None);
+    assert_eq!(point.ask, None);
+    assert_eq!(point.implied_volatility, None);
+    assert_eq!(point.volume, None);
+    assert_eq!(point.open_interest, None);
+
+    assert_eq!(HistorySeries::Last.value(&point), Some(1.25));
+    assert_eq!(HistorySeries::Mid.value(&point), None);
+}
+
+#[test]
+fn full_entry_reads_every_field() {
+    let point = parse_entry(
+        r#"{"t":"2026-10-16T14:30:00Z","p":1.25,"b":1.2,"a":1.3,"iv":0.18,"v":420,"oi":9100}"#,
+    )
+    .unwrap();
+
+    assert_eq!(point.bid, Some(1.2));
+    assert_eq!(point.ask, Some(1.3));
+    assert_eq!(point.implied_volatility, Some(0.18));
+    assert_eq!(point.volume, Some(420));
+    assert_eq!(point.open_interest, Some(9100));
+    assert!((HistorySeries::Mid.value(&point).unwrap() - 1.25).abs() < 1e-9);
+    assert!((HistorySeries::ImpliedVolatility.value(&point).unwrap() - 18.0).abs() < 1e-9);
+}
+
+#[test]
+fn malformed_entries_are_skipped() {
+    assert!(parse_entry("not json").is_none());
+    assert!(parse_entry(r#"{"p":1.25}"#).is_none());
+    assert!(parse_entry(r#"{"t":"yesterday","p":1.25}"#).is_none());
+}
@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+use serde_json::{json, Value};
+
+use stacks_bot::models::{FinancialStatement, Frequency, StatementType};
+use stacks_bot::service::finance::export::{export_statement, ExportFormat};
+
+fn series(values: &[(&str, f64)]) -> HashMap<String, Value> {
+    values
+        .iter()
+        .map(|(date, raw)| {
+            (
+                date.to_string(),
+                json!({ "currencyCode": "USD", "reportedValue": { "raw": raw } }),
+            )
+        })
+        .collect()
+}
+
+fn statement() -> FinancialStatement {
+    FinancialStatement {
+        symbol: "aapl".to_string(),
+        statement_type: "income".to_string(),
+        frequency: "quarterly".to_string(),
+        statement: HashMap::from([
+            (
+                "TotalRevenue".to_string(),
+                series(&[("2025-03-29", 95.4e9), ("2025-06-28", 94.04e9)]),
+            ),
+            ("NetIncome".to_string(), series(&[("2025-06-28", 23.43e9)])),
+            (
+                "DilutedAverageShares".to_string(),
+                series(&[("2025-03-29", 15.0e9), ("2025-06-28", 14.9e9)]),
+            ),
+            // Not in the layout
+            ("SpecialItems".to_string(), series(&[("2025-03-29", -1.5)])),
+        ]),
+    }
+}
+
+#[test]
+fn parses_formats() {
+    assert_eq!(ExportFormat::parse("CSV"), Some(ExportFormat::Csv));
+    assert_eq!(ExportFormat::parse(" json "), Some(ExportFormat::Json));
+    assert_eq!(ExportFormat::parse("xlsx"), None);
+}
+
+#[test]
+fn csv_has_every_period_and_line_item_in_statement_order() {
+    let file = export_statement(
+        &statement(),
+        StatementType::IncomeStatement,
+        Frequency::Quarterly,
+        ExportFormat::Csv,
+    )
+    .unwrap();
+    assert_eq!(file.filename, "AAPL-income-quarterly.csv");
+
+    let csv = String::from_utf8(file.bytes).unwrap();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines[0], "field,label,currency,2025-03-29,2025-06-28");
+    assert_eq!(
+        lines[1],
+        "TotalRevenue,Total Revenue,USD,95400000000,94040000000"
+    );
+    assert_eq!(lines[2], "NetIncome,Net Income,USD,,23430000000");
+    // Share counts carry no currency; fields outside the layout come last
+    assert!(lines.contains(&"DilutedAverageShares,Diluted Avg Shares,,15000000000,14900000000"));
+    assert_eq!(lines.last(), Some(&"SpecialItems,Special Items,USD,-1.5,"));
+}
+
+#[test]
+fn json_is_the_full_statement() {
+    let stmt = statement();
+    let file = export_statement(
+        &stmt,
+        StatementType::IncomeStatement,
+        Frequency::Quarterly,
+        ExportFormat::Json,
+    )
+    .unwrap();
+    assert_eq!(file.filename, "AAPL-income-quarterly.json");
+
+    let parsed: Value = serde_json::from_slice(&file.bytes).unwrap();
+    assert_eq!(parsed, serde_json::to_value(&stmt).unwrap());
+    assert_eq!(
+        parsed["statement"]["TotalRevenue"]["2025-06-28"]["reportedValue"]["raw"],
+        json!(94.04e9)
+    );
+}
+
+#[test]
+fn csv_quotes_labels_with_commas() {
+    let stmt = FinancialStatement {
+        symbol: "AAPL".to_string(),
+        statement_type: "cashflow".to_string(),
+        frequency: "annual".to_string(),
+        statement: HashMap::from([(
+            "NetLongTermDebtIssuance".to_string(),
+            series(&[("2024-09-28", -5.0e9)]),
+        )]),
+    };
+    let file = export_statement(
+        &stmt,
+        StatementType::CashFlow,
+        Frequency::Annual,
+        ExportFormat::Csv,
+    )
+    .unwrap();
+    assert_eq!(file.filename, "AAPL-cashflow-annual.csv");
+    assert_eq!(
+        String::from_utf8(file.bytes).unwrap(),
+        "field,label,currency,2024-09-28\nNetLongTermDebtIssuance,\"Long-Term Debt, Net\",USD,-5000000000\n"
+    );
+}
@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use serde_json::{json, Value};
+
+use stacks_bot::models::{FinancialStatement, Frequency, StatementType};
+use stacks_bot::service::finance::fundamentals::reshape_timeseries_to_financial_statements;
+use stacks_bot::service::finance::statements::{
+    abbreviate, build_statement_table, field_label, format_value, pct_change, LineKind,
+    STATEMENT_PERIODS,
+};
+
+const FIXTURE: &str = "build-docs/stacks-bot-docs/json_output/fundamentals_output.json";
+
+fn day(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+fn fixture_income() -> FinancialStatement {
+    let raw: Value = serde_json::from_str(&std::fs::read_to_string(FIXTURE).unwrap()).unwrap();
+    reshape_timeseries_to_financial_statements(&raw)
+        .into_iter()
+        .find(|s| s.statement_type == "income" && s.frequency == "annual")
+        .expect("annual income statement in fixture")
+}
+
+fn quarterly(field: &str, values: &[(&str, f64)]) -> FinancialStatement {
+    let series = values
+        .iter()
+        .map(|(date, raw)| {
+            (
+                date.to_string(),
+                json!({ "currencyCode": "USD", "reportedValue": { "raw": raw } }),
+            )
+        })
+        .collect();
+    FinancialStatement {
+        symbol: "TEST".to_string(),
+        statement_type: "income".to_string(),
+        frequency: "quarterly".to_string(),
+        statement: HashMap::from([(field.to_string(), series)]),
+    }
+}
+
+#[test]
+fn lays_out_income_statement_in_reporting_order() {
+    let stmt = fixture_income();
+    let table = build_statement_table(
+        &stmt,
+        StatementType::IncomeStatement,
+        Frequency::Annual,
+        None,
+        STATEMENT_PERIODS,
+    )
+    .unwrap();
+
+    assert_eq!(table.periods, [day(2024, 9, 30), day(2025, 9, 30)]);
+    assert_eq!(table.currency.as_deref(), Some("USD"));
+
+    let position = |field: &str| {
+        table
+            .rows
+            .iter()
+            .position(|r| r.field == field)
+            .unwrap_or_else(|| panic!("{field} missing"))
+    };
+    assert!(position("TotalRevenue") < position("GrossProfit"));
+    assert!(position("GrossProfit") < position("OperatingIncome"));
+    assert!(position("OperatingIncome") < position("NetIncome"));
+
+    let revenue = &table.rows[position("TotalRevenue")];
+    assert_eq!(
+        revenue.values,
+        [Some(391_035_000_000.0), Some(416_161_000_000.0)]
+    );
+    let yoy = revenue.change_pct.unwrap();
+    assert!((yoy - 6.4255).abs() < 1e-3, "{yoy}");
+    assert_eq!(revenue.yoy_pct, None);
+    assert!(table
+        .rows
+        .iter()
+        .all(|r| r.values.iter().any(Option::is_some)));
+}
+
+#[test]
+fn filters_periods_up_to_the_requested_date() {
+    let stmt = fixture_income();
+    let table = build_statement_table(
+        &stmt,
+        StatementType::IncomeStatement,
+        Frequency::Annual,
+        Some(day(2024, 12, 31)),
+        STATEMENT_PERIODS,
+    )
+    .unwrap();
+    assert_eq!(table.periods, [day(2024, 9, 30)]);
+    assert!(table.rows.iter().all(|r| r.change_pct.is_none()));
+
+    assert!(build_statement_table(
+        &stmt,
+        StatementType::IncomeStatement,
+        Frequency::Annual,
+        Some(day(2020, 12, 31)),
+        STATEMENT_PERIODS,
+    )
+    .is_none());
+}
+
+#[test]
+fn quarterly_tables_compare_sequential_and_year_ago_quarters() {
+    let stmt = quarterly(
+        "TotalRevenue",
+        &[
+            ("2024-06-30", 80.0),
+            ("2024-09-30", 90.0),
+            ("2024-12-31", 120.0),
+            ("2025-03-31", 95.0),
+            ("2025-06-30", 100.0),
+            ("2025-09-30", 110.0),
+        ],
+    );
+    let table = build_statement_table(
+        &stmt,
+        StatementType::IncomeStatement,
+        Frequency::Quarterly,
+        None,
+        STATEMENT_PERIODS,
+    )
+    .unwrap();
+
+    assert_eq!(table.periods.len(), STATEMENT_PERIODS);
+    assert_eq!(table.periods[0], day(2024, 9, 30));
+    let row = &table.rows[0];
+    assert!((row.change_pct.unwrap() - 10.0).abs() < 1e-9);
+    assert!((row.yoy_pct.unwrap() - 22.2222).abs() < 1e-3);
+}
+
+#[test]
+fn missing_quarters_leave_change_cells_empty() {
+    // Q2 2025 is absent: QoQ has no comparison; YoY still finds Q3 2024 by date
+    let stmt = quarterly(
+        "TotalRevenue",
+        &[
+            ("2024-06-30", 80.0),
+            ("2024-09-30", 90.0),
+            ("2024-12-31", 120.0),
+            ("2025-03-31", 95.0),
+            ("2025-09-30", 110.0),
+        ],
+    );
+    let table = build_statement_table(
+        &stmt,
+        StatementType::IncomeStatement,
+        Frequency::Quarterly,
+        None,
+        STATEMENT_PERIODS,
+    )
+    .unwrap();
+    let row = &table.rows[0];
+    assert_eq!(row.change_pct, None);
+    assert!((row.yoy_pct.unwrap() - 22.2222).abs() < 1e-3);
+
+    // Without a year-ago quarter there is no YoY, whatever sits five columns back
+    let stmt = quarterly(
+        "TotalRevenue",
+        &[
+            ("2024-06-30", 80.0),
+            ("2024-12-31", 120.0),
+            ("2025-03-31", 95.0),
+            ("2025-06-30", 100.0),
+            ("2025-09-30", 110.0),
+        ],
+    );
+    let table = build_statement_table(
+        &stmt,
+        StatementType::IncomeStatement,
+        Frequency::Quarterly,
+        None,
+        STATEMENT_PERIODS,
+    )
+    .unwrap();
+    let row = &table.rows[0];
+    assert!((row.change_pct.unwrap() - 10.0).abs() < 1e-9);
+    assert_eq!(row.yoy_pct, None);
+}
+
+#[test]
+fn formats_values_and_labels() {
+    assert_eq!(abbreviate(391_035_000_000.0), "391.04B");
+    assert_eq!(abbreviate(1_200_000_000_000.0), "1.20T");
+    assert_eq!(abbreviate(-850_300_000.0), "-850.30M");
+    assert_eq!(abbreviate(12_500.0), "12.50K");
+    assert_eq!(abbreviate(42.0), "42.00");
+    assert_eq!(format_value(6.08, LineKind::PerShare), "6.08");
+    assert_eq!(format_value(15_004_697_000.0, LineKind::Shares), "15.00B");
+
+    assert_eq!(pct_change(Some(-50.0), Some(-100.0)), Some(50.0));
+    assert_eq!(pct_change(Some(1.0), Some(0.0)), None);
+
+    assert_eq!(field_label("TotalRevenue"), "Total Revenue");
+    assert_eq!(field_label("EBITDA"), "EBITDA");
+    assert_eq!(
+        field_label("NetIncomeFromContinuingOperationNetMinorityInterest"),
+        "Net Income From Continuing Operation Net Minority Interest"
+    );
+}
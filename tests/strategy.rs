@@ -0,0 +1,160 @@
+use chrono::NaiveDate;
+
+use stacks_bot::service::command::strategy::parse_strategy;
+use stacks_bot::service::finance::strategy::{
+    analyze_strategy, LegSpec, OptionKind, StrategyAnalysis, StrategyLeg,
+};
+
+const SPOT: f64 = 600.0;
+
+fn leg(strike: f64, kind: OptionKind, quantity: i32, premium: f64) -> StrategyLeg {
+    StrategyLeg {
+        strike,
+        kind,
+        quantity,
+        premium,
+        iv: 0.20,
+        via_parity: false,
+    }
+}
+
+fn analyze(legs: Vec<StrategyLeg>) -> StrategyAnalysis {
+    analyze_strategy(SPOT, legs, 30.0, 0)
+}
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < 1e-6,
+        "expected {expected}, got {actual}"
+    );
+}
+
+fn assert_breakevens(analysis: &StrategyAnalysis, expected: &[f64]) {
+    assert_eq!(
+        analysis.breakevens.len(),
+        expected.len(),
+        "{:?}",
+        analysis.breakevens
+    );
+    for (actual, expected) in analysis.breakevens.iter().zip(expected) {
+        assert_close(*actual, *expected);
+    }
+}
+
+#[test]
+fn bull_call_vertical() {
+    use OptionKind::Call;
+    let a = analyze(vec![leg(600.0, Call, 1, 5.0), leg(605.0, Call, -1, 2.5)]);
+
+    assert_close(a.net_debit, 250.0);
+    assert_close(a.max_profit.unwrap(), 250.0);
+    assert_close(a.max_loss.unwrap(), -250.0);
+    assert_breakevens(&a, &[602.5]);
+    assert!(a.pop > 0.0 && a.pop < 1.0);
+}
+
+#[test]
+fn long_straddle_has_unbounded_profit() {
+    let a = analyze(vec![
+        leg(600.0, OptionKind::Call, 1, 5.0),
+        leg(600.0, OptionKind::Put, 1, 4.5),
+    ]);
+
+    assert_close(a.net_debit, 950.0);
+    assert_eq!(a.max_profit, None);
+    assert_close(a.max_loss.unwrap(), -950.0);
+    assert_breakevens(&a, &[590.5, 609.5]);
+}
+
+#[test]
+fn short_straddle_has_unbounded_loss() {
+    let a = analyze(vec![
+        leg(600.0, OptionKind::Call, -1, 5.0),
+        leg(600.0, OptionKind::Put, -1, 4.5),
+    ]);
+
+    assert_close(a.net_debit, -950.0);
+    assert_close(a.max_profit.unwrap(), 950.0);
+    assert_eq!(a.max_loss, None);
+    assert_breakevens(&a, &[590.5, 609.5]);
+}
+
+#[test]
+fn iron_condor() {
+    use OptionKind::{Call, Put};
+    let a = analyze(vec![
+        leg(590.0, Put, 1, 1.0),
+        leg(595.0, Put, -1, 2.0),
+        leg(605.0, Call, -1, 2.2),
+        leg(610.0, Call, 1, 1.1),
+    ]);
+
+    // 2.10 credit on 5-wide wings
+    assert_close(a.net_debit, -210.0);
+    assert_close(a.max_profit.unwrap(), 210.0);
+    assert_close(a.max_loss.unwrap(), -290.0);
+    assert_breakevens(&a, &[592.9, 607.1]);
+    assert!(a.pop > 0.0 && a.pop < 1.0);
+}
+
+#[test]
+fn parses_legs_expiry_and_horizon() {
+    let request = parse_strategy("spy 600C -1, 605c +1 595P x2 2026-10-23 T+3").unwrap();
+
+    assert_eq!(request.symbol, "SPY");
+    assert_eq!(
+        request.expiration,
+        NaiveDate::from_ymd_opt(2026, 10, 23).unwrap()
+    );
+    assert_eq!(request.horizon_days, Some(3));
+    assert_eq!(
+        request.legs,
+        vec![
+            LegSpec {
+                strike: 600.0,
+                kind: OptionKind::Call,
+                quantity: -1
+            },
+            LegSpec {
+                strike: 605.0,
+                kind: OptionKind::Call,
+                quantity: 1
+            },
+            LegSpec {
+                strike: 595.0,
+                kind: OptionKind::Put,
+                quantity: 2
+            },
+        ]
+    );
+}
+
+#[test]
+fn quantity_defaults_to_one() {
+    let request = parse_strategy("SPY 2026-10-23 600.5C").unwrap();
+    assert_eq!(request.legs.len(), 1);
+    assert_eq!(request.legs[0].strike, 600.5);
+    assert_eq!(request.legs[0].quantity, 1);
+    assert_eq!(request.horizon_days, None);
+}
+
+#[test]
+fn rejects_malformed_legs() {
+    let err = |input: &str| parse_strategy(input).unwrap_err();
+
+    assert!(err("SPY 600X -1 2026-10-23").contains("unrecognised token: 600X"));
+    assert!(err("SPY abcC 2026-10-23").contains("invalid strike"));
+    assert!(err("SPY 0P 2026-10-23").contains("invalid strike"));
+    assert!(err("SPY 600C 0 2026-10-23").contains("must not be zero"));
+    assert!(err("SPY 600C 2026-10-23 T+x").contains("invalid horizon"));
+    assert!(err("SPY 2026-10-23").contains("at least one leg"));
+    assert!(err("SPY 600C -1").contains("expiration required"));
+    assert!(err("SP$Y 600C 2026-10-23").contains("invalid symbol"));
+    assert!(err("").contains("symbol required"));
+
+    let nine_legs = (0..9)
+        .map(|i| format!("{}C", 600 + i))
+        .collect::<Vec<_>>()
+        .join(" ");
+    assert!(err(&format!("SPY {nine_legs} 2026-10-23")).contains("at most 8 legs"));
+}
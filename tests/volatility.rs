@@ -0,0 +1,81 @@
+use chrono::NaiveDate;
+
+use stacks_bot::service::finance::volatility::{interpolate_iv, iv_stats, AtmIvPoint};
+
+fn point(days: i64, iv: f64) -> AtmIvPoint {
+    AtmIvPoint {
+        expiration: NaiveDate::from_ymd_opt(2026, 10, 19).unwrap() + chrono::Duration::days(days),
+        days,
+        iv,
+    }
+}
+
+fn close(actual: f64, expected: f64) {
+    assert!((actual - expected).abs() < 1e-9, "{actual} != {expected}");
+}
+
+#[test]
+fn interpolates_total_variance_between_expiries() {
+    let curve = [point(20, 0.30), point(40, 0.40)];
+
+    // (0.09·20 + (0.16·40 − 0.09·20)·½) / 30 = 4.1 / 30
+    close(interpolate_iv(&curve, 30).unwrap(), (4.1_f64 / 30.0).sqrt());
+    // On an expiry the reading is that expiry's IV
+    close(interpolate_iv(&curve, 20).unwrap(), 0.30);
+    close(interpolate_iv(&curve, 40).unwrap(), 0.40);
+}
+
+#[test]
+fn holds_the_nearest_iv_beyond_the_curve() {
+    let curve = [point(20, 0.30), point(40, 0.40)];
+
+    close(interpolate_iv(&curve, 7).unwrap(), 0.30);
+    close(interpolate_iv(&curve, 90).unwrap(), 0.40);
+}
+
+#[test]
+fn empty_and_single_point_curves() {
+    assert_eq!(interpolate_iv(&[], 30), None);
+
+    let single = [point(45, 0.25)];
+    close(interpolate_iv(&single, 30).unwrap(), 0.25);
+    close(interpolate_iv(&single, 60).unwrap(), 0.25);
+}
+
+#[test]
+fn ranks_current_iv_against_history() {
+    let stats = iv_stats(0.35, &[0.20, 0.30, 0.40, 0.50]).unwrap();
+    close(stats.low, 0.20);
+    close(stats.high, 0.50);
+    close(stats.rank, 50.0);
+    close(stats.percentile, 50.0);
+    assert_eq!(stats.samples, 4);
+
+    assert!(iv_stats(0.35, &[]).is_none());
+}
+
+#[test]
+fn flat_history_ranks_in_the_middle() {
+    let stats = iv_stats(0.30, &[0.30; 5]).unwrap();
+    close(stats.rank, 50.0);
+    close(stats.percentile, 0.0);
+}
+
+#[test]
+fn short_history_and_current_outside_the_range() {
+    let stats = iv_stats(0.40, &[0.20]).unwrap();
+    close(stats.rank, 100.0);
+    close(stats.percentile, 100.0);
+    assert_eq!(stats.samples, 1);
+
+    // The current reading widens the range instead of ranking beyond 0–100
+    let above = iv_stats(0.60, &[0.20, 0.30, 0.40]).unwrap();
+    close(above.high, 0.60);
+    close(above.rank, 100.0);
+    close(above.percentile, 100.0);
+
+    let below = iv_stats(0.10, &[0.20, 0.30, 0.40]).unwrap();
+    close(below.low, 0.10);
+    close(below.rank, 0.0);
+    close(below.percentile, 0.0);
+}
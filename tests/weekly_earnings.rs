@@ -0,0 +1,115 @@
+use chrono::NaiveDate;
+
+use stacks_bot::models::EarningsSession;
+use stacks_bot::service::command::earnings::{WeeklyFilters, WEEKLY_PAGE_PREFIX};
+
+/// Discord's limit on a component `custom_id`.
+const CUSTOM_ID_MAX: usize = 100;
+
+fn d(s: &str) -> NaiveDate {
+    s.parse().unwrap()
+}
+
+fn filters() -> WeeklyFilters {
+    WeeklyFilters {
+        from: d("2026-10-19"),
+        to: d("2026-10-23"),
+        min_importance: None,
+        session: None,
+        watchlist_only: false,
+        sector: None,
+    }
+}
+
+#[test]
+fn unfiltered_ids_round_trip() {
+    let f = filters();
+    let id = f.to_custom_id(0);
+
+    assert_eq!(id, "weekly:0:20261019:20261023:-:-:0:");
+    assert!(id.starts_with(WEEKLY_PAGE_PREFIX));
+    assert_eq!(WeeklyFilters::parse_custom_id(&id), Some((f, 0)));
+}
+
+#[test]
+fn every_filter_round_trips() {
+    for session in [EarningsSession::BeforeOpen, EarningsSession::AfterClose] {
+        let f = WeeklyFilters {
+            min_importance: Some(4),
+            session: Some(session),
+            watchlist_only: true,
+            sector: Some("Consumer Cyclical".to_string()),
+            ..filters()
+        };
+        let id = f.to_custom_id(3);
+
+        assert_eq!(WeeklyFilters::parse_custom_id(&id), Some((f, 3)));
+    }
+}
+
+#[test]
+fn sector_may_contain_separators() {
+    let f = WeeklyFilters {
+        sector: Some("real estate: reits".to_string()),
+        ..filters()
+    };
+    let id = f.to_custom_id(1);
+
+    assert_eq!(WeeklyFilters::parse_custom_id(&id), Some((f, 1)));
+}
+
+#[test]
+fn worst_case_id_fits_discord_limit() {
+    let f = WeeklyFilters {
+        from: d("2026-12-28"),
+        to: d("2027-01-28"),
+        min_importance: Some(5),
+        session: Some(EarningsSession::AfterClose),
+        watchlist_only: true,
+        sector: Some("Communication Services and Financial Services ".repeat(5)),
+    };
+    let id = f.to_custom_id(999);
+
+    assert_eq!(id.chars().count(), CUSTOM_ID_MAX);
+
+    // The sector is cut to fit; everything else survives
+    let (parsed, page) = WeeklyFilters::parse_custom_id(&id).unwrap();
+    assert_eq!(page, 999);
+    assert_eq!(parsed.from, f.from);
+    assert_eq!(parsed.to, f.to);
+    assert_eq!(parsed.min_importance, Some(5));
+    assert_eq!(parsed.session, Some(EarningsSession::AfterClose));
+    assert!(parsed.watchlist_only);
+    assert!(f.sector.unwrap().starts_with(&parsed.sector.unwrap()));
+}
+
+#[test]
+fn non_ascii_sector_stays_within_limit() {
+    let f = WeeklyFilters {
+        sector: Some("Énergie renouvelable ".repeat(10)),
+        ..filters()
+    };
+
+    assert!(f.to_custom_id(12).chars().count() <= CUSTOM_ID_MAX);
+}
+
+#[test]
+fn rejects_foreign_or_broken_ids() {
+    assert_eq!(
+        WeeklyFilters::parse_custom_id("holders:institutional:0:10:AAPL"),
+        None
+    );
+    assert_eq!(
+        WeeklyFilters::parse_custom_id("weekly:x:20261019:20261023:-:-:0:"),
+        None
+    );
+    assert_eq!(
+        WeeklyFilters::parse_custom_id("weekly:0:2026-10-19:20261023:-:-:0:"),
+        None
+    );
+    assert_eq!(
+        WeeklyFilters::parse_custom_id("weekly:0:20261019:20261023:high:-:0:"),
+        None
+    );
+    assert_eq!(WeeklyFilters::parse_custom_id("weekly:0:20261019"), None);
+}